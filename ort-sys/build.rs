@@ -6,31 +6,21 @@ use std::{
 const ORT_ENV_SYSTEM_LIB_LOCATION: &str = "ORT_LIB_LOCATION";
 const ORT_ENV_SYSTEM_LIB_PROFILE: &str = "ORT_LIB_PROFILE";
 #[cfg(feature = "download-binaries")]
+const ORT_ENV_CACHE_DIR: &str = "ORT_CACHE_DIR";
+#[cfg(feature = "download-binaries")]
 const ORT_EXTRACT_DIR: &str = "onnxruntime";
 
+#[cfg(feature = "download-binaries")]
 #[path = "src/internal/dirs.rs"]
 mod dirs;
-use self::dirs::cache_dir;
-
 #[cfg(feature = "download-binaries")]
-fn fetch_file(source_url: &str) -> Vec<u8> {
-	let resp = ureq::get(source_url)
-		.timeout(std::time::Duration::from_secs(1800))
-		.call()
-		.unwrap_or_else(|err| panic!("Failed to GET `{source_url}`: {err}"));
-
-	let len = resp
-		.header("Content-Length")
-		.and_then(|s| s.parse::<usize>().ok())
-		.expect("Content-Length header should be present on archive response");
-	let mut reader = resp.into_reader();
-	let mut buffer = Vec::new();
-	reader
-		.read_to_end(&mut buffer)
-		.unwrap_or_else(|err| panic!("Failed to download from `{source_url}`: {err}"));
-	assert_eq!(buffer.len(), len);
-	buffer
-}
+#[path = "src/internal/download.rs"]
+mod download;
+#[cfg(feature = "download-binaries")]
+use self::download::{available_space, binary_cache_dir, check_disk_space, fetch_file, gzip_uncompressed_size};
+#[cfg(feature = "pkg-config")]
+#[path = "src/internal/pkg_config.rs"]
+mod pkg_config;
 
 #[cfg(feature = "download-binaries")]
 fn hex_str_to_bytes(c: impl AsRef<[u8]>) -> Vec<u8> {
@@ -47,17 +37,28 @@ fn hex_str_to_bytes(c: impl AsRef<[u8]>) -> Vec<u8> {
 }
 
 #[cfg(feature = "download-binaries")]
-fn verify_file(buf: &[u8], hash: impl AsRef<[u8]>) -> bool {
-	use sha2::Digest;
-	sha2::Sha256::digest(buf)[..] == hex_str_to_bytes(hash)
+fn verify_file(digest: &[u8], hash: impl AsRef<[u8]>) -> bool {
+	digest == hex_str_to_bytes(hash)
 }
 
 #[cfg(feature = "download-binaries")]
-fn extract_tgz(buf: &[u8], output: &Path) {
-	let buf: std::io::BufReader<&[u8]> = std::io::BufReader::new(buf);
+fn extract_tgz(file: &Path, output: &Path) {
+	// fail early rather than leave a partially extracted archive behind when the disk is (nearly) full
+	let required = gzip_uncompressed_size(file).expect("Failed to read downloaded .tgz file");
+	if let Err(e) = check_disk_space(output, required, available_space(output)) {
+		panic!("{e}");
+	}
+
+	let file = fs::File::open(file).expect("Failed to open downloaded .tgz file");
+	let buf = std::io::BufReader::with_capacity(1 << 20, file);
 	let tar = flate2::read::GzDecoder::new(buf);
 	let mut archive = tar::Archive::new(tar);
-	archive.unpack(output).expect("Failed to extract .tgz file");
+	if let Err(e) = archive.unpack(output) {
+		// the extracted directory is used to check whether the binaries have already been downloaded, so don't leave
+		// an incomplete one behind
+		let _ = fs::remove_dir_all(output.join(ORT_EXTRACT_DIR));
+		panic!("Failed to extract .tgz file: {e}");
+	}
 }
 
 #[cfg(feature = "copy-dylibs")]
@@ -255,6 +256,20 @@ fn prepare_libort_dir() -> (PathBuf, bool) {
 
 		(lib_dir, needs_link)
 	} else {
+		#[cfg(feature = "pkg-config")]
+		{
+			// like the `pkg-config` crate, allow overriding the pkg-config binary via `PKG_CONFIG`
+			let pkg_config = env::var_os("PKG_CONFIG").unwrap_or_else(|| "pkg-config".into());
+			// like other dylibs, whether this is actually linked is left to `real_main`, so `load-dynamic` doesn't link it
+			match pkg_config::probe(&pkg_config) {
+				Some(lib_dir) => return (lib_dir, true),
+				None => println!(
+					"cargo:warning=`pkg-config` feature is enabled, but `{}` could not be found via pkg-config; falling back",
+					pkg_config::PKG_NAME
+				)
+			}
+		}
+
 		#[cfg(feature = "download-binaries")]
 		{
 			let target = env::var("TARGET").unwrap().to_string();
@@ -308,20 +323,21 @@ fn prepare_libort_dir() -> (PathBuf, bool) {
 				x => panic!("downloaded binaries not available for target {x}\nyou may have to compile ONNX Runtime from source")
 			};
 
-			let mut cache_dir = cache_dir()
-				.expect("could not determine cache directory")
-				.join("dfbin")
-				.join(target)
-				.join(prebuilt_hash);
-			if fs::create_dir_all(&cache_dir).is_err() {
+			let mut cache_dir = binary_cache_dir(env::var_os(ORT_ENV_CACHE_DIR), &target, prebuilt_hash).expect("could not determine cache directory");
+			if let Err(e) = fs::create_dir_all(&cache_dir) {
+				if env::var_os(ORT_ENV_CACHE_DIR).is_some_and(|dir| !dir.is_empty()) {
+					println!("cargo:warning=could not create cache directory `{}` ({e}); falling back to OUT_DIR", cache_dir.display());
+				}
 				cache_dir = env::var("OUT_DIR").unwrap().into();
 			}
 
 			let lib_dir = cache_dir.join(ORT_EXTRACT_DIR);
 			if !lib_dir.exists() {
-				let downloaded_file = fetch_file(prebuilt_url);
-				assert!(verify_file(&downloaded_file, prebuilt_hash), "hash does not match!");
+				let downloaded_file = cache_dir.join("onnxruntime.tgz");
+				let digest = fetch_file(prebuilt_url, &downloaded_file);
+				assert!(verify_file(&digest, prebuilt_hash), "hash does not match!");
 				extract_tgz(&downloaded_file, &cache_dir);
+				let _ = fs::remove_file(&downloaded_file);
 			}
 
 			static_link_prerequisites(true);
@@ -345,6 +361,13 @@ fn prepare_libort_dir() -> (PathBuf, bool) {
 fn real_main(link: bool) {
 	println!("cargo:rerun-if-env-changed={}", ORT_ENV_SYSTEM_LIB_LOCATION);
 	println!("cargo:rerun-if-env-changed={}", ORT_ENV_SYSTEM_LIB_PROFILE);
+	#[cfg(feature = "download-binaries")]
+	println!("cargo:rerun-if-env-changed={}", ORT_ENV_CACHE_DIR);
+	#[cfg(feature = "pkg-config")]
+	{
+		println!("cargo:rerun-if-env-changed=PKG_CONFIG");
+		println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
+	}
 
 	let (install_dir, needs_link) = prepare_libort_dir();
 
@@ -353,7 +376,10 @@ fn real_main(link: bool) {
 	if link {
 		if needs_link {
 			println!("cargo:rustc-link-lib=onnxruntime");
-			println!("cargo:rustc-link-search=native={}", lib_dir.display());
+			// pkg-config reports no directory for libraries in the linker's default search path
+			if !lib_dir.as_os_str().is_empty() {
+				println!("cargo:rustc-link-search=native={}", lib_dir.display());
+			}
 		}
 
 		static_link_prerequisites(false);
@@ -0,0 +1,267 @@
+// streaming download of prebuilt binaries; used by the build script when `download-binaries` is enabled
+
+use std::{
+	ffi::OsString,
+	fs::{self, File},
+	io::{self, BufWriter, Read, Seek, SeekFrom, Write},
+	path::{Path, PathBuf},
+	time::{Duration, Instant}
+};
+
+use sha2::Digest;
+
+/// How often progress is reported while downloading.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Returns the directory the binaries identified by `target` & `prebuilt_hash` are cached in.
+///
+/// The cache is rooted at `root_override` (the value of `ORT_CACHE_DIR`) if it is set and non-empty, or the user's cache
+/// directory otherwise. Returns `None` if neither is available.
+pub fn binary_cache_dir(root_override: Option<OsString>, target: &str, prebuilt_hash: &str) -> Option<PathBuf> {
+	root_override
+		.filter(|root| !root.is_empty())
+		.map(PathBuf::from)
+		.or_else(super::dirs::cache_dir)
+		.map(|root| root.join("dfbin").join(target).join(prebuilt_hash))
+}
+
+/// Downloads `source_url` to `dest`, printing progress as `cargo:warning` lines. Returns the SHA-256 digest of the
+/// downloaded file, which is computed while streaming.
+///
+/// The file is first written to a temporary `.part` file next to `dest` and only moved into place once the download
+/// completes, so an interrupted build never leaves a truncated archive behind.
+///
+/// A download whose size doesn't match the response's `Content-Length` is not considered an error, since proxies that
+/// transparently decompress responses leave the (compressed) length as-is. The caller must verify the returned digest.
+pub fn fetch_file(source_url: &str, dest: &Path) -> Vec<u8> {
+	let resp = ureq::get(source_url)
+		.timeout(Duration::from_secs(1800))
+		.call()
+		.unwrap_or_else(|err| panic!("Failed to GET `{source_url}`: {err}"));
+
+	let len = resp
+		.header("Content-Length")
+		.and_then(|s| s.parse::<u64>().ok())
+		.expect("Content-Length header should be present on archive response");
+
+	let part_path = dest.with_extension("part");
+	let mut last_report = Instant::now();
+	let (digest, downloaded) = stream_to_file(resp.into_reader(), len, &part_path, |downloaded, total| {
+		if last_report.elapsed() >= PROGRESS_INTERVAL || downloaded == total {
+			last_report = Instant::now();
+			println!("cargo:warning=[ort] downloading ONNX Runtime binaries: {downloaded} / {total} bytes");
+		}
+	})
+	.unwrap_or_else(|err| panic!("Failed to download from `{source_url}`: {err}"));
+	if downloaded != len {
+		// the hash is checked by the caller, so this is only worth a warning
+		println!("cargo:warning=[ort] downloaded {downloaded} bytes, but `Content-Length` was {len}; relying on the hash to verify the download");
+	}
+	fs::rename(&part_path, dest).unwrap_or_else(|err| panic!("Failed to move downloaded file to `{}`: {err}", dest.display()));
+	digest
+}
+
+/// Streams `reader` into the file at `dest`, hashing the data as it is written. `progress` is called after each chunk
+/// with the number of bytes downloaded so far and the expected total. Returns the SHA-256 digest of the data and the
+/// number of bytes actually written, which may differ from `len`.
+pub fn stream_to_file(mut reader: impl Read, len: u64, dest: &Path, mut progress: impl FnMut(u64, u64)) -> io::Result<(Vec<u8>, u64)> {
+	let mut writer = BufWriter::new(File::create(dest)?);
+	let mut hasher = sha2::Sha256::new();
+	let mut buf = vec![0u8; 64 * 1024];
+	let mut downloaded = 0u64;
+	loop {
+		let n = match reader.read(&mut buf) {
+			Ok(0) => break,
+			Ok(n) => n,
+			Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+			Err(e) => return Err(e)
+		};
+		hasher.update(&buf[..n]);
+		writer.write_all(&buf[..n])?;
+		downloaded += n as u64;
+		progress(downloaded, len);
+	}
+	writer.flush()?;
+	Ok((hasher.finalize().to_vec(), downloaded))
+}
+
+/// Returns the size of the data compressed in the gzip file at `path`, read from the `ISIZE` field of its trailer.
+/// This is the size modulo 2^32, so it is underestimated for archives larger than 4 GiB.
+pub fn gzip_uncompressed_size(path: &Path) -> io::Result<u64> {
+	let mut file = File::open(path)?;
+	file.seek(SeekFrom::End(-4))?;
+	let mut isize = [0u8; 4];
+	file.read_exact(&mut isize)?;
+	Ok(u32::from_le_bytes(isize) as u64)
+}
+
+/// Checks that extracting `required` bytes into `dir` fits in the `available` space, returning a descriptive error if it
+/// doesn't. If the available space couldn't be determined, extraction is always allowed.
+pub fn check_disk_space(dir: &Path, required: u64, available: Option<u64>) -> Result<(), String> {
+	match available {
+		Some(available) if available < required => Err(format!(
+			"Not enough disk space to extract ONNX Runtime binaries to `{}`: {required} bytes are required, but only {available} bytes are available. \
+			 Free up some space, or download the binaries manually and point `ORT_LIB_LOCATION` to them.",
+			dir.display()
+		)),
+		_ => Ok(())
+	}
+}
+
+/// Returns the space available to the current user on the filesystem containing `path`, in bytes.
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+pub fn available_space(path: &Path) -> Option<u64> {
+	use std::{
+		ffi::{c_char, c_int, CString},
+		mem::MaybeUninit,
+		os::unix::ffi::OsStrExt
+	};
+
+	// `struct statvfs` is the same for glibc & musl on 64-bit targets
+	#[repr(C)]
+	struct StatVfs {
+		f_bsize: u64,
+		f_frsize: u64,
+		f_blocks: u64,
+		f_bfree: u64,
+		f_bavail: u64,
+		_rest: [u64; 6],
+		_spare: [c_int; 6]
+	}
+
+	extern "C" {
+		fn statvfs(path: *const c_char, buf: *mut StatVfs) -> c_int;
+	}
+
+	let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+	let mut stat = MaybeUninit::<StatVfs>::uninit();
+	if unsafe { statvfs(path.as_ptr(), stat.as_mut_ptr()) } != 0 {
+		return None;
+	}
+	let stat = unsafe { stat.assume_init() };
+	Some(stat.f_bavail.saturating_mul(stat.f_frsize))
+}
+
+/// Returns the space available to the current user on the filesystem containing `path`, in bytes.
+#[cfg(target_os = "windows")]
+pub fn available_space(path: &Path) -> Option<u64> {
+	use std::os::windows::ffi::OsStrExt;
+
+	#[link(name = "kernel32")]
+	extern "system" {
+		fn GetDiskFreeSpaceExW(directory_name: *const u16, free_bytes_available: *mut u64, total_bytes: *mut u64, total_free_bytes: *mut u64) -> i32;
+	}
+
+	let path: Vec<u16> = path.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
+	let mut available = 0;
+	if unsafe { GetDiskFreeSpaceExW(path.as_ptr(), &mut available, std::ptr::null_mut(), std::ptr::null_mut()) } == 0 {
+		return None;
+	}
+	Some(available)
+}
+
+/// Returns the space available to the current user on the filesystem containing `path`; unsupported on this platform.
+#[cfg(not(any(all(target_os = "linux", target_pointer_width = "64"), target_os = "windows")))]
+pub fn available_space(_path: &Path) -> Option<u64> {
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		io::{BufRead, BufReader, Write},
+		net::TcpListener,
+		thread
+	};
+
+	use sha2::Digest;
+
+	use super::*;
+
+	#[test]
+	fn test_fetch_file() {
+		let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = listener.local_addr().unwrap();
+		let server = {
+			let data = data.clone();
+			thread::spawn(move || {
+				let (mut stream, _) = listener.accept().unwrap();
+				let mut reader = BufReader::new(stream.try_clone().unwrap());
+				let mut line = String::new();
+				while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+					line.clear();
+				}
+				write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", data.len()).unwrap();
+				stream.write_all(&data).unwrap();
+			})
+		};
+
+		let dir = std::env::temp_dir().join(format!("ort-sys-fetch-test-{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+		let dest = dir.join("file.tgz");
+
+		let digest = fetch_file(&format!("http://{addr}/file.tgz"), &dest);
+		server.join().unwrap();
+
+		assert_eq!(fs::read(&dest).unwrap(), data);
+		assert_eq!(digest, sha2::Sha256::digest(&data).to_vec());
+		assert!(!dest.with_extension("part").exists());
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[test]
+	fn test_stream_to_file_length_mismatch() {
+		// e.g. a proxy decompressed the response, so the body is larger than `Content-Length`
+		let data: Vec<u8> = (0..100_000u32).map(|i| (i % 13) as u8).collect();
+
+		let dir = std::env::temp_dir().join(format!("ort-sys-stream-test-{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+		let dest = dir.join("file.tgz");
+
+		let mut last_progress = (0, 0);
+		let (digest, downloaded) = stream_to_file(&data[..], 40_000, &dest, |downloaded, total| last_progress = (downloaded, total)).unwrap();
+		assert_eq!(downloaded, data.len() as u64);
+		assert_eq!(last_progress, (data.len() as u64, 40_000));
+		assert_eq!(fs::read(&dest).unwrap(), data);
+		// the hash is what actually determines whether the download is valid
+		assert_eq!(digest, sha2::Sha256::digest(&data).to_vec());
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[test]
+	fn test_binary_cache_dir() {
+		let dir = binary_cache_dir(Some("/mnt/ci-cache".into()), "x86_64-unknown-linux-gnu", "ABCD").unwrap();
+		assert_eq!(dir, Path::new("/mnt/ci-cache").join("dfbin").join("x86_64-unknown-linux-gnu").join("ABCD"));
+
+		// an empty override is treated as unset
+		assert_eq!(binary_cache_dir(Some("".into()), "x86_64-unknown-linux-gnu", "ABCD"), binary_cache_dir(None, "x86_64-unknown-linux-gnu", "ABCD"));
+	}
+
+	#[test]
+	fn test_check_disk_space() {
+		let dir = Path::new("/tmp/ort-cache");
+		assert!(check_disk_space(dir, 1024, Some(4096)).is_ok());
+		assert!(check_disk_space(dir, 1024, None).is_ok());
+
+		// e.g. a CI runner with a small tmpfs
+		let err = check_disk_space(dir, 700_000_000, Some(64 * 1024 * 1024)).unwrap_err();
+		assert!(err.starts_with("Not enough disk space to extract ONNX Runtime binaries to `/tmp/ort-cache`"), "unexpected error: {err}");
+		assert!(err.contains("700000000 bytes are required, but only 67108864 bytes are available"), "unexpected error: {err}");
+
+		#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+		assert!(available_space(&std::env::temp_dir()).is_some_and(|available| available > 0));
+	}
+
+	#[test]
+	fn test_gzip_uncompressed_size() {
+		let path = std::env::temp_dir().join(format!("ort-sys-isize-test-{}.tgz", std::process::id()));
+		// only the trailer is read: CRC32, followed by the uncompressed size as a little-endian `u32`
+		fs::write(&path, [0x1f, 0x8b, 0xde, 0xad, 0xbe, 0xef, 0x00, 0xe1, 0xf5, 0x05]).unwrap();
+		assert_eq!(gzip_uncompressed_size(&path).unwrap(), 100_000_000);
+		fs::remove_file(&path).unwrap();
+	}
+}
@@ -1 +1,5 @@
 pub mod dirs;
+#[cfg(test)]
+mod download;
+#[cfg(test)]
+mod pkg_config;
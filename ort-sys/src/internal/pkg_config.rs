@@ -0,0 +1,69 @@
+// locating a system-installed ONNX Runtime via pkg-config; used by the build script when `pkg-config` is enabled
+
+use std::{ffi::OsStr, path::PathBuf, process::Command};
+
+/// The name of the pkg-config package installed by ONNX Runtime's CMake build (`libonnxruntime.pc`).
+pub const PKG_NAME: &str = "libonnxruntime";
+
+/// Queries `pkg_config` for the linker flags of [`PKG_NAME`], returning the directory containing `libonnxruntime`, or
+/// an empty path if it is in one of the linker's default search directories.
+///
+/// Returns `None` if pkg-config could not be run, or the package could not be found.
+pub fn probe(pkg_config: &OsStr) -> Option<PathBuf> {
+	let output = Command::new(pkg_config).args(["--libs", PKG_NAME]).output().ok()?;
+	if !output.status.success() {
+		return None;
+	}
+
+	let libs = String::from_utf8(output.stdout).ok()?;
+	let mut lib_dir = None;
+	let mut links_onnxruntime = false;
+	for flag in libs.split_whitespace() {
+		if let Some(dir) = flag.strip_prefix("-L") {
+			lib_dir.get_or_insert_with(|| PathBuf::from(dir));
+		} else if flag == "-lonnxruntime" {
+			links_onnxruntime = true;
+		}
+	}
+	links_onnxruntime.then(|| lib_dir.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env, fs};
+
+	use super::*;
+
+	#[test]
+	#[cfg(unix)]
+	fn test_probe() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let dir = env::temp_dir().join(format!("ort-sys-pkg-config-test-{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+
+		let fake_pkg_config = dir.join("pkg-config");
+		let script = format!(
+			"#!/bin/sh\nif [ \"$1\" = \"--libs\" ] && [ \"$2\" = \"{PKG_NAME}\" ]; then\n\techo \"-L/usr/lib/onnxruntime -lonnxruntime -pthread\"\nelse\n\texit 1\nfi\n"
+		);
+		fs::write(&fake_pkg_config, script).unwrap();
+		fs::set_permissions(&fake_pkg_config, fs::Permissions::from_mode(0o755)).unwrap();
+
+		assert_eq!(probe(fake_pkg_config.as_os_str()), Some(PathBuf::from("/usr/lib/onnxruntime")));
+
+		// the library may be in a default search directory, in which case pkg-config doesn't report one
+		let system_pkg_config = dir.join("system-pkg-config");
+		fs::write(&system_pkg_config, "#!/bin/sh\necho \"-lonnxruntime\"\n").unwrap();
+		fs::set_permissions(&system_pkg_config, fs::Permissions::from_mode(0o755)).unwrap();
+		assert_eq!(probe(system_pkg_config.as_os_str()), Some(PathBuf::new()));
+
+		// a failing pkg-config, or one that doesn't exist, should fall back
+		let failing_pkg_config = dir.join("failing-pkg-config");
+		fs::write(&failing_pkg_config, "#!/bin/sh\nexit 1\n").unwrap();
+		fs::set_permissions(&failing_pkg_config, fs::Permissions::from_mode(0o755)).unwrap();
+		assert_eq!(probe(failing_pkg_config.as_os_str()), None);
+		assert_eq!(probe(dir.join("does-not-exist").as_os_str()), None);
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+}
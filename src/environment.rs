@@ -1,11 +1,17 @@
-use std::{cell::UnsafeCell, ffi::CString, sync::atomic::AtomicPtr, sync::Arc};
+use std::{
+	cell::UnsafeCell,
+	ffi::CString,
+	sync::atomic::{AtomicPtr, Ordering},
+	sync::{Arc, Mutex}
+};
 
-use tracing::debug;
+use tracing::{debug, warn};
 
 use super::{
 	custom_logger,
-	error::{Error, Result},
-	ortsys, ExecutionProviderDispatch
+	error::{status_to_result, Error, Result},
+	memory::CustomAllocatorShim,
+	ortsys, AllocationDevice, AllocatorType, ArenaConfig, CustomAllocator, ExecutionProviderDispatch, MemoryInfo, MemoryType
 };
 #[cfg(feature = "load-dynamic")]
 use crate::G_ORT_DYLIB_PATH;
@@ -18,10 +24,30 @@ unsafe impl Sync for EnvironmentSingleton {}
 
 static G_ENV: EnvironmentSingleton = EnvironmentSingleton { cell: UnsafeCell::new(None) };
 
+/// ONNX Runtime shares one `OrtEnv` (and thus one logger) between all live environments, so the log category filter
+/// of the environment which created it must stay alive until every environment is released. This tracks the number of
+/// live environments, and the filter passed to ONNX Runtime when the `OrtEnv` was created.
+static G_LOGGER: Mutex<(usize, Option<Arc<LogCategoryFilter>>)> = Mutex::new((0, None));
+
+/// An ONNX Runtime environment, holding process-wide state such as logging and (optionally) the global thread pools.
+///
+/// Sessions use the global environment (see [`init`]) by default, which is created lazily the first time a session is
+/// created. An environment can also be created explicitly with [`EnvironmentBuilder::build`] and shared between many
+/// sessions via [`SessionBuilder::with_environment`](crate::SessionBuilder::with_environment).
 #[derive(Debug)]
-pub(crate) struct Environment {
+pub struct Environment {
 	pub(crate) execution_providers: Vec<ExecutionProviderDispatch>,
-	pub(crate) env_ptr: AtomicPtr<ort_sys::OrtEnv>
+	pub(crate) env_ptr: AtomicPtr<ort_sys::OrtEnv>,
+	/// Custom allocators registered with the environment; these must outlive it. ONNX Runtime holds pointers to the
+	/// shims, so they're boxed to keep their addresses stable.
+	#[allow(clippy::vec_box)]
+	custom_allocators: Vec<Box<CustomAllocatorShim>>,
+	/// The limit of the CPU arena registered with the environment, if one was registered; either via
+	/// [`SessionBuilder::with_cpu_mem_limit`](crate::SessionBuilder::with_cpu_mem_limit), or as a CPU allocator via
+	/// [`EnvironmentBuilder::with_shared_allocator`], in which case the limit may be unset (`Some(None)`).
+	cpu_arena_limit: Mutex<Option<Option<usize>>>,
+	/// The log category filter ONNX Runtime's logger is using, which is released after the environment.
+	_log_category_filter: Option<Arc<LogCategoryFilter>>
 }
 
 impl Drop for Environment {
@@ -32,7 +58,53 @@ impl Drop for Environment {
 		debug!("Releasing environment");
 
 		assert_ne!(env_ptr, std::ptr::null_mut());
+		for shim in &self.custom_allocators {
+			let status = ortsys![unsafe UnregisterAllocator(env_ptr, shim.memory_info.ptr)];
+			if let Err(e) = status_to_result(status) {
+				warn!("Failed to unregister custom allocator: {e}");
+			}
+		}
 		ortsys![unsafe ReleaseEnv(env_ptr)];
+
+		let mut logger = G_LOGGER.lock().unwrap_or_else(|e| e.into_inner());
+		logger.0 -= 1;
+		if logger.0 == 0 {
+			// the `OrtEnv` has been released, so the filter (held by this environment until it's dropped) is unused now
+			logger.1 = None;
+		}
+	}
+}
+
+impl Environment {
+	/// Returns the global environment, creating a default one if it has not yet been initialized.
+	pub fn current() -> Result<Arc<Environment>> {
+		get_environment().map(Arc::clone)
+	}
+
+	/// Registers a CPU arena allocator limited to `max_mem` bytes, shared by all sessions which use the environment's
+	/// allocators. Registering the same limit again is a no-op; any other CPU arena already registered with the
+	/// environment (including one from [`EnvironmentBuilder::with_shared_allocator`]) is a conflict.
+	pub(crate) fn register_cpu_arena(&self, max_mem: usize) -> Result<()> {
+		let mut limit = self.cpu_arena_limit.lock().unwrap_or_else(|e| e.into_inner());
+		match *limit {
+			Some(Some(registered)) if registered == max_mem => return Ok(()),
+			Some(registered) => return Err(Error::CpuMemLimitConflict { requested: max_mem, registered }),
+			None => {}
+		}
+
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Arena, MemoryType::Default)?;
+		let arena_cfg_ptr = ArenaConfig {
+			max_mem: Some(max_mem),
+			..ArenaConfig::default()
+		}
+		.create_raw()?;
+		let status = ortsys![unsafe CreateAndRegisterAllocator(self.env_ptr.load(Ordering::Relaxed), memory_info.ptr, arena_cfg_ptr)];
+		ortsys![unsafe ReleaseArenaCfg(arena_cfg_ptr)];
+		status_to_result(status).map_err(Error::RegisterSharedAllocator)?;
+		debug!("Registered CPU arena limited to {max_mem} bytes");
+
+		*limit = Some(Some(max_mem));
+		Ok(())
 	}
 }
 
@@ -47,11 +119,32 @@ pub(crate) fn get_environment() -> Result<&'static Arc<Environment>> {
 	}
 }
 
+/// Decides which of ONNX Runtime's log messages are forwarded to `tracing` based on their category; see
+/// [`EnvironmentBuilder::with_log_category_filter`]. A pointer to it is passed to ONNX Runtime as the logger parameter.
+pub(crate) struct LogCategoryFilter(pub(crate) Box<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl std::fmt::Debug for LogCategoryFilter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("LogCategoryFilter").finish_non_exhaustive()
+	}
+}
+
+impl LogCategoryFilter {
+	pub(crate) fn allows(&self, category: &str) -> bool {
+		(self.0)(category)
+	}
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct EnvironmentGlobalThreadPoolOptions {
 	pub inter_op_parallelism: Option<i32>,
 	pub intra_op_parallelism: Option<i32>,
+	/// Whether the global thread pools' threads spin while waiting for work, trading CPU usage for lower latency.
+	/// Spinning is enabled by default; see
+	/// [`SessionBuilder::with_intra_op_spinning`](crate::SessionBuilder::with_intra_op_spinning) for the tradeoffs.
 	pub spin_control: Option<bool>,
+	/// Pins the global intra-op threads to specific logical processors. Uses the same syntax as
+	/// [`SessionBuilder::with_intra_op_thread_affinity`](crate::SessionBuilder::with_intra_op_thread_affinity).
 	pub intra_op_thread_affinity: Option<String>
 }
 
@@ -68,7 +161,10 @@ pub struct EnvironmentGlobalThreadPoolOptions {
 pub struct EnvironmentBuilder {
 	name: String,
 	execution_providers: Vec<ExecutionProviderDispatch>,
-	global_thread_pool_options: Option<EnvironmentGlobalThreadPoolOptions>
+	global_thread_pool_options: Option<EnvironmentGlobalThreadPoolOptions>,
+	shared_allocators: Vec<(MemoryInfo, ArenaConfig)>,
+	custom_allocators: Vec<Box<dyn CustomAllocator>>,
+	log_category_filter: Option<LogCategoryFilter>
 }
 
 impl Default for EnvironmentBuilder {
@@ -76,13 +172,20 @@ impl Default for EnvironmentBuilder {
 		EnvironmentBuilder {
 			name: "default".to_string(),
 			execution_providers: vec![],
-			global_thread_pool_options: None
+			global_thread_pool_options: None,
+			shared_allocators: vec![],
+			custom_allocators: vec![],
+			log_category_filter: None
 		}
 	}
 }
 
 impl EnvironmentBuilder {
-	/// Configure the environment with a given name
+	/// Configure the environment with a given name.
+	///
+	/// The name is used as ONNX Runtime's log identifier, and is attached to every log event `ort` forwards to
+	/// `tracing` as the `id` field of the `ort` span. This makes it possible to tell apart logs from different
+	/// components in the same process.
 	///
 	/// **NOTE**: Since ONNX can only define one environment per process, creating multiple environments using multiple
 	/// [`EnvironmentBuilder`]s will end up re-using the same environment internally; a new one will _not_ be created.
@@ -95,9 +198,14 @@ impl EnvironmentBuilder {
 		self
 	}
 
-	/// Configures a list of execution providers sessions created under this environment will use by default. Sessions
-	/// may override these via
-	/// [`SessionBuilder::with_execution_providers`](crate::SessionBuilder::with_execution_providers).
+	/// Configures a list of execution providers sessions created under this environment will use by default, so they
+	/// don't need to be configured for each session.
+	///
+	/// Execution providers configured on a session via
+	/// [`SessionBuilder::with_execution_providers`](crate::SessionBuilder::with_execution_providers) are registered
+	/// first and thus take priority, but the environment's execution providers are still registered after them. Use
+	/// [`Session::registered_execution_providers`](crate::Session::registered_execution_providers) to check which
+	/// execution providers were registered for a session.
 	///
 	/// Execution providers are loaded in the order they are provided until a suitable execution provider is found. Most
 	/// execution providers will silently fail if they are unavailable or misconfigured (see notes below), however, some
@@ -144,15 +252,86 @@ impl EnvironmentBuilder {
 		self
 	}
 
-	/// Commit the configuration to a new [`Environment`].
+	/// Registers an arena allocator for the memory described by `memory_info` that is shared between all sessions in
+	/// this environment, instead of each session creating its own arena.
+	///
+	/// Sharing an arena reduces memory fragmentation and overall usage when many (especially small) models are loaded
+	/// at once. Sessions only use the shared allocator if they are created with
+	/// [`SessionBuilder::with_use_env_allocators`](crate::SessionBuilder::with_use_env_allocators).
+	pub fn with_shared_allocator(mut self, memory_info: MemoryInfo, arena_config: ArenaConfig) -> EnvironmentBuilder {
+		self.shared_allocators.push((memory_info, arena_config));
+		self
+	}
+
+	/// Registers a [`CustomAllocator`] implemented in Rust with this environment, so ONNX Runtime allocates memory
+	/// described by [`CustomAllocator::info`] through it. Only one allocator may be registered for a given device.
+	///
+	/// As with [`EnvironmentBuilder::with_shared_allocator`], sessions only use the allocator if they are created with
+	/// [`SessionBuilder::with_use_env_allocators`](crate::SessionBuilder::with_use_env_allocators). The allocator is
+	/// kept alive for as long as the environment.
+	pub fn with_custom_allocator(mut self, allocator: impl CustomAllocator + 'static) -> EnvironmentBuilder {
+		self.custom_allocators.push(Box::new(allocator));
+		self
+	}
+
+	/// Only forwards ONNX Runtime log messages whose category `filter` returns `true` for to `tracing`; other messages
+	/// are dropped before they reach any subscriber.
+	///
+	/// ONNX Runtime tags each message with a category describing where it came from, e.g. `onnxruntime` for most of
+	/// the runtime's own messages, or `VLOG0`, `VLOG1`, etc. for verbose logging (see
+	/// [`SessionBuilder::with_log_verbosity_level`](crate::SessionBuilder::with_log_verbosity_level)). Forwarded
+	/// messages carry their category as the `category` field of the `ort` span, so it can also be filtered on by the
+	/// subscriber, but filtering here avoids formatting messages that would be discarded anyway.
+	///
+	/// ```no_run
+	/// # fn main() -> ort::Result<()> {
+	/// ort::init().with_log_category_filter(|category| !category.starts_with("VLOG")).commit()?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// As with the environment's other parameters, the filter is ignored if the environment re-uses one that is still
+	/// alive (see [`EnvironmentBuilder::build`]).
+	pub fn with_log_category_filter(mut self, filter: impl Fn(&str) -> bool + Send + Sync + 'static) -> EnvironmentBuilder {
+		self.log_category_filter = Some(LogCategoryFilter(Box::new(filter)));
+		self
+	}
+
+	/// Commit the configuration to a new [`Environment`], replacing the global environment.
 	pub fn commit(self) -> Result<()> {
 		// drop global reference to previous environment
 		drop(unsafe { (*G_ENV.cell.get()).take() });
 
+		let env = self.build()?;
+		unsafe {
+			*G_ENV.cell.get() = Some(env);
+		};
+
+		Ok(())
+	}
+
+	/// Builds a new [`Environment`] without replacing the global environment. The environment can be shared between
+	/// sessions with [`SessionBuilder::with_environment`](crate::SessionBuilder::with_environment).
+	///
+	/// **NOTE**: ONNX Runtime only supports one environment per process; if another environment is still alive, the
+	/// returned environment will re-use it internally, and the new parameters will be ignored.
+	pub fn build(self) -> Result<Arc<Environment>> {
+		#[cfg(feature = "load-dynamic")]
+		crate::check_dylib_version()?;
+
+		// hold the lock until the environment is created, so that another environment can't be created or released
+		// in between deciding which filter the `OrtEnv` uses & creating it
+		let mut logger = G_LOGGER.lock().unwrap_or_else(|e| e.into_inner());
+		// if another environment is alive, ONNX Runtime reuses its `OrtEnv` & ignores our logger parameter
+		let log_category_filter = if logger.0 == 0 { self.log_category_filter.map(Arc::new) } else { logger.1.clone() };
+		let logger_param: *mut std::ffi::c_void = match &log_category_filter {
+			Some(filter) => Arc::as_ptr(filter) as *mut std::ffi::c_void,
+			None => std::ptr::null_mut()
+		};
+
 		let env_ptr = if let Some(global_thread_pool) = self.global_thread_pool_options {
 			let mut env_ptr: *mut ort_sys::OrtEnv = std::ptr::null_mut();
 			let logging_function: ort_sys::OrtLoggingFunction = Some(custom_logger);
-			let logger_param: *mut std::ffi::c_void = std::ptr::null_mut();
 			let cname = CString::new(self.name.clone()).unwrap();
 
 			let mut thread_options: *mut ort_sys::OrtThreadingOptions = std::ptr::null_mut();
@@ -184,8 +363,6 @@ impl EnvironmentBuilder {
 		} else {
 			let mut env_ptr: *mut ort_sys::OrtEnv = std::ptr::null_mut();
 			let logging_function: ort_sys::OrtLoggingFunction = Some(custom_logger);
-			// FIXME: What should go here?
-			let logger_param: *mut std::ffi::c_void = std::ptr::null_mut();
 			let cname = CString::new(self.name.clone()).unwrap();
 			ortsys![unsafe CreateEnvWithCustomLogger(
 					logging_function,
@@ -197,15 +374,38 @@ impl EnvironmentBuilder {
 			env_ptr
 		};
 		debug!(env_ptr = format!("{:?}", env_ptr).as_str(), "Environment created");
-
-		unsafe {
-			*G_ENV.cell.get() = Some(Arc::new(Environment {
-				execution_providers: self.execution_providers,
-				env_ptr: AtomicPtr::new(env_ptr)
-			}));
+		logger.0 += 1;
+		logger.1 = log_category_filter.clone();
+		drop(logger);
+
+		// wrap the environment first so it is released if registering an allocator fails
+		let mut env = Environment {
+			execution_providers: self.execution_providers,
+			env_ptr: AtomicPtr::new(env_ptr),
+			custom_allocators: Vec::with_capacity(self.custom_allocators.len()),
+			cpu_arena_limit: Mutex::new(None),
+			_log_category_filter: log_category_filter
 		};
 
-		Ok(())
+		for (memory_info, arena_config) in &self.shared_allocators {
+			let arena_cfg_ptr = arena_config.create_raw()?;
+			let status = ortsys![unsafe CreateAndRegisterAllocator(env_ptr, memory_info.ptr, arena_cfg_ptr)];
+			ortsys![unsafe ReleaseArenaCfg(arena_cfg_ptr)];
+			status_to_result(status).map_err(Error::RegisterSharedAllocator)?;
+			// ONNX Runtime only allows one shared allocator per device, so `with_cpu_mem_limit` can't register another
+			if matches!(memory_info.allocation_device(), Ok(AllocationDevice::CPU)) {
+				*env.cpu_arena_limit.get_mut().unwrap_or_else(|e| e.into_inner()) = Some(arena_config.max_mem);
+			}
+		}
+
+		for allocator in self.custom_allocators {
+			let mut shim = CustomAllocatorShim::new(allocator)?;
+			status_to_result(ortsys![unsafe RegisterAllocator(env_ptr, shim.as_ptr())]).map_err(Error::RegisterSharedAllocator)?;
+			// only track the allocator once it's registered so `Drop` doesn't try to unregister it
+			env.custom_allocators.push(shim);
+		}
+
+		Ok(Arc::new(env))
 	}
 }
 
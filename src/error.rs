@@ -33,6 +33,18 @@ pub enum Error {
 	/// Error occurred when creating an ONNX session.
 	#[error("Failed to create ONNX Runtime session: {0}")]
 	CreateSession(ErrorInternal),
+	/// The model uses a newer opset than the loaded ONNX Runtime binary supports.
+	#[error(
+		"The model uses opset {model_opset} for domain `{domain}`, but this version of ONNX Runtime only supports up to opset {max_supported_opset}. Try upgrading ONNX Runtime, or re-export the model with an older opset."
+	)]
+	UnsupportedOpset {
+		/// The operator set domain, e.g. `ai.onnx`.
+		domain: String,
+		/// The opset version the model was exported with.
+		model_opset: u32,
+		/// The newest opset version supported by the loaded ONNX Runtime binary for this domain.
+		max_supported_opset: u32
+	},
 	/// Error occurred when creating an IO binding.
 	#[error("Failed to create IO binding: {0}")]
 	CreateIoBinding(ErrorInternal),
@@ -66,6 +78,10 @@ pub enum Error {
 	/// Error occurred when getting sequence element type
 	#[error("Failed to get sequence element type: {0}")]
 	GetSequenceElementType(ErrorInternal),
+	/// The type of a value or model input/output is not supported by `ort`, e.g. optional or opaque types, or maps with
+	/// non-primitive values.
+	#[error("Unsupported value type: {0:?}")]
+	UnsupportedValueType(ort_sys::ONNXType),
 	/// Error occurred when getting tensor elements type
 	#[error("Failed to get tensor element type: {0}")]
 	GetTensorElementType(ErrorInternal),
@@ -75,6 +91,9 @@ pub enum Error {
 	/// Error occurred when getting ONNX dimensions
 	#[error("Failed to get dimensions: {0}")]
 	GetDimensions(ErrorInternal),
+	/// Error occurred when getting the symbolic names of ONNX dimensions
+	#[error("Failed to get symbolic dimensions: {0}")]
+	GetSymbolicDimensions(ErrorInternal),
 	/// Error occurred when getting string length
 	#[error("Failed to get string tensor length: {0}")]
 	GetStringTensorDataLength(ErrorInternal),
@@ -129,6 +148,12 @@ pub enum Error {
 	/// Dimensions of input data and the ONNX model do not match.
 	#[error("Dimensions do not match: {0:?}")]
 	NonMatchingDimensions(NonMatchingDimensionsError),
+	/// The session was created via [`crate::Session::from_raw`], so it has no options to reload with.
+	#[error("Sessions created from a raw pointer cannot be reloaded")]
+	ReloadUnavailable,
+	/// The requested functionality is not available in the version of ONNX Runtime `ort` is built against.
+	#[error("Unsupported by this version of ONNX Runtime: {0}")]
+	Unsupported(String),
 	/// File does not exist
 	#[error("File `{filename:?}` does not exist")]
 	FileDoesNotExist {
@@ -168,12 +193,61 @@ pub enum Error {
 		/// The type corresponding to the attempted conversion into a Rust type, not equal to `actual`
 		requested: TensorElementType
 	},
+	#[error("Cannot view a value that is {0:?} as a tensor")]
+	NotTensor(ValueType),
+	#[error("String tensors cannot be viewed without copying; use `Value::extract_tensor` instead")]
+	StringTensorView,
+	/// The tensor's data lives in device memory (e.g. on a CUDA device), so it can't be viewed from the host.
+	#[error("Tensor data is not in CPU memory and cannot be viewed")]
+	TensorNotInCpuMemory,
+	/// [`crate::Value::try_view_tensor_mut`] was called on a value which shares or borrows its data.
+	#[error("Tensor data is shared with or borrowed from elsewhere and cannot be mutated")]
+	TensorNotMutable,
+	/// [`crate::Value::try_extract_scalar`] was called on a tensor that doesn't have exactly one element.
+	#[error("Expected a tensor with exactly one element, but it has shape {0:?}")]
+	NotScalar(Vec<i64>),
+	#[cfg(feature = "ndarray")]
+	#[error("Tensor of shape {0:?} cannot be viewed with the requested dimensionality: {1}")]
+	InvalidViewShape(Vec<i64>, ndarray::ShapeError),
+	/// An array passed to [`crate::Value::from_array_view`] was not in standard layout.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	#[error("Array is not contiguous in memory; use `Value::from_array_contiguous` to copy it into a contiguous buffer")]
+	NonContiguousArray,
+	/// A boolean tensor contained a byte other than `0` or `1`.
+	#[error("Boolean tensor contains invalid value `{0}`; expected 0 or 1")]
+	InvalidBoolValue(u8),
 	#[error("Error trying to load symbol `{symbol}` from dynamic library: {error}")]
 	DlLoad { symbol: &'static str, error: String },
 	#[error("{0}")]
 	ExecutionProvider(ErrorInternal),
+	/// The CUDA execution provider could not be registered because a CUDA library it depends on (such as cuDNN) could
+	/// not be loaded, usually because it isn't installed or is the wrong version.
+	#[error("{message}\nhint: {hint}")]
+	CudaLibraryLoad {
+		/// The error message reported by ONNX Runtime.
+		message: String,
+		/// The file name of the library that failed to load, e.g. `libcudnn.so.8`.
+		library: String,
+		/// A suggestion on how to fix the error.
+		hint: String
+	},
 	#[error("Execution provider `{0}` was not registered because its corresponding Cargo feature is disabled.")]
 	ExecutionProviderNotRegistered(&'static str),
+	/// An execution provider was skipped because it is not available in the loaded build of ONNX Runtime.
+	#[error("Execution provider `{0}` is not available in this build of ONNX Runtime")]
+	ExecutionProviderUnavailable(&'static str),
+	/// An execution provider marked with [`ExecutionProviderDispatch::error_on_failure`](crate::ExecutionProviderDispatch::error_on_failure)
+	/// could not be registered.
+	#[error("Required execution provider `{name}` could not be registered ({reason}); registered execution providers: {registered:?}")]
+	RequiredExecutionProviderNotRegistered {
+		/// The name of the required execution provider, or the names of its alternatives separated by ` | `.
+		name: String,
+		/// Why the (last alternative of the) execution provider failed to register.
+		reason: String,
+		/// The execution providers that were registered successfully.
+		registered: Vec<&'static str>
+	},
 	#[error("Expected tensor to be on CPU in order to get data, but had allocation device `{0}`.")]
 	TensorNotOnCpu(&'static str),
 	#[error("String tensors require the session's allocator to be provided through `Value::from_array`.")]
@@ -186,10 +260,32 @@ pub enum Error {
 	GetAvailableProviders(ErrorInternal),
 	#[error("Unknown allocation device `{0}`")]
 	UnknownAllocationDevice(String),
+	#[error("Tensors borrowing Rust data must be described by a CPU `MemoryInfo`, but the given `MemoryInfo` describes {0:?} memory")]
+	NonCpuMemoryInfo(crate::MemoryDeviceType),
 	#[error("Error when binding input: {0}")]
 	BindInput(ErrorInternal),
 	#[error("Error when binding output: {0}")]
 	BindOutput(ErrorInternal),
+	#[error("Output buffer is too small: tensor requires {required} bytes, but buffer is {actual} bytes")]
+	OutputBufferTooSmall { required: usize, actual: usize },
+	#[error("Output buffer is not aligned to {alignment} bytes, as required by its element type")]
+	OutputBufferMisaligned { alignment: usize },
+	#[error("Cannot bind a tensor of type {0:?} to a raw buffer")]
+	UnsupportedBufferElementType(TensorElementType),
+	#[error("Invalid dimension `{0}`; tensor dimensions must be non-negative")]
+	InvalidDimension(i64),
+	#[error("Failed to create custom operator domain: {0}")]
+	CreateCustomOpDomain(ErrorInternal),
+	#[error("Failed to add custom operator domain to session options: {0}")]
+	AddCustomOpDomain(ErrorInternal),
+	#[error("Failed to register shared allocator: {0}")]
+	RegisterSharedAllocator(ErrorInternal),
+	#[error("Shape {shape:?} describes {expected} elements, but {actual} were provided")]
+	ShapeDataMismatch { shape: Vec<i64>, expected: usize, actual: usize },
+	#[error("Shape {0:?} describes more elements than fit in memory")]
+	ShapeTooLarge(Vec<i64>),
+	#[error("Expected {expected} bytes of {element_type:?} tensor data, but {actual} were provided")]
+	TensorBytesLengthMismatch { element_type: TensorElementType, expected: usize, actual: usize },
 	#[error("Failed to clear IO binding: {0}")]
 	ClearBinding(ErrorInternal),
 	#[error("Error when retrieving session outputs from `IoBinding`: {0}")]
@@ -205,7 +301,131 @@ pub enum Error {
 	#[error("Error occurred while attempting to extract data from sequence value: {0}")]
 	ExtractSequence(ErrorInternal),
 	#[error("Error occurred while attempting to extract data from map value: {0}")]
-	ExtractMap(ErrorInternal)
+	ExtractMap(ErrorInternal),
+	#[error("The loaded ONNX Runtime binary does not support the training API.")]
+	TrainingNotSupported,
+	#[error("Failed to load training checkpoint: {0}")]
+	LoadCheckpoint(ErrorInternal),
+	#[error("Failed to save training checkpoint: {0}")]
+	SaveCheckpoint(ErrorInternal),
+	#[error("Failed to create training session: {0}")]
+	CreateTrainingSession(ErrorInternal),
+	#[error("Missing input `{0}` for training session")]
+	TrainingInputMissing(String),
+	#[error("Failed to run training step: {0}")]
+	TrainStep(ErrorInternal),
+	#[error("Failed to run evaluation step: {0}")]
+	EvalStep(ErrorInternal),
+	#[error("Failed to run optimizer step: {0}")]
+	OptimizerStep(ErrorInternal),
+	#[error("Failed to reset gradients: {0}")]
+	LazyResetGrad(ErrorInternal),
+	#[error("Failed to set learning rate: {0}")]
+	SetLearningRate(ErrorInternal),
+	#[error("Failed to get learning rate: {0}")]
+	GetLearningRate(ErrorInternal),
+	#[error("Failed to export inference model: {0}")]
+	ExportModel(ErrorInternal),
+	/// The ONNX Runtime binary loaded with `load-dynamic` is older than [`crate::MIN_ORT_VERSION`].
+	#[cfg(feature = "load-dynamic")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "load-dynamic")))]
+	#[error(
+		"The ONNX Runtime binary at `{path}` reports version {version}, which is not compatible with this version of ort (expected {}.{} or newer). Set `ORT_SKIP_VERSION_CHECK=1` to bypass this check.",
+		crate::MIN_ORT_VERSION.0, crate::MIN_ORT_VERSION.1
+	)]
+	IncompatibleVersion { path: String, version: String },
+	/// The ONNX Runtime dylib could not be loaded.
+	#[cfg(feature = "load-dynamic")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "load-dynamic")))]
+	#[error("An error occurred while attempting to load the ONNX Runtime binary at `{path}`: {error}")]
+	LoadLibrary { path: String, error: String },
+	/// The region of a memory mapping passed to `Value::from_mmap` does not fit within the mapping.
+	#[cfg(feature = "memmap")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "memmap")))]
+	#[error("Tensor of {len} bytes at offset {offset} does not fit in memory mapping of {map_len} bytes")]
+	MmapOutOfBounds { offset: usize, len: usize, map_len: usize },
+	/// The data passed to `Value::from_mmap` is not aligned to the size of its element type.
+	#[cfg(feature = "memmap")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "memmap")))]
+	#[error("Tensor data at offset {offset} in memory mapping is not aligned to {alignment} bytes")]
+	MmapMisaligned { offset: usize, alignment: usize },
+	/// The requested output does not exist in the model.
+	#[error("Model has no output named `{0}`")]
+	UnknownOutputName(String),
+	/// The same output was requested more than once from [`crate::Session::run_extract_many`].
+	#[error("Output `{0}` was requested more than once")]
+	DuplicateOutputName(String),
+	/// The number of shapes passed to [`crate::SessionBuilder::commit_and_warmup`] does not match the
+	/// number of model inputs.
+	#[error("Model has {expected} inputs, but {actual} warmup shapes were provided")]
+	WarmupShapeCount { expected: usize, actual: usize },
+	/// The number of input values passed to a session does not match the number of inputs they're given for, e.g. if
+	/// [`crate::Session::run_positional`] was passed more or fewer inputs than the model has.
+	#[error("Model has {expected} inputs, but {actual} were provided")]
+	PositionalInputCount { expected: usize, actual: usize },
+	/// The value of the metadata key passed to [`crate::SessionBuilder::with_metadata_driven_overrides`] is not a list
+	/// of `name=size` dimension overrides.
+	#[error("Invalid dimension overrides in metadata key `{key}`: `{value}` (expected comma-separated `name=size` pairs)")]
+	InvalidMetadataOverrides { key: String, value: String },
+	/// The shape of a tensor passed to [`crate::Value::to_image`] does not match the requested layout.
+	#[cfg(feature = "image")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+	#[error("Tensor of shape {shape:?} is not a single RGB image in {layout:?} layout")]
+	InvalidImageShape { layout: crate::ImageLayout, shape: Vec<i64> },
+	/// A session was built with a [CPU memory limit](crate::SessionBuilder::with_cpu_mem_limit) different from the one
+	/// already registered with its environment. `registered` is `None` if the environment has an unlimited CPU allocator
+	/// registered via [`crate::EnvironmentBuilder::with_shared_allocator`].
+	#[error(
+		"Cannot limit the CPU arena to {requested} bytes, since {} was already registered with the environment",
+		.registered.map_or_else(|| "an unlimited CPU allocator".to_string(), |registered| format!("a limit of {registered} bytes"))
+	)]
+	CpuMemLimitConflict { requested: usize, registered: Option<usize> },
+	/// A [`crate::QuantizedTensor`] was created from a value which is not an `Int8` or `Uint8` tensor.
+	#[error("Quantized tensors must be Int8 or Uint8 tensors, got {0}")]
+	InvalidQuantizedTensorType(ValueType),
+	/// The scale or zero point of a [`crate::QuantizedTensor`] is invalid.
+	#[error("Invalid quantization parameters: {0}")]
+	InvalidQuantizationParameters(String),
+	/// [`crate::Session::run_dynamic`] was called on a session with memory pattern optimization enabled.
+	#[error("Memory pattern optimization must be disabled for varying input shapes; use `SessionBuilder::with_memory_pattern(false)`")]
+	MemoryPatternEnabled,
+	/// The model could not be read, either from the reader passed to
+	/// [`SessionBuilder::commit_from_reader`](crate::SessionBuilder::commit_from_reader), or from its file to
+	/// compute [`crate::GraphStats`].
+	#[error("Failed to read model: {0}")]
+	ReadModel(io::Error),
+	/// ONNX Runtime did not log where it placed the model's nodes during
+	/// [`crate::SessionBuilder::check_full_ep_coverage`]'s trial build.
+	#[error("ONNX Runtime did not report node placements for the model")]
+	NodePlacementsNotLogged,
+	/// [`crate::GraphStats`] could not be computed for the session's model.
+	#[error("Failed to compute graph statistics: {0}")]
+	ComputeGraphStats(String),
+	/// Failed to read the profiling file written by ONNX Runtime.
+	#[cfg(feature = "profiling")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+	#[error("Failed to read profiling file: {0}")]
+	ReadProfile(io::Error),
+	/// The profiling file written by ONNX Runtime was not a valid trace.
+	#[cfg(feature = "profiling")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+	#[error("Failed to parse profiling file: {0}")]
+	ParseProfile(serde_json::Error)
+}
+
+impl Error {
+	/// Returns the error message exactly as reported by ONNX Runtime (or the dynamic loader) if this error occurred
+	/// while registering an execution provider, e.g. `libcublasLt.so.11: cannot open shared object file: No such file
+	/// or directory` when CUDA libraries are missing.
+	///
+	/// Returns `None` for any other kind of error.
+	pub fn ep_registration_detail(&self) -> Option<&str> {
+		match self {
+			Error::ExecutionProvider(ErrorInternal::Msg(msg)) | Error::CudaLibraryLoad { message: msg, .. } => Some(msg),
+			Error::DlLoad { error, .. } => Some(error),
+			_ => None
+		}
+	}
 }
 
 impl From<Infallible> for Error {
@@ -327,3 +547,47 @@ pub(crate) fn status_to_result(status: *mut ort_sys::OrtStatus) -> Result<(), Er
 	let status_wrapper: OrtStatusWrapper = status.into();
 	status_wrapper.into()
 }
+
+/// Maps an error from `CreateSession`, detecting models that use an opset newer than ONNX Runtime supports.
+pub(crate) fn create_session_error(e: ErrorInternal) -> Error {
+	if let ErrorInternal::Msg(msg) = &e {
+		if let Some(err) = parse_unsupported_opset(msg) {
+			return err;
+		}
+	}
+	Error::CreateSession(e)
+}
+
+/// Parses ONNX Runtime's error for models stamped with an unreleased opset, which looks like:
+/// `... Opset 99 is under development and support for this is limited. ... Current official support for domain ai.onnx
+/// is till opset 20.`
+fn parse_unsupported_opset(msg: &str) -> Option<Error> {
+	let model_opset = msg.split("Opset ").nth(1)?.split(" is under development").next()?.parse().ok()?;
+	let (domain, max_supported_opset) = msg.split("Current official support for domain ").nth(1)?.split_once(" is till opset ")?;
+	let max_supported_opset = max_supported_opset.trim_end_matches(|c: char| !c.is_ascii_digit()).parse().ok()?;
+	Some(Error::UnsupportedOpset {
+		domain: if domain.is_empty() { "ai.onnx".to_owned() } else { domain.to_owned() },
+		model_opset,
+		max_supported_opset
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_unsupported_opset() {
+		let msg = "Load model from model.onnx failed:/onnxruntime_src/onnxruntime/core/graph/model.cc:149 \
+			onnxruntime::Model::Model(...) ONNX Runtime only *guarantees* support for models stamped with official released onnx \
+			opset versions. Opset 99 is under development and support for this is limited. The operator schemas and or other \
+			functionality may change before next ONNX release and in this case ONNX Runtime will not guarantee backward \
+			compatibility. Current official support for domain ai.onnx is till opset 20.";
+		assert!(matches!(
+			create_session_error(ErrorInternal::Msg(msg.to_owned())),
+			Error::UnsupportedOpset { domain, model_opset: 99, max_supported_opset: 20 } if domain == "ai.onnx"
+		));
+
+		assert!(matches!(create_session_error(ErrorInternal::Msg("Protobuf parsing failed.".to_owned())), Error::CreateSession(_)));
+	}
+}
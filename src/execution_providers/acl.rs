@@ -30,7 +30,7 @@ impl From<ACLExecutionProvider> for ExecutionProviderDispatch {
 
 impl ExecutionProvider for ACLExecutionProvider {
 	fn as_str(&self) -> &'static str {
-		"AclExecutionProvider"
+		"ACLExecutionProvider"
 	}
 
 	#[allow(unused, unreachable_code)]
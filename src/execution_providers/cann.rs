@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use super::ExecutionProvider;
 use crate::{ArenaExtendStrategy, Error, ExecutionProviderDispatch, Result, SessionBuilder};
 
@@ -30,6 +32,7 @@ pub struct CANNExecutionProvider {
 	arena_extend_strategy: Option<ArenaExtendStrategy>,
 	enable_cann_graph: Option<bool>,
 	dump_graphs: Option<bool>,
+	dump_om_model: Option<bool>,
 	precision_mode: Option<CANNExecutionProviderPrecisionMode>,
 	op_select_impl_mode: Option<CANNExecutionProviderImplementationMode>,
 	optypelist_for_impl_mode: Option<String>
@@ -61,12 +64,26 @@ impl CANNExecutionProvider {
 		self
 	}
 
-	/// Configure whether to dump the subgraph into ONNX format for analysis of subgraph segmentation.
+	/// Dump the subgraph into ONNX format for analysis of subgraph segmentation. Graphs are written to the current
+	/// working directory; CANN does not support configuring the output path.
 	pub fn with_dump_graphs(mut self) -> Self {
 		self.dump_graphs = Some(true);
 		self
 	}
 
+	/// Configure whether to dump subgraphs; see [`CANNExecutionProvider::with_dump_graphs`].
+	pub fn with_dump_graphs_enabled(mut self, enable: bool) -> Self {
+		self.dump_graphs = Some(enable);
+		self
+	}
+
+	/// Configure whether to dump the offline model (`.om`) CANN compiles for each subgraph to the current working
+	/// directory. Only takes effect when [`CANNExecutionProvider::with_cann_graph`] is enabled.
+	pub fn with_dump_om_model(mut self, enable: bool) -> Self {
+		self.dump_om_model = Some(enable);
+		self
+	}
+
 	/// Set the precision mode of the operator. See [`CANNExecutionProviderPrecisionMode`].
 	pub fn with_precision_mode(mut self, mode: CANNExecutionProviderPrecisionMode) -> Self {
 		self.precision_mode = Some(mode);
@@ -96,6 +113,33 @@ impl CANNExecutionProvider {
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
+
+	/// Returns the provider option keys & values to pass to `UpdateCANNProviderOptions`.
+	fn options(&self) -> Result<super::ProviderOptions> {
+		Ok(super::map_keys! {
+			device_id = self.device_id,
+			npu_mem_limit = self.npu_mem_limit,
+			arena_extend_strategy = self.arena_extend_strategy.as_ref().map(|v| match v {
+				ArenaExtendStrategy::NextPowerOfTwo => "kNextPowerOfTwo",
+				ArenaExtendStrategy::SameAsRequested => "kSameAsRequested"
+			}),
+			enable_cann_graph = self.enable_cann_graph.map(<bool as Into<i32>>::into),
+			dump_graphs = self.dump_graphs.map(<bool as Into<i32>>::into),
+			dump_om_model = self.dump_om_model.map(<bool as Into<i32>>::into),
+			precision_mode = self.precision_mode.as_ref().map(|v| match v {
+				CANNExecutionProviderPrecisionMode::ForceFP32 => "force_fp32",
+				CANNExecutionProviderPrecisionMode::ForceFP16 => "force_fp16",
+				CANNExecutionProviderPrecisionMode::AllowFP32ToFP16 => "allow_fp32_to_fp16",
+				CANNExecutionProviderPrecisionMode::MustKeepOrigin => "must_keep_origin_dtype",
+				CANNExecutionProviderPrecisionMode::AllowMixedPrecision => "allow_mix_precision"
+			}),
+			op_select_impl_mode = self.op_select_impl_mode.as_ref().map(|v| match v {
+				CANNExecutionProviderImplementationMode::HighPrecision => "high_precision",
+				CANNExecutionProviderImplementationMode::HighPerformance => "high_performance"
+			}),
+			optypelist_for_impl_mode = self.optypelist_for_impl_mode.clone()
+		})
+	}
 }
 
 impl From<CANNExecutionProvider> for ExecutionProviderDispatch {
@@ -109,34 +153,21 @@ impl ExecutionProvider for CANNExecutionProvider {
 		"CANNExecutionProvider"
 	}
 
+	fn check_options(&self) -> Result<()> {
+		self.options().map(drop)
+	}
+
+	fn effective_options(&self) -> HashMap<String, String> {
+		self.options().map(|(_, _, _, keys, values)| super::options_map(&keys, &values)).unwrap_or_default()
+	}
+
 	#[allow(unused, unreachable_code)]
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
 		#[cfg(any(feature = "load-dynamic", feature = "cann"))]
 		{
+			let (key_ptrs, value_ptrs, len, keys, values) = self.options()?;
 			let mut cann_options: *mut ort_sys::OrtCANNProviderOptions = std::ptr::null_mut();
 			crate::error::status_to_result(crate::ortsys![unsafe CreateCANNProviderOptions(&mut cann_options)]).map_err(Error::ExecutionProvider)?;
-			let (key_ptrs, value_ptrs, len, keys, values) = super::map_keys! {
-				device_id = self.device_id,
-				npu_mem_limit = self.npu_mem_limit,
-				arena_extend_strategy = self.arena_extend_strategy.as_ref().map(|v| match v {
-					ArenaExtendStrategy::NextPowerOfTwo => "kNextPowerOfTwo",
-					ArenaExtendStrategy::SameAsRequested => "kSameAsRequested"
-				}),
-				enable_cann_graph = self.enable_cann_graph.map(<bool as Into<i32>>::into),
-				dump_graphs = self.dump_graphs.map(<bool as Into<i32>>::into),
-				precision_mode = self.precision_mode.as_ref().map(|v| match v {
-					CANNExecutionProviderPrecisionMode::ForceFP32 => "force_fp32",
-					CANNExecutionProviderPrecisionMode::ForceFP16 => "force_fp16",
-					CANNExecutionProviderPrecisionMode::AllowFP32ToFP16 => "allow_fp32_to_fp16",
-					CANNExecutionProviderPrecisionMode::MustKeepOrigin => "must_keep_origin_dtype",
-					CANNExecutionProviderPrecisionMode::AllowMixedPrecision => "allow_mix_precision"
-				}),
-				op_select_impl_mode = self.op_select_impl_mode.as_ref().map(|v| match v {
-					CANNExecutionProviderImplementationMode::HighPrecision => "high_precision",
-					CANNExecutionProviderImplementationMode::HighPerformance => "high_performance"
-				}),
-				optypelist_for_impl_mode = self.optypelist_for_impl_mode.clone()
-			};
 			if let Err(e) =
 				crate::error::status_to_result(crate::ortsys![unsafe UpdateCANNProviderOptions(cann_options, key_ptrs.as_ptr(), value_ptrs.as_ptr(), len as _)])
 					.map_err(Error::ExecutionProvider)
@@ -155,3 +186,39 @@ impl ExecutionProvider for CANNExecutionProvider {
 		Err(Error::ExecutionProviderNotRegistered(self.as_str()))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_options() {
+		let ep = CANNExecutionProvider::default()
+			.with_device_id(1)
+			.with_cann_graph(false)
+			.with_dump_graphs()
+			.with_dump_om_model(true)
+			.with_precision_mode(CANNExecutionProviderPrecisionMode::AllowMixedPrecision);
+		let (key_ptrs, value_ptrs, len, keys, values) = ep.options().unwrap();
+		assert_eq!(len, 5);
+		assert_eq!((key_ptrs.len(), value_ptrs.len()), (5, 5));
+
+		let options: Vec<(&str, &str)> = keys.iter().zip(values.iter()).map(|(k, v)| (k.to_str().unwrap(), v.to_str().unwrap())).collect();
+		assert_eq!(
+			options,
+			[
+				("device_id", "1"),
+				("enable_cann_graph", "0"),
+				("dump_graphs", "1"),
+				("dump_om_model", "1"),
+				("precision_mode", "allow_mix_precision")
+			]
+		);
+
+		let (.., keys, _) = CANNExecutionProvider::default().options().unwrap();
+		assert!(keys.is_empty());
+
+		let (.., keys, values) = CANNExecutionProvider::default().with_dump_graphs().with_dump_graphs_enabled(false).options().unwrap();
+		assert_eq!((keys[0].to_str().unwrap(), values[0].to_str().unwrap()), ("dump_graphs", "0"));
+	}
+}
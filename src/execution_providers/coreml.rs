@@ -6,11 +6,39 @@ extern "C" {
 	pub(crate) fn OrtSessionOptionsAppendExecutionProvider_CoreML(options: *mut ort_sys::OrtSessionOptions, flags: u32) -> ort_sys::OrtStatusPtr;
 }
 
+/// The oldest ONNX Runtime version which can configure CoreML via key/value provider options. This is newer than
+/// [`crate::MAX_ORT_VERSION`], so these options are only available with `load-dynamic`.
+#[cfg(feature = "load-dynamic")]
+const PROVIDER_OPTIONS_MIN_ORT_VERSION: (u32, u32) = (1, 20);
+
+/// The strategy CoreML uses to specialize a model for the device it runs on; see
+/// [`CoreMLExecutionProvider::with_specialization_strategy`].
+#[derive(Debug, Clone)]
+pub enum CoreMLExecutionProviderSpecializationStrategy {
+	/// The default specialization strategy.
+	Default,
+	/// Prefers specializing the model for faster prediction, at the cost of longer load times.
+	FastPrediction
+}
+
+impl CoreMLExecutionProviderSpecializationStrategy {
+	/// Returns the value of the `SpecializationStrategy` provider option for this strategy.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			CoreMLExecutionProviderSpecializationStrategy::Default => "Default",
+			CoreMLExecutionProviderSpecializationStrategy::FastPrediction => "FastPrediction"
+		}
+	}
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct CoreMLExecutionProvider {
 	use_cpu_only: bool,
 	enable_on_subgraph: bool,
-	only_enable_device_with_ane: bool
+	only_enable_device_with_ane: bool,
+	model_cache_dir: Option<String>,
+	specialization_strategy: Option<CoreMLExecutionProviderSpecializationStrategy>,
+	allow_low_precision_accumulation_on_gpu: Option<bool>
 }
 
 impl CoreMLExecutionProvider {
@@ -35,6 +63,69 @@ impl CoreMLExecutionProvider {
 		self
 	}
 
+	/// Sets a directory in which compiled CoreML models are cached, so subsequent sessions using the same model load
+	/// faster.
+	///
+	/// Requires the `load-dynamic` feature and ONNX Runtime 1.20 or later; registering the execution provider with this
+	/// option set fails with [`Error::Unsupported`] on older versions.
+	#[cfg(feature = "load-dynamic")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "load-dynamic")))]
+	pub fn with_model_cache_dir(mut self, path: impl ToString) -> Self {
+		self.model_cache_dir = Some(path.to_string());
+		self
+	}
+
+	/// Sets the strategy CoreML uses to specialize the model for the device.
+	///
+	/// Requires the `load-dynamic` feature and ONNX Runtime 1.20 or later; registering the execution provider with this
+	/// option set fails with [`Error::Unsupported`] on older versions.
+	#[cfg(feature = "load-dynamic")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "load-dynamic")))]
+	pub fn with_specialization_strategy(mut self, strategy: CoreMLExecutionProviderSpecializationStrategy) -> Self {
+		self.specialization_strategy = Some(strategy);
+		self
+	}
+
+	/// Allows computations on the GPU to accumulate in 16-bit floating point instead of 32-bit, which is faster but
+	/// less precise.
+	///
+	/// Requires the `load-dynamic` feature and ONNX Runtime 1.20 or later; registering the execution provider with this
+	/// option set fails with [`Error::Unsupported`] on older versions.
+	#[cfg(feature = "load-dynamic")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "load-dynamic")))]
+	pub fn with_low_precision_accumulation(mut self, enable: bool) -> Self {
+		self.allow_low_precision_accumulation_on_gpu = Some(enable);
+		self
+	}
+
+	/// Whether any options only supported by the key/value provider options API (rather than the legacy flags API) are
+	/// set.
+	#[cfg(feature = "load-dynamic")]
+	fn uses_provider_options(&self) -> bool {
+		self.model_cache_dir.is_some() || self.specialization_strategy.is_some() || self.allow_low_precision_accumulation_on_gpu.is_some()
+	}
+
+	/// Returns the key/value provider options for this configuration.
+	fn provider_options(&self) -> Vec<(&'static str, String)> {
+		let compute_units = if self.use_cpu_only {
+			Some("CPUOnly")
+		} else if self.only_enable_device_with_ane {
+			Some("CPUAndNeuralEngine")
+		} else {
+			None
+		};
+		[
+			("MLComputeUnits", compute_units.map(str::to_string)),
+			("EnableOnSubgraphs", self.enable_on_subgraph.then(|| "1".to_string())),
+			("ModelCacheDirectory", self.model_cache_dir.clone()),
+			("SpecializationStrategy", self.specialization_strategy.as_ref().map(|v| v.as_str().to_string())),
+			("AllowLowPrecisionAccumulationOnGPU", self.allow_low_precision_accumulation_on_gpu.map(|v| (v as i32).to_string()))
+		]
+		.into_iter()
+		.filter_map(|(k, v)| v.map(|v| (k, v)))
+		.collect()
+	}
+
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
@@ -51,8 +142,39 @@ impl ExecutionProvider for CoreMLExecutionProvider {
 		"CoreMLExecutionProvider"
 	}
 
+	fn check_options(&self) -> Result<()> {
+		for (_, value) in self.provider_options() {
+			std::ffi::CString::new(value)?;
+		}
+		Ok(())
+	}
+
 	#[allow(unused, unreachable_code)]
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
+		#[cfg(feature = "load-dynamic")]
+		if self.uses_provider_options() {
+			check_provider_options_supported(crate::runtime_version())?;
+			let (keys, values): (Vec<std::ffi::CString>, Vec<std::ffi::CString>) = self
+				.provider_options()
+				.into_iter()
+				.map(|(k, v)| Ok((std::ffi::CString::new(k)?, std::ffi::CString::new(v)?)))
+				.collect::<Result<Vec<_>>>()?
+				.into_iter()
+				.unzip();
+			let key_ptrs: Vec<*const std::ffi::c_char> = keys.iter().map(|k| k.as_ptr()).collect();
+			let value_ptrs: Vec<*const std::ffi::c_char> = values.iter().map(|v| v.as_ptr()).collect();
+			let len = keys.len();
+			let ep_name = std::ffi::CString::new("CoreML").unwrap();
+			return crate::error::status_to_result(crate::ortsys![unsafe SessionOptionsAppendExecutionProvider(
+				session_builder.session_options_ptr,
+				ep_name.as_ptr(),
+				key_ptrs.as_ptr(),
+				value_ptrs.as_ptr(),
+				len as _,
+			)])
+			.map_err(Error::ExecutionProvider);
+		}
+
 		#[cfg(any(feature = "load-dynamic", feature = "coreml"))]
 		{
 			super::get_ep_register!(OrtSessionOptionsAppendExecutionProvider_CoreML(options: *mut ort_sys::OrtSessionOptions, flags: u32) -> ort_sys::OrtStatusPtr);
@@ -73,3 +195,65 @@ impl ExecutionProvider for CoreMLExecutionProvider {
 		Err(Error::ExecutionProviderNotRegistered(self.as_str()))
 	}
 }
+
+/// Checks that the ONNX Runtime `version` in use accepts CoreML's key/value provider options; older versions only
+/// accept the legacy flags, and reject `CoreML` as a provider options EP name.
+#[cfg(feature = "load-dynamic")]
+fn check_provider_options_supported(version: Option<(u32, u32)>) -> Result<()> {
+	if version.is_some_and(|version| version >= PROVIDER_OPTIONS_MIN_ORT_VERSION) {
+		Ok(())
+	} else {
+		Err(Error::Unsupported(
+			"the CoreML model cache directory, specialization strategy, and low precision accumulation options require ONNX Runtime 1.20".to_string()
+		))
+	}
+}
+
+#[cfg(all(test, feature = "load-dynamic"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_provider_options_version() {
+		assert!(check_provider_options_supported(Some((1, 20))).is_ok());
+		assert!(check_provider_options_supported(Some((1, 21))).is_ok());
+		assert!(matches!(check_provider_options_supported(Some((1, 17))), Err(Error::Unsupported(_))));
+		assert!(matches!(check_provider_options_supported(None), Err(Error::Unsupported(_))));
+	}
+
+	#[test]
+	fn test_provider_options() {
+		assert!(!CoreMLExecutionProvider::default().uses_provider_options());
+		assert!(CoreMLExecutionProvider::default().provider_options().is_empty());
+
+		let ep = CoreMLExecutionProvider::default().with_model_cache_dir("/tmp/coreml-cache");
+		assert!(ep.uses_provider_options());
+		assert_eq!(ep.provider_options(), vec![("ModelCacheDirectory", "/tmp/coreml-cache".to_string())]);
+
+		let ep = CoreMLExecutionProvider::default().with_specialization_strategy(CoreMLExecutionProviderSpecializationStrategy::FastPrediction);
+		assert_eq!(ep.provider_options(), vec![("SpecializationStrategy", "FastPrediction".to_string())]);
+		let ep = CoreMLExecutionProvider::default().with_specialization_strategy(CoreMLExecutionProviderSpecializationStrategy::Default);
+		assert_eq!(ep.provider_options(), vec![("SpecializationStrategy", "Default".to_string())]);
+
+		let ep = CoreMLExecutionProvider::default().with_low_precision_accumulation(true);
+		assert_eq!(ep.provider_options(), vec![("AllowLowPrecisionAccumulationOnGPU", "1".to_string())]);
+		let ep = CoreMLExecutionProvider::default().with_low_precision_accumulation(false);
+		assert_eq!(ep.provider_options(), vec![("AllowLowPrecisionAccumulationOnGPU", "0".to_string())]);
+
+		// legacy flags are translated when the provider options API is used
+		let ep = CoreMLExecutionProvider::default()
+			.with_cpu_only()
+			.with_subgraphs()
+			.with_low_precision_accumulation(true);
+		assert_eq!(
+			ep.provider_options(),
+			vec![
+				("MLComputeUnits", "CPUOnly".to_string()),
+				("EnableOnSubgraphs", "1".to_string()),
+				("AllowLowPrecisionAccumulationOnGPU", "1".to_string())
+			]
+		);
+		let ep = CoreMLExecutionProvider::default().with_ane_only();
+		assert_eq!(ep.provider_options(), vec![("MLComputeUnits", "CPUAndNeuralEngine".to_string())]);
+	}
+}
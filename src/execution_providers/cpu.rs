@@ -1,17 +1,35 @@
 use super::ExecutionProvider;
 use crate::{error::status_to_result, ortsys, Error, ExecutionProviderDispatch, Result, SessionBuilder};
 
+/// The default CPU execution provider, powered by MLAS.
+///
+/// ONNX Runtime always falls back to the CPU execution provider for nodes that other execution providers can't handle.
+/// Listing a configured `CPUExecutionProvider` explicitly (usually last) in the session's execution providers makes
+/// those fallback nodes use its configuration instead of the defaults.
 #[derive(Debug, Default, Clone)]
 pub struct CPUExecutionProvider {
-	use_arena: bool
+	use_arena: bool,
+	intra_threads: Option<i16>
 }
 
 impl CPUExecutionProvider {
+	/// Enable the CPU memory arena allocator.
 	pub fn with_arena_allocator(mut self) -> Self {
 		self.use_arena = true;
 		self
 	}
 
+	/// Configure the number of threads used to parallelize the execution within nodes placed on the CPU. This is
+	/// equivalent to [`SessionBuilder::with_intra_threads`], and overrides it.
+	pub fn with_intra_threads(mut self, num_threads: i16) -> Self {
+		self.intra_threads = Some(num_threads);
+		self
+	}
+
+	pub(crate) fn use_arena(&self) -> bool {
+		self.use_arena
+	}
+
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
@@ -34,6 +52,9 @@ impl ExecutionProvider for CPUExecutionProvider {
 	}
 
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
+		if let Some(num_threads) = self.intra_threads {
+			status_to_result(ortsys![unsafe SetIntraOpNumThreads(session_builder.session_options_ptr, num_threads as i32)]).map_err(Error::ExecutionProvider)?;
+		}
 		if self.use_arena {
 			status_to_result(ortsys![unsafe EnableCpuMemArena(session_builder.session_options_ptr)]).map_err(Error::ExecutionProvider)
 		} else {
@@ -1,5 +1,7 @@
-use super::ExecutionProvider;
-use crate::{ArenaExtendStrategy, Error, ExecutionProviderDispatch, Result, SessionBuilder};
+use std::{collections::HashMap, os::raw::c_void};
+
+use super::{ArbitrarilyConfigurableExecutionProvider, ExecutionProvider};
+use crate::{ArenaExtendStrategy, Error, ErrorInternal, ExecutionProviderDispatch, Result, SessionBuilder};
 
 /// The type of search done for cuDNN convolution algorithms.
 #[derive(Debug, Clone)]
@@ -31,6 +33,17 @@ pub enum CUDAExecutionProviderCuDNNConvAlgoSearch {
 	Default
 }
 
+impl CUDAExecutionProviderCuDNNConvAlgoSearch {
+	/// Returns the value of the `cudnn_conv_algo_search` option corresponding to this search mode.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			CUDAExecutionProviderCuDNNConvAlgoSearch::Exhaustive => "EXHAUSTIVE",
+			CUDAExecutionProviderCuDNNConvAlgoSearch::Heuristic => "HEURISTIC",
+			CUDAExecutionProviderCuDNNConvAlgoSearch::Default => "DEFAULT"
+		}
+	}
+}
+
 impl Default for CUDAExecutionProviderCuDNNConvAlgoSearch {
 	fn default() -> Self {
 		Self::Exhaustive
@@ -47,9 +60,37 @@ pub struct CUDAExecutionProvider {
 	cudnn_conv_use_max_workspace: Option<bool>,
 	cudnn_conv1d_pad_to_nc1d: Option<bool>,
 	enable_cuda_graph: Option<bool>,
-	enable_skip_layer_norm_strict_mode: Option<bool>
+	enable_skip_layer_norm_strict_mode: Option<bool>,
+	user_compute_stream: Option<*mut c_void>,
+	arbitrary_config: Vec<(String, String)>
 }
 
+/// Options supported by the CUDA execution provider in ONNX Runtime v1.17.
+const KNOWN_CONFIG_KEYS: &[&str] = &[
+	"device_id",
+	"has_user_compute_stream",
+	"user_compute_stream",
+	"gpu_mem_limit",
+	"arena_extend_strategy",
+	"cudnn_conv_algo_search",
+	"do_copy_in_default_stream",
+	"gpu_external_alloc",
+	"gpu_external_free",
+	"gpu_external_empty_cache",
+	"cudnn_conv_use_max_workspace",
+	"enable_cuda_graph",
+	"cudnn_conv1d_pad_to_nc1d",
+	"tunable_op_enable",
+	"tunable_op_tuning_enable",
+	"tunable_op_max_tuning_duration_ms",
+	"enable_skip_layer_norm_strict_mode",
+	"prefer_nhwc",
+	"use_ep_level_unified_stream"
+];
+
+unsafe impl Send for CUDAExecutionProvider {}
+unsafe impl Sync for CUDAExecutionProvider {}
+
 impl CUDAExecutionProvider {
 	pub fn with_device_id(mut self, device_id: i32) -> Self {
 		self.device_id = Some(device_id);
@@ -78,6 +119,11 @@ impl CUDAExecutionProvider {
 		self
 	}
 
+	/// Alias of [`CUDAExecutionProvider::with_conv_algorithm_search`], named after the `cudnn_conv_algo_search` option.
+	pub fn with_conv_algo_search(self, search: CUDAExecutionProviderCuDNNConvAlgoSearch) -> Self {
+		self.with_conv_algorithm_search(search)
+	}
+
 	/// Whether to do copies in the default stream or use separate streams. The recommended setting is true. If false,
 	/// there are race conditions and possibly better performance.
 	pub fn with_copy_in_default_stream(mut self, enable: bool) -> Self {
@@ -144,9 +190,51 @@ impl CUDAExecutionProvider {
 		self
 	}
 
+	/// Configures the CUDA execution provider to launch all of its work on the given CUDA stream (a `cudaStream_t`)
+	/// instead of creating its own. This allows coordinating the session's work with your own kernels, i.e. when serving
+	/// multiple sessions on separate streams.
+	///
+	/// # Safety
+	///
+	/// `stream` must be a valid CUDA stream on the device configured with
+	/// [`CUDAExecutionProvider::with_device_id`] (device 0 by default). ONNX Runtime does not take ownership of the
+	/// stream, so it must not be destroyed until every session created with this execution provider has been dropped.
+	pub unsafe fn with_compute_stream(mut self, stream: *mut c_void) -> Self {
+		self.user_compute_stream = Some(stream);
+		self
+	}
+
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
+
+	/// Returns the provider option keys & values to pass to `UpdateCUDAProviderOptions`.
+	fn options(&self) -> Result<super::ProviderOptions> {
+		Ok(super::map_keys! {
+			device_id = self.device_id,
+			arena_extend_strategy = self.arena_extend_strategy.as_ref().map(|v| match v {
+				ArenaExtendStrategy::NextPowerOfTwo => "kNextPowerOfTwo",
+				ArenaExtendStrategy::SameAsRequested => "kSameAsRequested"
+			}),
+			cudnn_conv_algo_search = self.cudnn_conv_algo_search.as_ref().map(CUDAExecutionProviderCuDNNConvAlgoSearch::as_str),
+			gpu_mem_limit = self.gpu_mem_limit,
+			do_copy_in_default_stream = self.do_copy_in_default_stream.map(<bool as Into<i32>>::into),
+			cudnn_conv_use_max_workspace = self.cudnn_conv_use_max_workspace.map(<bool as Into<i32>>::into),
+			cudnn_conv1d_pad_to_nc1d = self.cudnn_conv1d_pad_to_nc1d.map(<bool as Into<i32>>::into),
+			enable_cuda_graph = self.enable_cuda_graph.map(<bool as Into<i32>>::into),
+			enable_skip_layer_norm_strict_mode = self.enable_skip_layer_norm_strict_mode.map(<bool as Into<i32>>::into);
+			&self.arbitrary_config
+		})
+	}
+}
+
+impl ArbitrarilyConfigurableExecutionProvider for CUDAExecutionProvider {
+	fn with_arbitrary_config(mut self, key: impl ToString, value: impl ToString) -> Self {
+		let key = key.to_string();
+		super::check_config_key(self.as_str(), KNOWN_CONFIG_KEYS, &key);
+		self.arbitrary_config.push((key, value.to_string()));
+		self
+	}
 }
 
 impl From<CUDAExecutionProvider> for ExecutionProviderDispatch {
@@ -160,34 +248,30 @@ impl ExecutionProvider for CUDAExecutionProvider {
 		"CUDAExecutionProvider"
 	}
 
+	fn check_options(&self) -> Result<()> {
+		self.options().map(drop)
+	}
+
+	fn effective_options(&self) -> HashMap<String, String> {
+		self.options().map(|(_, _, _, keys, values)| super::options_map(&keys, &values)).unwrap_or_default()
+	}
+
 	#[allow(unused, unreachable_code)]
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
 		#[cfg(any(feature = "load-dynamic", feature = "cuda"))]
 		{
+			let (key_ptrs, value_ptrs, len, keys, values) = self.options()?;
 			let mut cuda_options: *mut ort_sys::OrtCUDAProviderOptionsV2 = std::ptr::null_mut();
 			crate::error::status_to_result(crate::ortsys![unsafe CreateCUDAProviderOptions(&mut cuda_options)]).map_err(Error::ExecutionProvider)?;
-			let (key_ptrs, value_ptrs, len, keys, values) = super::map_keys! {
-				device_id = self.device_id,
-				arena_extend_strategy = self.arena_extend_strategy.as_ref().map(|v| match v {
-					ArenaExtendStrategy::NextPowerOfTwo => "kNextPowerOfTwo",
-					ArenaExtendStrategy::SameAsRequested => "kSameAsRequested"
-				}),
-				cudnn_conv_algo_search = self.cudnn_conv_algo_search.as_ref().map(|v| match v {
-					CUDAExecutionProviderCuDNNConvAlgoSearch::Exhaustive => "EXHAUSTIVE",
-					CUDAExecutionProviderCuDNNConvAlgoSearch::Heuristic => "HEURISTIC",
-					CUDAExecutionProviderCuDNNConvAlgoSearch::Default => "DEFAULT"
-				}),
-				gpu_mem_limit = self.gpu_mem_limit,
-				do_copy_in_default_stream = self.do_copy_in_default_stream.map(<bool as Into<i32>>::into),
-				cudnn_conv_use_max_workspace = self.cudnn_conv_use_max_workspace.map(<bool as Into<i32>>::into),
-				cudnn_conv1d_pad_to_nc1d = self.cudnn_conv1d_pad_to_nc1d.map(<bool as Into<i32>>::into),
-				enable_cuda_graph = self.enable_cuda_graph.map(<bool as Into<i32>>::into),
-				enable_skip_layer_norm_strict_mode = self.enable_skip_layer_norm_strict_mode.map(<bool as Into<i32>>::into)
-			};
-			if let Err(e) =
-				crate::error::status_to_result(crate::ortsys![unsafe UpdateCUDAProviderOptions(cuda_options, key_ptrs.as_ptr(), value_ptrs.as_ptr(), len as _)])
-					.map_err(Error::ExecutionProvider)
-			{
+			let mut result =
+				crate::error::status_to_result(crate::ortsys![unsafe UpdateCUDAProviderOptions(cuda_options, key_ptrs.as_ptr(), value_ptrs.as_ptr(), len as _)]);
+			if let (Ok(()), Some(stream)) = (&result, self.user_compute_stream) {
+				// pointer options can't be passed as strings, so they have to be set separately; this also sets
+				// `has_user_compute_stream`
+				let key = std::ffi::CString::new("user_compute_stream").unwrap();
+				result = crate::error::status_to_result(crate::ortsys![unsafe UpdateCUDAProviderOptionsWithValue(cuda_options, key.as_ptr(), stream)]);
+			}
+			if let Err(e) = result.map_err(Error::ExecutionProvider) {
 				crate::ortsys![unsafe ReleaseCUDAProviderOptions(cuda_options)];
 				std::mem::drop((keys, values));
 				return Err(e);
@@ -196,9 +280,150 @@ impl ExecutionProvider for CUDAExecutionProvider {
 			let status = crate::ortsys![unsafe SessionOptionsAppendExecutionProvider_CUDA_V2(session_builder.session_options_ptr, cuda_options)];
 			crate::ortsys![unsafe ReleaseCUDAProviderOptions(cuda_options)];
 			std::mem::drop((keys, values));
-			return crate::error::status_to_result(status).map_err(Error::ExecutionProvider);
+			return crate::error::status_to_result(status).map_err(|e| match e {
+				ErrorInternal::Msg(message) => with_library_hint(message),
+				e => Error::ExecutionProvider(e)
+			});
 		}
 
 		Err(Error::ExecutionProviderNotRegistered(self.as_str()))
 	}
 }
+
+/// The CUDA & cuDNN versions Microsoft's ONNX Runtime 1.17 builds are linked against.
+#[allow(unused)]
+const EXPECTED_VERSIONS: &str = "CUDA 11.8 and cuDNN 8 (or CUDA 12.x and cuDNN 8.9 for CUDA 12 builds)";
+
+/// CUDA libraries the CUDA execution provider links to, by file name prefix (on Linux, then Windows).
+#[allow(unused)]
+const CUDA_LIBRARIES: &[(&str, &str, &str)] = &[
+	("libcudnn", "cudnn64_", "cuDNN"),
+	("libcublasLt", "cublasLt64_", "cuBLAS"),
+	("libcublas", "cublas64_", "cuBLAS"),
+	("libcudart", "cudart64_", "the CUDA runtime"),
+	("libcufft", "cufft64_", "cuFFT"),
+	("libcurand", "curand64_", "cuRAND")
+];
+
+/// Converts a registration error from ONNX Runtime into [`Error::CudaLibraryLoad`] if it was caused by a CUDA library
+/// failing to load, e.g. `libcudnn.so.8: cannot open shared object file: No such file or directory`.
+#[allow(unused)]
+fn with_library_hint(message: String) -> Error {
+	let library = message
+		.split(|c: char| c.is_whitespace() || matches!(c, ':' | '"' | '\'' | '`'))
+		.find_map(|token| {
+			let (_, _, name) = CUDA_LIBRARIES
+				.iter()
+				.find(|(linux, windows, _)| (token.starts_with(linux) && token.contains(".so")) || (token.starts_with(windows) && token.ends_with(".dll")))?;
+			Some((token.to_string(), *name))
+		});
+	match library {
+		Some((library, name)) => {
+			let hint = format!(
+				"{name} (`{library}`) could not be loaded. ONNX Runtime's CUDA execution provider requires {EXPECTED_VERSIONS}; make sure the matching \
+				 versions are installed and can be found by the dynamic loader (i.e. are in `LD_LIBRARY_PATH` or `PATH`)."
+			);
+			Error::CudaLibraryLoad { message, library, hint }
+		}
+		None => Error::ExecutionProvider(ErrorInternal::Msg(message))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_conv_algo_search_option() {
+		assert_eq!(CUDAExecutionProviderCuDNNConvAlgoSearch::Exhaustive.as_str(), "EXHAUSTIVE");
+		assert_eq!(CUDAExecutionProviderCuDNNConvAlgoSearch::Heuristic.as_str(), "HEURISTIC");
+		assert_eq!(CUDAExecutionProviderCuDNNConvAlgoSearch::Default.as_str(), "DEFAULT");
+
+		let ep = CUDAExecutionProvider::default().with_conv_algo_search(CUDAExecutionProviderCuDNNConvAlgoSearch::Heuristic);
+		assert!(matches!(ep.cudnn_conv_algo_search, Some(CUDAExecutionProviderCuDNNConvAlgoSearch::Heuristic)));
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	fn test_unknown_config_key_warning() {
+		let (ep, output) = crate::test_util::capture_logs(tracing::Level::INFO, || {
+			CUDAExecutionProvider::default()
+				.with_arbitrary_config("prefer_nhwc", "1")
+				.with_arbitrary_config("cudnn_conv_algo_serach", "HEURISTIC")
+		});
+		assert_eq!(ep.arbitrary_config.len(), 2);
+		assert!(output.contains("`cudnn_conv_algo_serach` is not a known option for `CUDAExecutionProvider`"), "missing warning: {output}");
+		assert!(!output.contains("`prefer_nhwc`"), "unexpected warning for a valid key: {output}");
+	}
+
+	#[test]
+	fn test_library_hint() {
+		let message = "Failed to load library libonnxruntime_providers_cuda.so with error: \
+		               libcudnn.so.8: cannot open shared object file: No such file or directory";
+		match with_library_hint(message.to_string()) {
+			Error::CudaLibraryLoad { message: msg, library, hint } => {
+				assert_eq!(msg, message);
+				assert_eq!(library, "libcudnn.so.8");
+				assert!(hint.starts_with("cuDNN (`libcudnn.so.8`) could not be loaded"), "unexpected hint: {hint}");
+				assert!(hint.contains(EXPECTED_VERSIONS));
+			}
+			e => panic!("expected a library load error, got {e:?}")
+		}
+
+		let err = with_library_hint("libcublasLt.so.11: cannot open shared object file: No such file or directory".to_string());
+		assert!(matches!(&err, Error::CudaLibraryLoad { library, .. } if library == "libcublasLt.so.11"));
+		// the hint is appended to the original message, which is still available as-is
+		assert!(err.to_string().starts_with("libcublasLt.so.11: cannot open shared object file: No such file or directory\nhint: cuBLAS"));
+		assert_eq!(err.ep_registration_detail(), Some("libcublasLt.so.11: cannot open shared object file: No such file or directory"));
+
+		assert!(matches!(
+			with_library_hint("LoadLibrary failed with error 126 when trying to load \"cudnn64_8.dll\"".to_string()),
+			Error::CudaLibraryLoad { library, .. } if library == "cudnn64_8.dll"
+		));
+
+		// unrelated errors are left alone
+		assert!(matches!(
+			with_library_hint("CUDA failure 100: no CUDA-capable device is detected".to_string()),
+			Error::ExecutionProvider(ErrorInternal::Msg(_))
+		));
+	}
+
+	#[test]
+	fn test_compute_stream_option() {
+		assert_eq!(CUDAExecutionProvider::default().user_compute_stream, None);
+
+		let mut stream = 0_u8;
+		let stream_ptr = &mut stream as *mut u8 as *mut c_void;
+		let ep = unsafe { CUDAExecutionProvider::default().with_device_id(1).with_compute_stream(stream_ptr) };
+		assert_eq!(ep.user_compute_stream, Some(stream_ptr));
+		// the stream is kept when the EP is cloned, i.e. when it's shared between sessions via an environment
+		let dispatch = ep.build();
+		assert!(matches!(dispatch.clone(), ExecutionProviderDispatch::CUDA(ep) if ep.user_compute_stream == Some(stream_ptr)));
+	}
+
+	#[test]
+	fn test_effective_options() {
+		assert!(CUDAExecutionProvider::default().effective_options().is_empty());
+
+		let ep = CUDAExecutionProvider::default()
+			.with_device_id(1)
+			.with_arena_extend_strategy(ArenaExtendStrategy::SameAsRequested)
+			.with_conv_algo_search(CUDAExecutionProviderCuDNNConvAlgoSearch::Heuristic)
+			.with_cuda_graph()
+			.with_arbitrary_config("prefer_nhwc", "1")
+			// arbitrary options take precedence over those set via dedicated methods
+			.with_arbitrary_config("device_id", "2");
+		let expected: HashMap<String, String> = [
+			("device_id", "2"),
+			("arena_extend_strategy", "kSameAsRequested"),
+			("cudnn_conv_algo_search", "HEURISTIC"),
+			("enable_cuda_graph", "1"),
+			("prefer_nhwc", "1")
+		]
+		.into_iter()
+		.map(|(k, v)| (k.to_string(), v.to_string()))
+		.collect();
+		assert_eq!(ep.effective_options(), expected);
+		assert_eq!(ep.build().or_else(crate::CPUExecutionProvider::default()).effective_options(), expected);
+	}
+}
@@ -0,0 +1,124 @@
+use super::{ExecutionProvider, ROCmExecutionProvider};
+use crate::{Error, ExecutionProviderDispatch, Result, SessionBuilder};
+
+#[derive(Debug, Default, Clone)]
+pub struct MIGraphXExecutionProvider {
+	device_id: i32,
+	enable_fp16: bool,
+	enable_int8: bool,
+	use_native_calibration_table: bool,
+	int8_calibration_table_name: Option<String>
+}
+
+impl MIGraphXExecutionProvider {
+	pub fn with_device_id(mut self, device_id: i32) -> Self {
+		self.device_id = device_id;
+		self
+	}
+
+	/// Returns the ID of the device this execution provider will run on.
+	pub fn device_id(&self) -> i32 {
+		self.device_id
+	}
+
+	pub fn with_fp16(mut self) -> Self {
+		self.enable_fp16 = true;
+		self
+	}
+
+	pub fn with_int8(mut self) -> Self {
+		self.enable_int8 = true;
+		self
+	}
+
+	/// Sets the name of the calibration table used for INT8 quantization; see
+	/// [`MIGraphXExecutionProvider::with_int8`].
+	pub fn with_int8_calibration_table(mut self, name: impl ToString) -> Self {
+		self.int8_calibration_table_name = Some(name.to_string());
+		self
+	}
+
+	/// Read the INT8 calibration table in its native format, rather than the format generated by ONNX Runtime.
+	pub fn with_native_calibration_table(mut self) -> Self {
+		self.use_native_calibration_table = true;
+		self
+	}
+
+	pub fn build(self) -> ExecutionProviderDispatch {
+		self.into()
+	}
+}
+
+impl From<MIGraphXExecutionProvider> for ExecutionProviderDispatch {
+	fn from(value: MIGraphXExecutionProvider) -> Self {
+		ExecutionProviderDispatch::MIGraphX(value)
+	}
+}
+
+impl ExecutionProvider for MIGraphXExecutionProvider {
+	fn as_str(&self) -> &'static str {
+		"MIGraphXExecutionProvider"
+	}
+
+	fn check_options(&self) -> Result<()> {
+		self.int8_calibration_table_name.as_deref().map(std::ffi::CString::new).transpose()?;
+		Ok(())
+	}
+
+	#[allow(unused, unreachable_code)]
+	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
+		#[cfg(any(feature = "load-dynamic", feature = "migraphx"))]
+		{
+			let int8_calibration_table_name = self.int8_calibration_table_name.as_deref().map(std::ffi::CString::new).transpose()?;
+			let migraphx_options = ort_sys::OrtMIGraphXProviderOptions {
+				device_id: self.device_id,
+				migraphx_fp16_enable: self.enable_fp16.into(),
+				migraphx_int8_enable: self.enable_int8.into(),
+				migraphx_use_native_calibration_table: self.use_native_calibration_table.into(),
+				migraphx_int8_calibration_table_name: int8_calibration_table_name.as_ref().map_or(std::ptr::null(), |name| name.as_ptr())
+			};
+			return crate::error::status_to_result(
+				crate::ortsys![unsafe SessionOptionsAppendExecutionProvider_MIGraphX(session_builder.session_options_ptr, &migraphx_options as *const _)]
+			)
+			.map_err(Error::ExecutionProvider);
+		}
+
+		Err(Error::ExecutionProviderNotRegistered(self.as_str()))
+	}
+}
+
+/// Creates a [`ROCmExecutionProvider`] and a [`MIGraphXExecutionProvider`] which both target the device `device_id`.
+///
+/// AMD GPUs are commonly used with both execution providers registered, with MIGraphX handling the operators it
+/// supports and ROCm handling the rest. Both must run on the same device, or data will be needlessly copied between
+/// devices (or fail to be copied at all). The returned execution providers can be further configured before
+/// registering them:
+///
+/// ```no_run
+/// # use ort::Session;
+/// # fn main() -> ort::Result<()> {
+/// let (rocm, migraphx) = ort::rocm_migraphx_on_device(1);
+/// let session = Session::builder()?
+/// 	.with_execution_providers([migraphx.with_fp16().build(), rocm.build()])?
+/// 	.with_model_from_file("model.onnx")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn rocm_migraphx_on_device(device_id: i32) -> (ROCmExecutionProvider, MIGraphXExecutionProvider) {
+	(ROCmExecutionProvider::default().with_device_id(device_id), MIGraphXExecutionProvider::default().with_device_id(device_id))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_rocm_migraphx_on_device() {
+		let (rocm, migraphx) = rocm_migraphx_on_device(3);
+		assert_eq!(rocm.device_id(), 3);
+		assert_eq!(migraphx.device_id(), 3);
+
+		let (rocm, migraphx) = rocm_migraphx_on_device(0);
+		assert_eq!((rocm.device_id(), migraphx.device_id()), (0, 0));
+	}
+}
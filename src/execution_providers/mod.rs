@@ -1,4 +1,11 @@
-use std::{fmt::Debug, os::raw::c_char};
+use std::{
+	collections::HashMap,
+	ffi::CString,
+	fmt::Debug,
+	os::raw::c_char,
+	sync::Arc,
+	time::{Duration, Instant}
+};
 
 use crate::{char_p_to_string, ortsys, Error, Result, SessionBuilder};
 
@@ -15,7 +22,7 @@ pub use self::acl::ACLExecutionProvider;
 mod openvino;
 pub use self::openvino::OpenVINOExecutionProvider;
 mod coreml;
-pub use self::coreml::CoreMLExecutionProvider;
+pub use self::coreml::{CoreMLExecutionProvider, CoreMLExecutionProviderSpecializationStrategy};
 mod rocm;
 pub use self::rocm::ROCmExecutionProvider;
 mod cann;
@@ -32,6 +39,8 @@ mod xnnpack;
 pub use self::xnnpack::XNNPACKExecutionProvider;
 mod armnn;
 pub use self::armnn::ArmNNExecutionProvider;
+mod migraphx;
+pub use self::migraphx::{rocm_migraphx_on_device, MIGraphXExecutionProvider};
 
 /// ONNX Runtime works with different hardware acceleration libraries through its extensible **Execution Providers**
 /// (EP) framework to optimally execute the ONNX models on the hardware platform. This interface enables flexibility for
@@ -57,33 +66,125 @@ pub trait ExecutionProvider {
 	/// dynamic library during registration. In most cases (i.e. showing the user an error message if CUDA could not be
 	/// enabled), you'll instead want to detect and handle errors from [`ExecutionProvider::register`].
 	fn is_available(&self) -> Result<bool> {
-		let mut providers: *mut *mut c_char = std::ptr::null_mut();
-		let mut num_providers = 0;
-		ortsys![unsafe GetAvailableProviders(&mut providers, &mut num_providers) -> Error::GetAvailableProviders];
-		if providers.is_null() {
-			return Ok(false);
+		Ok(available_execution_providers()?.iter().any(|avail| avail == self.as_str()))
+	}
+
+	/// Attempts to register this execution provider on the given session.
+	///
+	/// If ONNX Runtime fails to register the execution provider, the returned error contains its message unmodified;
+	/// see [`Error::ep_registration_detail`].
+	fn register(&self, session_builder: &SessionBuilder) -> Result<()>;
+
+	/// Returns the string-keyed provider options this execution provider passes to ONNX Runtime when it is
+	/// [registered](ExecutionProvider::register), after merging options set via dedicated methods with those set via
+	/// [`ArbitrarilyConfigurableExecutionProvider::with_arbitrary_config`].
+	///
+	/// Execution providers which are configured via an options struct or flags rather than string keys (e.g.
+	/// [`CPUExecutionProvider`] or [`ROCmExecutionProvider`]) return an empty map, as do those whose options can't be
+	/// passed to ONNX Runtime (see [`ExecutionProvider::try_build`]).
+	fn effective_options(&self) -> HashMap<String, String> {
+		HashMap::new()
+	}
+
+	/// Checks that the configured options can be passed to ONNX Runtime, returning the error registration would
+	/// otherwise fail with. See [`ExecutionProvider::try_build`].
+	fn check_options(&self) -> Result<()> {
+		Ok(())
+	}
+
+	/// Converts this execution provider into an [`ExecutionProviderDispatch`] like its infallible `build` method, but
+	/// checks its options can be passed to ONNX Runtime up front (e.g. that paths don't contain NUL bytes). Invalid
+	/// options are then reported here, when the configuration is loaded, rather than when the execution provider is
+	/// [registered](ExecutionProvider::register) with a session.
+	fn try_build(self) -> Result<ExecutionProviderDispatch>
+	where
+		Self: Sized + Into<ExecutionProviderDispatch>
+	{
+		self.check_options()?;
+		Ok(self.into())
+	}
+
+	/// Determines whether this execution provider is actually usable by attempting to register it on a throwaway
+	/// [`SessionBuilder`], without needing to load a model.
+	///
+	/// This gives a more accurate signal than [`ExecutionProvider::is_available`], which only checks if ONNX Runtime
+	/// was compiled with support for this execution provider; an EP may be compiled in but still fail to register, i.e.
+	/// if the CUDA toolkit is not installed.
+	///
+	/// An `Err` may be returned if a serious internal error occurs, i.e. the session builder could not be created.
+	fn probe(&self) -> Result<ProbeResult> {
+		if !self.is_available()? {
+			return Ok(ProbeResult::NotCompiled);
 		}
 
-		for i in 0..num_providers {
-			let avail = match char_p_to_string(unsafe { *providers.offset(i as isize) }) {
-				Ok(avail) => avail,
-				Err(e) => {
-					let _ = ortsys![unsafe ReleaseAvailableProviders(providers, num_providers)];
-					return Err(e);
-				}
-			};
-			if self.as_str() == avail {
-				let _ = ortsys![unsafe ReleaseAvailableProviders(providers, num_providers)];
-				return Ok(true);
-			}
+		let session_builder = SessionBuilder::new()?;
+		match self.register(&session_builder) {
+			Ok(()) => Ok(ProbeResult::Available),
+			Err(Error::ExecutionProviderNotRegistered(_)) => Ok(ProbeResult::NotCompiled),
+			Err(e) => Ok(ProbeResult::CompiledButUnusable(e.to_string()))
 		}
+	}
+}
+
+/// An execution provider which accepts options by their string keys, as documented by ONNX Runtime. This allows
+/// setting options `ort` does not (yet) provide a dedicated method for.
+pub trait ArbitrarilyConfigurableExecutionProvider {
+	/// Sets the option `key` to `value`. Arbitrary options are applied after those set via dedicated methods, so they
+	/// take precedence.
+	///
+	/// ONNX Runtime silently ignores unknown keys. To help catch typos, debug builds (those with `debug_assertions`
+	/// enabled) log a warning if `key` is not an option this execution provider is known to support.
+	fn with_arbitrary_config(self, key: impl ToString, value: impl ToString) -> Self;
+}
+
+/// Converts the keys & values produced by [`map_keys`] into a map for [`ExecutionProvider::effective_options`].
+#[allow(unused)]
+pub(crate) fn options_map(keys: &[CString], values: &[CString]) -> HashMap<String, String> {
+	keys.iter()
+		.zip(values)
+		.map(|(k, v)| (k.to_string_lossy().into_owned(), v.to_string_lossy().into_owned()))
+		.collect()
+}
 
-		let _ = ortsys![unsafe ReleaseAvailableProviders(providers, num_providers)];
-		Ok(false)
+/// Logs a warning in debug builds if `key` is not in `known_keys`, the set of options supported by the execution
+/// provider `ep`.
+#[allow(unused)]
+#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+pub(crate) fn check_config_key(ep: &'static str, known_keys: &[&str], key: &str) {
+	#[cfg(debug_assertions)]
+	if !known_keys.contains(&key) {
+		tracing::warn!("`{key}` is not a known option for `{ep}`, so it will likely be ignored by ONNX Runtime. Valid options are: {known_keys:?}");
 	}
+}
 
-	/// Attempts to register this execution provider on the given session.
-	fn register(&self, session_builder: &SessionBuilder) -> Result<()>;
+/// Returns the identifiers of all execution providers ONNX Runtime was compiled with support for, as reported by
+/// `GetAvailableProviders`. See [`ExecutionProvider::is_available`].
+pub fn available_execution_providers() -> Result<Vec<String>> {
+	let mut providers: *mut *mut c_char = std::ptr::null_mut();
+	let mut num_providers = 0;
+	ortsys![unsafe GetAvailableProviders(&mut providers, &mut num_providers) -> Error::GetAvailableProviders];
+	if providers.is_null() {
+		return Ok(Vec::new());
+	}
+
+	let available = (0..num_providers)
+		.map(|i| char_p_to_string(unsafe { *providers.offset(i as isize) }))
+		.collect::<Result<Vec<_>>>();
+	let _ = ortsys![unsafe ReleaseAvailableProviders(providers, num_providers)];
+	available
+}
+
+/// The result of [probing](ExecutionProvider::probe) an execution provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProbeResult {
+	/// The execution provider was successfully registered and is ready to use.
+	Available,
+	/// ONNX Runtime was compiled with support for the execution provider, but it failed to register. Contains the error
+	/// message reported during registration.
+	CompiledButUnusable(String),
+	/// Either ONNX Runtime was not compiled with support for the execution provider, or its corresponding Cargo feature
+	/// is disabled.
+	NotCompiled
 }
 
 /// The strategy for extending the device memory arena.
@@ -118,7 +219,80 @@ pub enum ExecutionProviderDispatch {
 	TVM(TVMExecutionProvider),
 	CANN(CANNExecutionProvider),
 	XNNPACK(XNNPACKExecutionProvider),
-	ArmNN(ArmNNExecutionProvider)
+	ArmNN(ArmNNExecutionProvider),
+	MIGraphX(MIGraphXExecutionProvider),
+	/// Attempts to register the first execution provider, falling back to the second if registration fails. Created
+	/// via [`ExecutionProviderDispatch::or_else`].
+	OrElse(Box<ExecutionProviderDispatch>, Box<ExecutionProviderDispatch>),
+	/// Fails the session build if the execution provider can't be registered. Created via
+	/// [`ExecutionProviderDispatch::error_on_failure`].
+	ErrorOnFailure(Box<ExecutionProviderDispatch>)
+}
+
+impl ExecutionProviderDispatch {
+	/// Creates a dispatch that attempts to register `self`, and only if that fails, attempts to register `next`
+	/// instead. Calls can be chained to express a list of alternatives, tried in order until one registers
+	/// successfully:
+	///
+	/// ```no_run
+	/// # use ort::{CPUExecutionProvider, CUDAExecutionProvider, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?
+	/// 	.with_execution_providers([CUDAExecutionProvider::default()
+	/// 		.with_device_id(1)
+	/// 		.build()
+	/// 		.or_else(CUDAExecutionProvider::default().build())
+	/// 		.or_else(CPUExecutionProvider::default().with_arena_allocator().build())])?
+	/// 	.with_model_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn or_else(self, next: impl Into<ExecutionProviderDispatch>) -> ExecutionProviderDispatch {
+		ExecutionProviderDispatch::OrElse(Box::new(self), Box::new(next.into()))
+	}
+
+	/// Marks this execution provider as required, so that building a session fails if it can't be registered, instead
+	/// of silently falling back to the next execution provider (or the CPU).
+	///
+	/// This only applies to this dispatch; other execution providers in the same list are still optional, and all of
+	/// them are attempted before the error is reported. If the dispatch has alternatives created via
+	/// [`ExecutionProviderDispatch::or_else`], the session build only fails if none of them could be registered.
+	///
+	/// ```no_run
+	/// # use ort::{CUDAExecutionProvider, Session, TensorRTExecutionProvider};
+	/// # fn main() -> ort::Result<()> {
+	/// // CUDA is required, but TensorRT is optional
+	/// let session = Session::builder()?
+	/// 	.with_execution_providers([TensorRTExecutionProvider::default().build(), CUDAExecutionProvider::default().build().error_on_failure()])?
+	/// 	.with_model_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn error_on_failure(self) -> ExecutionProviderDispatch {
+		ExecutionProviderDispatch::ErrorOnFailure(Box::new(self))
+	}
+
+	/// Returns the alternatives this dispatch will attempt to register, in order.
+	pub(crate) fn alternatives(&self) -> Vec<&ExecutionProviderDispatch> {
+		match self {
+			Self::OrElse(primary, next) => {
+				let mut alternatives = primary.alternatives();
+				alternatives.extend(next.alternatives());
+				alternatives
+			}
+			Self::ErrorOnFailure(inner) => inner.alternatives(),
+			ex => vec![ex]
+		}
+	}
+
+	/// Returns whether failing to register this dispatch should fail the session build.
+	pub(crate) fn is_required(&self) -> bool {
+		match self {
+			Self::ErrorOnFailure(_) => true,
+			Self::OrElse(primary, next) => primary.is_required() || next.is_required(),
+			_ => false
+		}
+	}
 }
 
 macro_rules! impl_dispatch {
@@ -127,38 +301,80 @@ macro_rules! impl_dispatch {
 			fn as_str(&self) -> &'static str {
 				match self {
 					$(Self::$variant(inner) => inner.as_str(),)*
+					Self::OrElse(primary, _) => primary.as_str(),
+					Self::ErrorOnFailure(inner) => inner.as_str()
 				}
 			}
 
 			fn is_available(&self) -> $crate::Result<bool> {
 				match self {
 					$(Self::$variant(inner) => inner.is_available(),)*
+					Self::OrElse(primary, next) => Ok(primary.is_available()? || next.is_available()?),
+					Self::ErrorOnFailure(inner) => inner.is_available()
 				}
 			}
 
 			fn register(&self, session_builder: &$crate::SessionBuilder) -> $crate::Result<()> {
 				match self {
 					$(Self::$variant(inner) => inner.register(session_builder),)*
+					Self::OrElse(primary, next) => primary.register(session_builder).or_else(|e| {
+						tracing::debug!("Failed to register `{}`, falling back to `{}`: {e}", primary.as_str(), next.as_str());
+						next.register(session_builder)
+					}),
+					Self::ErrorOnFailure(inner) => inner.register(session_builder)
+				}
+			}
+
+			fn effective_options(&self) -> ::std::collections::HashMap<String, String> {
+				match self {
+					$(Self::$variant(inner) => inner.effective_options(),)*
+					Self::OrElse(primary, _) => primary.effective_options(),
+					Self::ErrorOnFailure(inner) => inner.effective_options()
+				}
+			}
+
+			fn check_options(&self) -> $crate::Result<()> {
+				match self {
+					$(Self::$variant(inner) => inner.check_options(),)*
+					Self::OrElse(primary, next) => primary.check_options().and_then(|()| next.check_options()),
+					Self::ErrorOnFailure(inner) => inner.check_options()
+				}
+			}
+
+			fn probe(&self) -> $crate::Result<ProbeResult> {
+				match self {
+					$(Self::$variant(inner) => inner.probe(),)*
+					Self::OrElse(primary, next) => match primary.probe()? {
+						ProbeResult::Available => Ok(ProbeResult::Available),
+						_ => next.probe()
+					},
+					Self::ErrorOnFailure(inner) => inner.probe()
 				}
 			}
 		}
 	};
 }
 
-impl_dispatch!(CPU, CUDA, TensorRT, ACL, OneDNN, OpenVINO, CoreML, CANN, ROCm, DirectML, TVM, NNAPI, QNN, XNNPACK, ArmNN);
+impl_dispatch!(CPU, CUDA, TensorRT, ACL, OneDNN, OpenVINO, CoreML, CANN, ROCm, DirectML, TVM, NNAPI, QNN, XNNPACK, ArmNN, MIGraphX);
 
 #[allow(unused)]
 macro_rules! map_keys {
-	($($fn_name:ident = $ex:expr),*) => {
+	($($fn_name:ident = $ex:expr),* $(; $extra:expr)?) => {
 		{
 			let mut keys = ::std::vec::Vec::<std::ffi::CString>::new();
 			let mut values = ::std::vec::Vec::<std::ffi::CString>::new();
 			$(
 				if let Some(v) = $ex {
 					keys.push(::std::ffi::CString::new(stringify!($fn_name)).unwrap());
-					values.push(::std::ffi::CString::new(v.to_string().as_str()).unwrap());
+					values.push(::std::ffi::CString::new(v.to_string().as_str())?);
 				}
 			)*
+			$(
+				for (k, v) in $extra {
+					keys.push(::std::ffi::CString::new(::std::convert::AsRef::<str>::as_ref(k))?);
+					values.push(::std::ffi::CString::new(::std::convert::AsRef::<str>::as_ref(v))?);
+				}
+			)?
 			assert_eq!(keys.len(), values.len()); // sanity check
 			let key_ptrs: ::std::vec::Vec<*const ::std::ffi::c_char> = keys.iter().map(|k| k.as_ptr()).collect();
 			let value_ptrs: ::std::vec::Vec<*const ::std::ffi::c_char> = values.iter().map(|v| v.as_ptr()).collect();
@@ -169,6 +385,10 @@ macro_rules! map_keys {
 #[allow(unused)]
 pub(crate) use map_keys;
 
+/// Provider options as built by [`map_keys`]: pointers to each key & value, the number of options, and the owned keys &
+/// values the pointers point into (which must be kept alive for as long as the pointers are used).
+pub(crate) type ProviderOptions = (Vec<*const std::ffi::c_char>, Vec<*const std::ffi::c_char>, usize, Vec<std::ffi::CString>, Vec<std::ffi::CString>);
+
 #[allow(unused)]
 macro_rules! get_ep_register {
 	($symbol:ident($($id:ident: $type:ty),*) -> $rt:ty) => {
@@ -192,22 +412,319 @@ macro_rules! get_ep_register {
 #[allow(unused)]
 pub(crate) use get_ep_register;
 
+/// The outcome of registering a single execution provider in [`apply_execution_providers`].
+#[derive(Debug, Clone)]
+#[allow(unused)]
+pub(crate) struct EpRegistration {
+	pub(crate) name: &'static str,
+	/// How long the call to [`ExecutionProvider::register`] took, whether or not it succeeded.
+	pub(crate) duration: Duration,
+	pub(crate) registered: bool
+}
+
+/// A callback set via [`SessionBuilder::on_ep_event`].
+pub(crate) type EpEventCallback = Arc<dyn Fn(&str, Result<(), &Error>) + Send + Sync>;
+
 #[tracing::instrument(skip_all)]
-pub(crate) fn apply_execution_providers(session_builder: &SessionBuilder, execution_providers: impl Iterator<Item = ExecutionProviderDispatch>) {
+pub(crate) fn apply_execution_providers(
+	session_builder: &SessionBuilder,
+	execution_providers: impl Iterator<Item = ExecutionProviderDispatch>
+) -> Result<Vec<EpRegistration>> {
+	// ONNX Runtime always places unsupported nodes on the CPU EP; if the CPU EP is listed explicitly, its configuration
+	// is applied to the session options so that those fallback nodes use it rather than the defaults.
 	let mut fallback_to_cpu = true;
-	for ex in execution_providers {
-		if let Err(e) = ex.register(session_builder) {
-			if let &Error::ExecutionProviderNotRegistered(_) = &e {
-				tracing::debug!("{}", e);
+	let mut registrations = Vec::new();
+	// the first required EP that failed to register, and why; reported once all EPs have been attempted
+	let mut required_failure: Option<(String, String)> = None;
+	// EPs that weren't compiled in are skipped up front; attempting to register them anyway can make ONNX Runtime try to
+	// load their provider libraries, which prints errors to stderr that we can't intercept
+	let available = match available_execution_providers() {
+		Ok(available) => Some(available),
+		Err(e) => {
+			tracing::debug!("Failed to get available execution providers, attempting to register all: {e}");
+			None
+		}
+	};
+	for dispatch in execution_providers {
+		let mut failure = None;
+		// for `ExecutionProviderDispatch::or_else`, try each alternative in order until one registers successfully
+		for ex in dispatch.alternatives() {
+			if let Some(available) = &available {
+				if !available.iter().any(|avail| avail == ex.as_str()) {
+					tracing::warn!("`{}` is not available in this build of ONNX Runtime; skipping registration", ex.as_str());
+					registrations.push(EpRegistration {
+						name: ex.as_str(),
+						duration: Duration::ZERO,
+						registered: false
+					});
+					let e = Error::ExecutionProviderUnavailable(ex.as_str());
+					if let Some(callback) = &session_builder.ep_event_callback {
+						callback(ex.as_str(), Err(&e));
+					}
+					failure = Some(e.to_string());
+					continue;
+				}
+			}
+
+			tracing::debug!("Registering `{}` with options {:?}", ex.as_str(), ex.effective_options());
+			// registration can be slow for some EPs (e.g. TensorRT building engines), so time it to help diagnose cold starts
+			let start = Instant::now();
+			let result = ex.register(session_builder);
+			let duration = start.elapsed();
+			if let Err(e) = &result {
+				if let Error::ExecutionProviderNotRegistered(_) = e {
+					tracing::debug!("{e} (after {duration:.2?})");
+				} else {
+					tracing::warn!("An error occurred when attempting to register `{}` after {duration:.2?}: {e}", ex.as_str());
+				}
 			} else {
-				tracing::warn!("An error occurred when attempting to register `{}`: {e}", ex.as_str());
+				tracing::info!("Successfully registered `{}` in {duration:.2?}", ex.as_str());
+				fallback_to_cpu = false;
+			}
+			registrations.push(EpRegistration {
+				name: ex.as_str(),
+				duration,
+				registered: result.is_ok()
+			});
+			if let Some(callback) = &session_builder.ep_event_callback {
+				callback(ex.as_str(), result.as_ref().map(|_| ()));
+			}
+			match result {
+				Ok(()) => {
+					failure = None;
+					break;
+				}
+				Err(e) => failure = Some(format!("`{}`: {e}", ex.as_str()))
+			}
+		}
+		if let Some(reason) = failure {
+			if dispatch.is_required() && required_failure.is_none() {
+				let name = dispatch.alternatives().iter().map(|alt| alt.as_str()).collect::<Vec<_>>().join(" | ");
+				required_failure = Some((name, reason));
 			}
-		} else {
-			tracing::info!("Successfully registered `{}`", ex.as_str());
-			fallback_to_cpu = false;
 		}
 	}
+	if let Some((name, reason)) = required_failure {
+		return Err(Error::RequiredExecutionProviderNotRegistered {
+			name,
+			reason,
+			registered: registrations.iter().filter(|r| r.registered).map(|r| r.name).collect()
+		});
+	}
 	if fallback_to_cpu {
 		tracing::warn!("No execution providers registered successfully. Falling back to CPU.");
 	}
+	Ok(registrations)
+}
+
+#[cfg(test)]
+mod tests {
+	use test_log::test;
+
+	use super::*;
+
+	#[test]
+	fn test_register_cpu() -> Result<()> {
+		for cpu in [
+			CPUExecutionProvider::default(),
+			CPUExecutionProvider::default().with_arena_allocator(),
+			CPUExecutionProvider::default().with_arena_allocator().with_intra_threads(2)
+		] {
+			let session_builder = SessionBuilder::new()?;
+			cpu.register(&session_builder)?;
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_registration_timing() -> Result<()> {
+		let session_builder = SessionBuilder::new()?;
+		let (registrations, output) = crate::test_util::capture_logs(tracing::Level::INFO, || {
+			apply_execution_providers(&session_builder, std::iter::once(CPUExecutionProvider::default().with_arena_allocator().build()))
+		});
+		let registrations = registrations?;
+		assert_eq!(registrations.len(), 1);
+		assert_eq!(registrations[0].name, "CPUExecutionProvider");
+		assert!(registrations[0].registered);
+
+		// the time each EP took to register is logged along with its name
+		let timing_logs: Vec<&str> = output.lines().filter(|l| l.contains("Successfully registered `CPUExecutionProvider` in ")).collect();
+		assert_eq!(timing_logs.len(), 1, "expected a single timing log: {output}");
+		assert!(timing_logs[0].contains(&format!("{:.2?}", registrations[0].duration)), "unexpected timing log: {}", timing_logs[0]);
+		Ok(())
+	}
+
+	#[test]
+	fn test_or_else() -> Result<()> {
+		let session_builder = SessionBuilder::new()?;
+		// the `cuda` feature is disabled (or CUDA is unavailable) in tests, so registration of the primary EP must fail
+		let dispatch = CUDAExecutionProvider::default()
+			.build()
+			.or_else(CPUExecutionProvider::default().with_arena_allocator())
+			.or_else(CPUExecutionProvider::default());
+		assert_eq!(dispatch.alternatives().len(), 3);
+
+		let registrations = apply_execution_providers(&session_builder, std::iter::once(dispatch))?;
+		let attempted: Vec<(&str, bool)> = registrations.iter().map(|r| (r.name, r.registered)).collect();
+		assert_eq!(attempted, [("CUDAExecutionProvider", false), ("CPUExecutionProvider", true)]);
+		Ok(())
+	}
+
+	#[test]
+	fn test_error_on_failure() -> Result<()> {
+		// neither TensorRT nor CUDA are available in tests
+		let execution_providers = [
+			TensorRTExecutionProvider::default().build(),
+			CPUExecutionProvider::default().build(),
+			CUDAExecutionProvider::default().build().error_on_failure()
+		];
+
+		let session_builder = SessionBuilder::new()?;
+		match apply_execution_providers(&session_builder, execution_providers.into_iter()) {
+			Err(Error::RequiredExecutionProviderNotRegistered { name, reason, registered }) => {
+				assert_eq!(name, "CUDAExecutionProvider");
+				assert!(reason.contains("CUDAExecutionProvider") && !reason.contains("TensorRT"), "unexpected reason: {reason}");
+				// optional EPs listed before the required one are still attempted
+				assert_eq!(registered, ["CPUExecutionProvider"]);
+			}
+			r => panic!("expected required EP failure, got {r:?}")
+		}
+
+		// required EPs only fail if none of their alternatives register
+		let session_builder = SessionBuilder::new()?;
+		let dispatch = CUDAExecutionProvider::default().build().or_else(CPUExecutionProvider::default()).error_on_failure();
+		assert!(apply_execution_providers(&session_builder, std::iter::once(dispatch))?.iter().any(|r| r.registered));
+		Ok(())
+	}
+
+	#[test]
+	fn test_provider_names() -> Result<()> {
+		// these must match the names ONNX Runtime uses in `GetAvailableProviders` (see `onnxruntime/core/graph/constants.h`),
+		// otherwise the EP is always treated as unavailable and skipped
+		let names: Vec<&str> = [
+			CPUExecutionProvider::default().build(),
+			CUDAExecutionProvider::default().build(),
+			TensorRTExecutionProvider::default().build(),
+			OpenVINOExecutionProvider::default().build(),
+			ACLExecutionProvider::default().build(),
+			OneDNNExecutionProvider::default().build(),
+			CoreMLExecutionProvider::default().build(),
+			DirectMLExecutionProvider::default().build(),
+			ROCmExecutionProvider::default().build(),
+			NNAPIExecutionProvider::default().build(),
+			QNNExecutionProvider::default().build(),
+			TVMExecutionProvider::default().build(),
+			CANNExecutionProvider::default().build(),
+			XNNPACKExecutionProvider::default().build(),
+			ArmNNExecutionProvider::default().build(),
+			MIGraphXExecutionProvider::default().build()
+		]
+		.iter()
+		.map(|ep| ep.as_str())
+		.collect();
+		assert_eq!(
+			names,
+			[
+				"CPUExecutionProvider",
+				"CUDAExecutionProvider",
+				"TensorrtExecutionProvider",
+				"OpenVINOExecutionProvider",
+				"ACLExecutionProvider",
+				"DnnlExecutionProvider",
+				"CoreMLExecutionProvider",
+				"DmlExecutionProvider",
+				"ROCMExecutionProvider",
+				"NnapiExecutionProvider",
+				"QNNExecutionProvider",
+				"TvmExecutionProvider",
+				"CANNExecutionProvider",
+				"XnnpackExecutionProvider",
+				"ArmNNExecutionProvider",
+				"MIGraphXExecutionProvider"
+			]
+		);
+
+		// tests run against a CPU-only build of ONNX Runtime, so only the CPU EP's name can be checked against it directly
+		assert!(available_execution_providers()?.iter().any(|ep| ep == names[0]));
+		Ok(())
+	}
+
+	#[test]
+	fn test_skip_unavailable() -> Result<()> {
+		// tests run against a CPU-only build of ONNX Runtime
+		assert!(!available_execution_providers()?.iter().any(|ep| ep == "CUDAExecutionProvider"));
+
+		let session_builder = SessionBuilder::new()?;
+		let (registrations, output) = crate::test_util::capture_logs(tracing::Level::DEBUG, || {
+			apply_execution_providers(&session_builder, std::iter::once(CUDAExecutionProvider::default().build().or_else(CPUExecutionProvider::default())))
+		});
+		let registrations = registrations?;
+		let attempted: Vec<(&str, bool)> = registrations.iter().map(|r| (r.name, r.registered)).collect();
+		assert_eq!(attempted, [("CUDAExecutionProvider", false), ("CPUExecutionProvider", true)]);
+
+		let cuda_logs: Vec<&str> = output.lines().filter(|l| l.contains("CUDAExecutionProvider")).collect();
+		assert_eq!(cuda_logs.len(), 1, "expected a single log for the unavailable EP: {output}");
+		assert!(cuda_logs[0].contains("WARN") && cuda_logs[0].contains("skipping registration"), "unexpected log: {}", cuda_logs[0]);
+		assert_eq!(output.matches("WARN").count(), 1, "unexpected warnings: {output}");
+		assert!(!output.contains("ERROR"), "unexpected errors: {output}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_on_ep_event() -> Result<()> {
+		use std::sync::Mutex;
+
+		let events = Arc::new(Mutex::new(Vec::new()));
+		let session_builder = SessionBuilder::new()?.on_ep_event({
+			let events = Arc::clone(&events);
+			move |name, result| events.lock().unwrap().push((name.to_string(), result.is_ok()))
+		})?;
+		let execution_providers = [
+			CUDAExecutionProvider::default().build(),
+			TensorRTExecutionProvider::default().build().or_else(CPUExecutionProvider::default())
+		];
+		apply_execution_providers(&session_builder, execution_providers.into_iter())?;
+
+		let events = events.lock().unwrap();
+		assert_eq!(
+			*events,
+			[
+				("CUDAExecutionProvider".to_string(), false),
+				("TensorrtExecutionProvider".to_string(), false),
+				("CPUExecutionProvider".to_string(), true)
+			]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_ep_registration_detail() -> Result<()> {
+		let session_builder = SessionBuilder::new()?;
+		let provider_name = std::ffi::CString::new("NotARealExecutionProvider").unwrap();
+		let status = ortsys![unsafe SessionOptionsAppendExecutionProvider(
+			session_builder.session_options_ptr,
+			provider_name.as_ptr(),
+			std::ptr::null(),
+			std::ptr::null(),
+			0
+		)];
+		let err = crate::error::status_to_result(status)
+			.map_err(Error::ExecutionProvider)
+		.expect_err("registering an unknown execution provider should fail");
+
+		let detail = err.ep_registration_detail().expect("registration errors should carry ONNX Runtime's message");
+		assert!(detail.contains("Unknown provider name"), "unexpected message: {detail}");
+		// the message should be surfaced as-is, without any added context
+		assert_eq!(err.to_string(), detail);
+
+		assert!(Error::ExecutionProviderNotRegistered("CUDAExecutionProvider").ep_registration_detail().is_none());
+		Ok(())
+	}
+
+	#[test]
+	fn test_probe_cpu() -> Result<()> {
+		assert_eq!(CPUExecutionProvider::default().probe()?, ProbeResult::Available);
+		assert_eq!(CPUExecutionProvider::default().build().probe()?, ProbeResult::Available);
+		Ok(())
+	}
 }
@@ -1,4 +1,4 @@
-use std::os::raw::c_void;
+use std::{collections::HashMap, os::raw::c_void};
 
 use super::ExecutionProvider;
 use crate::{Error, ExecutionProviderDispatch, Result, SessionBuilder};
@@ -12,7 +12,8 @@ pub struct OpenVINOExecutionProvider {
 	context: *mut c_void,
 	enable_opencl_throttling: bool,
 	enable_dynamic_shapes: bool,
-	enable_npu_fast_compile: bool
+	enable_npu_fast_compile: bool,
+	export_ep_ctx_blob: bool
 }
 
 unsafe impl Send for OpenVINOExecutionProvider {}
@@ -28,7 +29,8 @@ impl Default for OpenVINOExecutionProvider {
 			context: std::ptr::null_mut(),
 			enable_opencl_throttling: false,
 			enable_dynamic_shapes: false,
-			enable_npu_fast_compile: false
+			enable_npu_fast_compile: false,
+			export_ep_ctx_blob: false
 		}
 	}
 }
@@ -55,7 +57,8 @@ impl OpenVINOExecutionProvider {
 		self
 	}
 
-	/// Explicitly specify the path to save and load the blobs, enabling model caching.
+	/// Explicitly specify the path to save and load the blobs, enabling model caching. Combine with
+	/// [`OpenVINOExecutionProvider::with_export_context_blob`] to export the compiled blob for later sessions.
 	pub fn with_cache_dir(mut self, dir: impl ToString) -> Self {
 		self.cache_dir = Some(dir.to_string());
 		self
@@ -87,9 +90,35 @@ impl OpenVINOExecutionProvider {
 		self
 	}
 
+	/// Configure whether to export the compiled model as an EP context blob (into the directory configured with
+	/// [`OpenVINOExecutionProvider::with_cache_dir`]), so later sessions can import it and skip recompilation. This can
+	/// drastically reduce cold start times.
+	///
+	/// When enabled, the EP is registered using ONNX Runtime's string-keyed provider options rather than the legacy
+	/// options struct, which has no field for this option; [`OpenVINOExecutionProvider::with_opencl_context`] is not
+	/// supported in this mode.
+	pub fn with_export_context_blob(mut self, enable: bool) -> Self {
+		self.export_ep_ctx_blob = enable;
+		self
+	}
+
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
+
+	/// Returns the provider option keys & values to pass to `SessionOptionsAppendExecutionProvider`.
+	fn options(&self) -> Result<super::ProviderOptions> {
+		Ok(super::map_keys! {
+			device_type = self.device_type.clone(),
+			device_id = self.device_id.clone(),
+			num_of_threads = Some(self.num_threads),
+			cache_dir = self.cache_dir.clone(),
+			enable_opencl_throttling = self.enable_opencl_throttling.then_some("true"),
+			enable_dynamic_shapes = self.enable_dynamic_shapes.then_some("true"),
+			enable_npu_fast_compile = self.enable_npu_fast_compile.then_some("true"),
+			export_ep_ctx_blob = self.export_ep_ctx_blob.then_some("true")
+		})
+	}
 }
 
 impl From<OpenVINOExecutionProvider> for ExecutionProviderDispatch {
@@ -103,27 +132,40 @@ impl ExecutionProvider for OpenVINOExecutionProvider {
 		"OpenVINOExecutionProvider"
 	}
 
+	fn check_options(&self) -> Result<()> {
+		self.options().map(drop)
+	}
+
+	fn effective_options(&self) -> HashMap<String, String> {
+		self.options().map(|(_, _, _, keys, values)| super::options_map(&keys, &values)).unwrap_or_default()
+	}
+
 	#[allow(unused, unreachable_code)]
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
 		#[cfg(any(feature = "load-dynamic", feature = "openvino"))]
 		{
+			if self.export_ep_ctx_blob {
+				let (key_ptrs, value_ptrs, len, keys, values) = self.options()?;
+				let ep_name = std::ffi::CString::new("OpenVINO").unwrap();
+				return crate::error::status_to_result(crate::ortsys![unsafe SessionOptionsAppendExecutionProvider(
+					session_builder.session_options_ptr,
+					ep_name.as_ptr(),
+					key_ptrs.as_ptr(),
+					value_ptrs.as_ptr(),
+					len as _,
+				)])
+				.map_err(Error::ExecutionProvider);
+			}
+
+			// these must stay alive until the EP is registered
+			let device_type = self.device_type.as_deref().map(std::ffi::CString::new).transpose()?;
+			let device_id = self.device_id.as_deref().map(std::ffi::CString::new).transpose()?;
+			let cache_dir = self.cache_dir.as_deref().map(std::ffi::CString::new).transpose()?;
 			let openvino_options = ort_sys::OrtOpenVINOProviderOptions {
-				device_type: self
-					.device_type
-					.clone()
-					.map(|x| x.as_bytes().as_ptr() as *const std::ffi::c_char)
-					.unwrap_or_else(std::ptr::null),
-				device_id: self
-					.device_id
-					.clone()
-					.map(|x| x.as_bytes().as_ptr() as *const std::ffi::c_char)
-					.unwrap_or_else(std::ptr::null),
+				device_type: device_type.as_ref().map_or(std::ptr::null(), |x| x.as_ptr()),
+				device_id: device_id.as_ref().map_or(std::ptr::null(), |x| x.as_ptr()),
 				num_of_threads: self.num_threads,
-				cache_dir: self
-					.cache_dir
-					.clone()
-					.map(|x| x.as_bytes().as_ptr() as *const std::ffi::c_char)
-					.unwrap_or_else(std::ptr::null),
+				cache_dir: cache_dir.as_ref().map_or(std::ptr::null(), |x| x.as_ptr()),
 				context: self.context,
 				enable_opencl_throttling: self.enable_opencl_throttling.into(),
 				enable_dynamic_shapes: self.enable_dynamic_shapes.into(),
@@ -138,3 +180,27 @@ impl ExecutionProvider for OpenVINOExecutionProvider {
 		Err(Error::ExecutionProviderNotRegistered(self.as_str()))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_options() {
+		let ep = OpenVINOExecutionProvider::default()
+			.with_device_type("GPU_FP16")
+			.with_cache_dir("/tmp/ov-cache")
+			.with_export_context_blob(true);
+		let (.., len, keys, values) = ep.options().unwrap();
+		assert_eq!(len, 4);
+
+		let options: Vec<(&str, &str)> = keys.iter().zip(values.iter()).map(|(k, v)| (k.to_str().unwrap(), v.to_str().unwrap())).collect();
+		assert_eq!(
+			options,
+			[("device_type", "GPU_FP16"), ("num_of_threads", "8"), ("cache_dir", "/tmp/ov-cache"), ("export_ep_ctx_blob", "true")]
+		);
+
+		let (.., keys, _) = OpenVINOExecutionProvider::default().with_export_context_blob(false).options().unwrap();
+		assert!(!keys.iter().any(|k| k.to_str().unwrap() == "export_ep_ctx_blob"));
+	}
+}
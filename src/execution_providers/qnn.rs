@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use super::ExecutionProvider;
 use crate::{Error, ExecutionProviderDispatch, Result, SessionBuilder};
 
@@ -98,6 +100,18 @@ impl QNNExecutionProvider {
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
+
+	/// Returns the provider option keys & values to pass to `SessionOptionsAppendExecutionProvider`.
+	fn options(&self) -> Result<super::ProviderOptions> {
+		Ok(super::map_keys! {
+			backend_path = self.backend_path.clone(),
+			profiling_level = self.profiling_level.as_ref().map(|v| v.as_str()),
+			qnn_context_cache_enable = self.qnn_context_cache_enable.map(<bool as Into<i32>>::into),
+			qnn_context_cache_path = self.qnn_context_cache_path.clone(),
+			htp_performance_mode = self.htp_performance_mode.as_ref().map(|v| v.as_str()),
+			rpc_control_latency = self.rpc_control_latency
+		})
+	}
 }
 
 impl From<QNNExecutionProvider> for ExecutionProviderDispatch {
@@ -111,18 +125,19 @@ impl ExecutionProvider for QNNExecutionProvider {
 		"QNNExecutionProvider"
 	}
 
+	fn check_options(&self) -> Result<()> {
+		self.options().map(drop)
+	}
+
+	fn effective_options(&self) -> HashMap<String, String> {
+		self.options().map(|(_, _, _, keys, values)| super::options_map(&keys, &values)).unwrap_or_default()
+	}
+
 	#[allow(unused, unreachable_code)]
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
 		#[cfg(any(feature = "load-dynamic", feature = "qnn"))]
 		{
-			let (key_ptrs, value_ptrs, len, _keys, _values) = super::map_keys! {
-				backend_path = self.backend_path.clone(),
-				profiling_level = self.profiling_level.as_ref().map(|v| v.as_str()),
-				qnn_context_cache_enable = self.qnn_context_cache_enable.map(<bool as Into<i32>>::into),
-				qnn_context_cache_path = self.qnn_context_cache_path.clone(),
-				htp_performance_mode = self.htp_performance_mode.as_ref().map(|v| v.as_str()),
-				rpc_control_latency = self.rpc_control_latency
-			};
+			let (key_ptrs, value_ptrs, len, _keys, _values) = self.options()?;
 			let ep_name = std::ffi::CString::new("QNN").unwrap();
 			return crate::error::status_to_result(crate::ortsys![unsafe SessionOptionsAppendExecutionProvider(
 				session_builder.session_options_ptr,
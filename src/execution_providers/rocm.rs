@@ -43,6 +43,11 @@ impl ROCmExecutionProvider {
 		self
 	}
 
+	/// Returns the ID of the device this execution provider will run on.
+	pub fn device_id(&self) -> i32 {
+		self.device_id
+	}
+
 	pub fn with_exhaustive_conv_search(mut self) -> Self {
 		self.miopen_conv_exhaustive_search = true;
 		self
@@ -101,7 +106,7 @@ impl From<ROCmExecutionProvider> for ExecutionProviderDispatch {
 
 impl ExecutionProvider for ROCmExecutionProvider {
 	fn as_str(&self) -> &'static str {
-		"ROCmExecutionProvider"
+		"ROCMExecutionProvider"
 	}
 
 	#[allow(unused, unreachable_code)]
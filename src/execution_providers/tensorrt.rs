@@ -1,4 +1,6 @@
-use super::ExecutionProvider;
+use std::collections::HashMap;
+
+use super::{ArbitrarilyConfigurableExecutionProvider, ExecutionProvider};
 use crate::{Error, ExecutionProviderDispatch, Result, SessionBuilder};
 
 #[derive(Debug, Default, Clone)]
@@ -30,9 +32,51 @@ pub struct TensorRTExecutionProvider {
 	extra_plugin_lib_paths: Option<String>,
 	profile_min_shapes: Option<String>,
 	profile_max_shapes: Option<String>,
-	profile_opt_shapes: Option<String>
+	profile_opt_shapes: Option<String>,
+	arbitrary_config: Vec<(String, String)>
 }
 
+/// Options supported by the TensorRT execution provider in ONNX Runtime v1.17.
+const KNOWN_CONFIG_KEYS: &[&str] = &[
+	"device_id",
+	"has_user_compute_stream",
+	"user_compute_stream",
+	"trt_max_partition_iterations",
+	"trt_min_subgraph_size",
+	"trt_max_workspace_size",
+	"trt_fp16_enable",
+	"trt_int8_enable",
+	"trt_int8_calibration_table_name",
+	"trt_int8_use_native_calibration_table",
+	"trt_dla_enable",
+	"trt_dla_core",
+	"trt_dump_subgraphs",
+	"trt_engine_cache_enable",
+	"trt_engine_cache_path",
+	"trt_engine_decryption_enable",
+	"trt_engine_decryption_lib_path",
+	"trt_force_sequential_engine_build",
+	"trt_context_memory_sharing_enable",
+	"trt_layer_norm_fp32_fallback",
+	"trt_timing_cache_enable",
+	"trt_timing_cache_path",
+	"trt_force_timing_cache",
+	"trt_detailed_build_log",
+	"trt_build_heuristics_enable",
+	"trt_sparsity_enable",
+	"trt_builder_optimization_level",
+	"trt_auxiliary_streams",
+	"trt_tactic_sources",
+	"trt_extra_plugin_lib_paths",
+	"trt_profile_min_shapes",
+	"trt_profile_max_shapes",
+	"trt_profile_opt_shapes",
+	"trt_cuda_graph_enable",
+	"trt_dump_ep_context_model",
+	"trt_ep_context_file_path",
+	"trt_ep_context_embed_mode"
+];
+
 impl TensorRTExecutionProvider {
 	pub fn with_device_id(mut self, device_id: i32) -> Self {
 		self.device_id = Some(device_id);
@@ -64,11 +108,18 @@ impl TensorRTExecutionProvider {
 		self
 	}
 
+	/// Enables offloading to NVIDIA's Deep Learning Accelerator (DLA) cores, available on Jetson (Xavier & Orin) and
+	/// DRIVE platforms. See also [`TensorRTExecutionProvider::with_dla_core`].
+	///
+	/// Only certain layers can run on the DLA, and typically only with FP16 or INT8 precision (see
+	/// [`TensorRTExecutionProvider::with_fp16`]); TensorRT runs unsupported layers on the GPU instead.
 	pub fn with_dla(mut self, enable: bool) -> Self {
 		self.dla_enable = Some(enable);
 		self
 	}
 
+	/// Selects which DLA core to run on when the DLA is [enabled](TensorRTExecutionProvider::with_dla). Defaults to
+	/// core 0; Jetson AGX modules have 2 DLA cores.
 	pub fn with_dla_core(mut self, core: u32) -> Self {
 		self.dla_core = Some(core);
 		self
@@ -174,9 +225,88 @@ impl TensorRTExecutionProvider {
 		self
 	}
 
+	/// Configures an explicit optimization profile for models with dynamic input shapes, so that TensorRT builds a
+	/// single engine covering the whole range of shapes rather than rebuilding engines as new shapes are encountered.
+	///
+	/// Each argument uses ONNX Runtime's syntax of comma-separated `input_name:dims` entries, where `dims` are
+	/// separated by `x`, e.g. `input_ids:1x16,attention_mask:1x16`. All three should list the same inputs; this is
+	/// checked on a best-effort basis, with a warning logged if they don't.
+	///
+	/// ```
+	/// # use ort::TensorRTExecutionProvider;
+	/// let ep = TensorRTExecutionProvider::default().with_profile_shapes("images:1x3x224x224", "images:8x3x224x224", "images:32x3x224x224");
+	/// ```
+	pub fn with_profile_shapes(self, min: impl ToString, opt: impl ToString, max: impl ToString) -> Self {
+		let (min, opt, max) = (min.to_string(), opt.to_string(), max.to_string());
+		let (min_inputs, opt_inputs, max_inputs) = (profile_input_names(&min), profile_input_names(&opt), profile_input_names(&max));
+		if min_inputs != opt_inputs || min_inputs != max_inputs {
+			tracing::warn!(
+				"TensorRT profile shapes list different inputs (min: {min_inputs:?}, opt: {opt_inputs:?}, max: {max_inputs:?}); ONNX Runtime may reject them"
+			);
+		}
+		self.with_profile_min_shapes(min).with_profile_opt_shapes(opt).with_profile_max_shapes(max)
+	}
+
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
+
+	/// Returns the provider option keys & values to pass to `UpdateTensorRTProviderOptions`.
+	fn options(&self) -> Result<super::ProviderOptions> {
+		Ok(super::map_keys! {
+			device_id = self.device_id,
+			trt_max_workspace_size = self.max_workspace_size,
+			trt_max_partition_iterations = self.max_partition_iterations,
+			trt_min_subgraph_size = self.min_subgraph_size,
+			trt_fp16_enable = self.fp16_enable.map(<bool as Into<i32>>::into),
+			trt_int8_enable = self.int8_enable.map(<bool as Into<i32>>::into),
+			trt_int8_use_native_calibration_table = self.int8_use_native_calibration_table.map(<bool as Into<i32>>::into),
+			trt_int8_calibration_table_name = self.int8_calibration_table_name.clone(),
+			trt_dla_enable = self.dla_enable.map(<bool as Into<i32>>::into),
+			trt_dla_core = self.dla_core,
+			trt_engine_cache_enable = self.engine_cache_enable.map(<bool as Into<i32>>::into),
+			trt_engine_cache_path = self.engine_cache_path.clone(),
+			trt_dump_subgraphs = self.dump_subgraphs.map(<bool as Into<i32>>::into),
+			trt_force_sequential_engine_build = self.force_sequential_engine_build.map(<bool as Into<i32>>::into),
+			trt_context_memory_sharing_enable = self.enable_context_memory_sharing.map(<bool as Into<i32>>::into),
+			trt_layer_norm_fp32_fallback = self.layer_norm_fp32_fallback.map(<bool as Into<i32>>::into),
+			trt_timing_cache_enable = self.timing_cache_enable.map(<bool as Into<i32>>::into),
+			// https://github.com/microsoft/onnxruntime/pull/17956
+			trt_force_timing_cache = self.force_timing_cache.map(<bool as Into<i32>>::into),
+			trt_detailed_build_log = self.detailed_build_log.map(<bool as Into<i32>>::into),
+			trt_build_heuristics_enable = self.enable_build_heuristics.map(<bool as Into<i32>>::into),
+			trt_sparsity_enable = self.enable_sparsity.map(<bool as Into<i32>>::into),
+			trt_builder_optimization_level = self.builder_optimization_level,
+			trt_auxiliary_streams = self.auxiliary_streams,
+			trt_tactic_sources = self.tactic_sources.clone(),
+			trt_extra_plugin_lib_paths = self.extra_plugin_lib_paths.clone(),
+			trt_profile_min_shapes = self.profile_min_shapes.clone(),
+			trt_profile_max_shapes = self.profile_max_shapes.clone(),
+			trt_profile_opt_shapes = self.profile_opt_shapes.clone();
+			&self.arbitrary_config
+		})
+	}
+}
+
+/// Returns the sorted input names in a profile shapes string like `input1:1x3x224x224,input2:1x16`.
+fn profile_input_names(shapes: &str) -> Vec<&str> {
+	let mut names: Vec<&str> = shapes
+		.split(',')
+		.filter(|entry| !entry.trim().is_empty())
+		// input names may contain colons themselves, but the dims never do
+		.map(|entry| entry.rsplit_once(':').map_or(entry, |(name, _)| name).trim())
+		.collect();
+	names.sort_unstable();
+	names
+}
+
+impl ArbitrarilyConfigurableExecutionProvider for TensorRTExecutionProvider {
+	fn with_arbitrary_config(mut self, key: impl ToString, value: impl ToString) -> Self {
+		let key = key.to_string();
+		super::check_config_key(self.as_str(), KNOWN_CONFIG_KEYS, &key);
+		self.arbitrary_config.push((key, value.to_string()));
+		self
+	}
 }
 
 impl From<TensorRTExecutionProvider> for ExecutionProviderDispatch {
@@ -190,43 +320,21 @@ impl ExecutionProvider for TensorRTExecutionProvider {
 		"TensorrtExecutionProvider"
 	}
 
+	fn check_options(&self) -> Result<()> {
+		self.options().map(drop)
+	}
+
+	fn effective_options(&self) -> HashMap<String, String> {
+		self.options().map(|(_, _, _, keys, values)| super::options_map(&keys, &values)).unwrap_or_default()
+	}
+
 	#[allow(unused, unreachable_code)]
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
 		#[cfg(any(feature = "load-dynamic", feature = "tensorrt"))]
 		{
+			let (key_ptrs, value_ptrs, len, keys, values) = self.options()?;
 			let mut trt_options: *mut ort_sys::OrtTensorRTProviderOptionsV2 = std::ptr::null_mut();
 			crate::error::status_to_result(crate::ortsys![unsafe CreateTensorRTProviderOptions(&mut trt_options)]).map_err(Error::ExecutionProvider)?;
-			let (key_ptrs, value_ptrs, len, keys, values) = super::map_keys! {
-				device_id = self.device_id,
-				trt_max_workspace_size = self.max_workspace_size,
-				trt_max_partition_iterations = self.max_partition_iterations,
-				trt_min_subgraph_size = self.min_subgraph_size,
-				trt_fp16_enable = self.fp16_enable.map(<bool as Into<i32>>::into),
-				trt_int8_enable = self.int8_enable.map(<bool as Into<i32>>::into),
-				trt_int8_use_native_calibration_table = self.int8_use_native_calibration_table.map(<bool as Into<i32>>::into),
-				trt_int8_calibration_table_name = self.int8_calibration_table_name.clone(),
-				trt_dla_enable = self.dla_enable.map(<bool as Into<i32>>::into),
-				trt_dla_core = self.dla_core,
-				trt_engine_cache_enable = self.engine_cache_enable.map(<bool as Into<i32>>::into),
-				trt_engine_cache_path = self.engine_cache_path.clone(),
-				trt_dump_subgraphs = self.dump_subgraphs.map(<bool as Into<i32>>::into),
-				trt_force_sequential_engine_build = self.force_sequential_engine_build.map(<bool as Into<i32>>::into),
-				trt_context_memory_sharing_enable = self.enable_context_memory_sharing.map(<bool as Into<i32>>::into),
-				trt_layer_norm_fp32_fallback = self.layer_norm_fp32_fallback.map(<bool as Into<i32>>::into),
-				trt_timing_cache_enable = self.timing_cache_enable.map(<bool as Into<i32>>::into),
-				// https://github.com/microsoft/onnxruntime/pull/17956
-				trt_force_timing_cache = self.force_timing_cache.map(<bool as Into<i32>>::into),
-				trt_detailed_build_log = self.detailed_build_log.map(<bool as Into<i32>>::into),
-				trt_build_heuristics_enable = self.enable_build_heuristics.map(<bool as Into<i32>>::into),
-				trt_sparsity_enable = self.enable_sparsity.map(<bool as Into<i32>>::into),
-				trt_builder_optimization_level = self.builder_optimization_level,
-				trt_auxiliary_streams = self.auxiliary_streams,
-				trt_tactic_sources = self.tactic_sources.clone(),
-				trt_extra_plugin_lib_paths = self.extra_plugin_lib_paths.clone(),
-				trt_profile_min_shapes = self.profile_min_shapes.clone(),
-				trt_profile_max_shapes = self.profile_max_shapes.clone(),
-				trt_profile_opt_shapes = self.profile_opt_shapes.clone()
-			};
 			if let Err(e) = crate::error::status_to_result(
 				crate::ortsys![unsafe UpdateTensorRTProviderOptions(trt_options, key_ptrs.as_ptr(), value_ptrs.as_ptr(), len as _)]
 			)
@@ -246,3 +354,61 @@ impl ExecutionProvider for TensorRTExecutionProvider {
 		Err(Error::ExecutionProviderNotRegistered(self.as_str()))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_profile_shapes() {
+		let ep = TensorRTExecutionProvider::default().with_device_id(0).with_profile_shapes(
+			"input_ids:1x1,attention_mask:1x1",
+			"attention_mask:1x128,input_ids:1x128",
+			"input_ids:8x512,attention_mask:8x512"
+		);
+		let (.., len, keys, values) = ep.options().unwrap();
+		assert_eq!(len, 4);
+
+		let options: Vec<(&str, &str)> = keys.iter().zip(values.iter()).map(|(k, v)| (k.to_str().unwrap(), v.to_str().unwrap())).collect();
+		assert_eq!(
+			options,
+			[
+				("device_id", "0"),
+				("trt_profile_min_shapes", "input_ids:1x1,attention_mask:1x1"),
+				("trt_profile_max_shapes", "input_ids:8x512,attention_mask:8x512"),
+				("trt_profile_opt_shapes", "attention_mask:1x128,input_ids:1x128")
+			]
+		);
+	}
+
+	#[test]
+	fn test_dla() {
+		let ep = TensorRTExecutionProvider::default().with_fp16(true).with_dla(true).with_dla_core(1);
+		let (.., keys, values) = ep.options().unwrap();
+		let options: Vec<(&str, &str)> = keys.iter().zip(values.iter()).map(|(k, v)| (k.to_str().unwrap(), v.to_str().unwrap())).collect();
+		assert_eq!(options, [("trt_fp16_enable", "1"), ("trt_dla_enable", "1"), ("trt_dla_core", "1")]);
+
+		let options = TensorRTExecutionProvider::default().with_dla(false).effective_options();
+		assert_eq!(options["trt_dla_enable"], "0");
+		assert!(!options.contains_key("trt_dla_core"));
+	}
+
+	#[test]
+	fn test_profile_input_names() {
+		assert_eq!(profile_input_names("b:1x3,a:1x16"), ["a", "b"]);
+		assert_eq!(profile_input_names("encoder:hidden_states:1x64x768"), ["encoder:hidden_states"]);
+		assert!(profile_input_names("").is_empty());
+		assert_ne!(profile_input_names("a:1x16"), profile_input_names("a:1x16,b:1x16"));
+	}
+
+	#[test]
+	fn test_try_build() {
+		let ep = TensorRTExecutionProvider::default().with_engine_cache_path("/tmp/trt\0cache");
+		assert!(matches!(ep.clone().try_build(), Err(Error::FfiStringNull(_))));
+		// options which can't be passed to ONNX Runtime aren't reported as effective
+		assert!(ep.effective_options().is_empty());
+
+		let dispatch = TensorRTExecutionProvider::default().with_engine_cache_path("/tmp/trt-cache").try_build().unwrap();
+		assert_eq!(dispatch.effective_options()["trt_engine_cache_path"], "/tmp/trt-cache");
+	}
+}
@@ -3,7 +3,7 @@ use crate::{Error, ExecutionProviderDispatch, Result, SessionBuilder};
 
 #[cfg(all(not(feature = "load-dynamic"), feature = "tvm"))]
 extern "C" {
-	fn OrtSessionOptionsAppendExecutionProvider_Tvm(options: *mut ort_sys::OrtSessionOptions, use_arena: std::os::raw::c_int) -> ort_sys::OrtStatusPtr;
+	fn OrtSessionOptionsAppendExecutionProvider_Tvm(options: *mut ort_sys::OrtSessionOptions, opt_str: *const std::os::raw::c_char) -> ort_sys::OrtStatusPtr;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,6 +49,51 @@ impl TVMExecutionProvider {
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
+
+	/// Returns the comma-separated options string to pass to `OrtSessionOptionsAppendExecutionProvider_Tvm`.
+	fn options_string(&self) -> Result<std::ffi::CString> {
+		let mut option_string = Vec::new();
+		if let Some(check_hash) = self.check_hash {
+			option_string.push(format!("check_hash:{}", if check_hash { "True" } else { "False" }));
+		}
+		if let Some(executor) = self.executor {
+			option_string.push(format!(
+				"executor:{}",
+				match executor {
+					TVMExecutorType::GraphExecutor => "graph",
+					TVMExecutorType::VirtualMachine => "vm"
+				}
+			));
+		}
+		if let Some(freeze_weights) = self.freeze_weights {
+			option_string.push(format!("freeze_weights:{}", if freeze_weights { "True" } else { "False" }));
+		}
+		if let Some(hash_file_path) = self.hash_file_path.as_ref() {
+			option_string.push(format!("hash_file_path:{hash_file_path}"));
+		}
+		if let Some(input_names) = self.input_names.as_ref() {
+			option_string.push(format!("input_names:{input_names}"));
+		}
+		if let Some(input_shapes) = self.input_shapes.as_ref() {
+			option_string.push(format!("input_shapes:{input_shapes}"));
+		}
+		if let Some(opt_level) = self.opt_level {
+			option_string.push(format!("opt_level:{opt_level}"));
+		}
+		if let Some(so_folder) = self.so_folder.as_ref() {
+			option_string.push(format!("so_folder:{so_folder}"));
+		}
+		if let Some(target) = self.target.as_ref() {
+			option_string.push(format!("target:{target}"));
+		}
+		if let Some(target_host) = self.target_host.as_ref() {
+			option_string.push(format!("target_host:{target_host}"));
+		}
+		if let Some(to_nhwc) = self.to_nhwc {
+			option_string.push(format!("to_nhwc:{}", if to_nhwc { "True" } else { "False" }));
+		}
+		Ok(std::ffi::CString::new(option_string.join(","))?)
+	}
 }
 
 impl From<TVMExecutionProvider> for ExecutionProviderDispatch {
@@ -62,52 +107,16 @@ impl ExecutionProvider for TVMExecutionProvider {
 		"TvmExecutionProvider"
 	}
 
+	fn check_options(&self) -> Result<()> {
+		self.options_string().map(drop)
+	}
+
 	#[allow(unused, unreachable_code)]
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
 		#[cfg(any(feature = "load-dynamic", feature = "tvm"))]
 		{
 			super::get_ep_register!(OrtSessionOptionsAppendExecutionProvider_Tvm(options: *mut ort_sys::OrtSessionOptions, opt_str: *const std::os::raw::c_char) -> ort_sys::OrtStatusPtr);
-			let mut option_string = Vec::new();
-			if let Some(check_hash) = self.check_hash {
-				option_string.push(format!("check_hash:{}", if check_hash { "True" } else { "False" }));
-			}
-			if let Some(executor) = self.executor {
-				option_string.push(format!(
-					"executor:{}",
-					match executor {
-						TVMExecutorType::GraphExecutor => "graph",
-						TVMExecutorType::VirtualMachine => "vm"
-					}
-				));
-			}
-			if let Some(freeze_weights) = self.freeze_weights {
-				option_string.push(format!("freeze_weights:{}", if freeze_weights { "True" } else { "False" }));
-			}
-			if let Some(hash_file_path) = self.hash_file_path.as_ref() {
-				option_string.push(format!("hash_file_path:{hash_file_path}"));
-			}
-			if let Some(input_names) = self.input_names.as_ref() {
-				option_string.push(format!("input_names:{input_names}"));
-			}
-			if let Some(input_shapes) = self.input_shapes.as_ref() {
-				option_string.push(format!("input_shapes:{input_shapes}"));
-			}
-			if let Some(opt_level) = self.opt_level {
-				option_string.push(format!("opt_level:{opt_level}"));
-			}
-			if let Some(so_folder) = self.so_folder.as_ref() {
-				option_string.push(format!("so_folder:{so_folder}"));
-			}
-			if let Some(target) = self.target.as_ref() {
-				option_string.push(format!("target:{target}"));
-			}
-			if let Some(target_host) = self.target_host.as_ref() {
-				option_string.push(format!("target_host:{target_host}"));
-			}
-			if let Some(to_nhwc) = self.to_nhwc {
-				option_string.push(format!("to_nhwc:{}", if to_nhwc { "True" } else { "False" }));
-			}
-			let options_string = std::ffi::CString::new(option_string.join(",")).unwrap();
+			let options_string = self.options_string()?;
 			return crate::error::status_to_result(unsafe {
 				OrtSessionOptionsAppendExecutionProvider_Tvm(session_builder.session_options_ptr, options_string.as_ptr())
 			})
@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::{collections::HashMap, num::NonZeroUsize};
 
 use super::ExecutionProvider;
 use crate::{Error, ExecutionProviderDispatch, Result, SessionBuilder};
@@ -17,6 +17,13 @@ impl XNNPACKExecutionProvider {
 	pub fn build(self) -> ExecutionProviderDispatch {
 		self.into()
 	}
+
+	/// Returns the provider option keys & values to pass to `SessionOptionsAppendExecutionProvider`.
+	fn options(&self) -> Result<super::ProviderOptions> {
+		Ok(super::map_keys! {
+			intra_op_num_threads = self.intra_op_num_threads.as_ref()
+		})
+	}
 }
 
 impl From<XNNPACKExecutionProvider> for ExecutionProviderDispatch {
@@ -27,16 +34,22 @@ impl From<XNNPACKExecutionProvider> for ExecutionProviderDispatch {
 
 impl ExecutionProvider for XNNPACKExecutionProvider {
 	fn as_str(&self) -> &'static str {
-		"XNNPACKExecutionProvider"
+		"XnnpackExecutionProvider"
+	}
+
+	fn check_options(&self) -> Result<()> {
+		self.options().map(drop)
+	}
+
+	fn effective_options(&self) -> HashMap<String, String> {
+		self.options().map(|(_, _, _, keys, values)| super::options_map(&keys, &values)).unwrap_or_default()
 	}
 
 	#[allow(unused, unreachable_code)]
 	fn register(&self, session_builder: &SessionBuilder) -> Result<()> {
 		#[cfg(any(feature = "load-dynamic", feature = "xnnpack"))]
 		{
-			let (key_ptrs, value_ptrs, len, _keys, _values) = super::map_keys! {
-				intra_op_num_threads = self.intra_op_num_threads.as_ref()
-			};
+			let (key_ptrs, value_ptrs, len, _keys, _values) = self.options()?;
 			let ep_name = std::ffi::CString::new("XNNPACK").unwrap();
 			return crate::error::status_to_result(crate::ortsys![unsafe SessionOptionsAppendExecutionProvider(
 				session_builder.session_options_ptr,
@@ -4,8 +4,9 @@ use crate::{
 	memory::MemoryInfo,
 	ortsys,
 	session::{output::SessionOutputs, RunOptions},
-	value::Value,
-	Error, Result, Session
+	tensor::{element_count, TensorElementType},
+	value::{Value, ValueInner},
+	AllocatorType, Error, MemoryType, Result, Session
 };
 
 /// Enables binding of session inputs and/or outputs to pre-allocated memory.
@@ -22,6 +23,7 @@ pub struct IoBinding<'s> {
 	pub(crate) ptr: *mut ort_sys::OrtIoBinding,
 	session: &'s Session,
 	input_values: Vec<Value>,
+	output_values: Vec<Value>,
 	output_names: Vec<String>
 }
 
@@ -33,6 +35,7 @@ impl<'s> IoBinding<'s> {
 			ptr,
 			session,
 			input_values: Vec::new(),
+			output_values: Vec::new(),
 			output_names: Vec::new()
 		})
 	}
@@ -64,6 +67,63 @@ impl<'s> IoBinding<'s> {
 		Ok(())
 	}
 
+	/// Bind a session output to a caller-provided CPU buffer, so that ONNX Runtime writes the output directly into it.
+	///
+	/// The buffer is interpreted as a tensor of type `element_type` with the given `shape`; it must be large enough to
+	/// hold the whole tensor, otherwise [`Error::OutputBufferTooSmall`] is returned, and aligned to the size of
+	/// `element_type`, otherwise [`Error::OutputBufferMisaligned`] is returned. String tensors are not supported.
+	pub fn bind_output_to_buffer<'b: 's, S: AsRef<str>>(
+		&mut self,
+		name: S,
+		buf: &'b mut [u8],
+		element_type: TensorElementType,
+		shape: impl AsRef<[i64]>
+	) -> Result<()> {
+		let shape = shape.as_ref();
+		let element_size = element_type.byte_size().ok_or(Error::UnsupportedBufferElementType(element_type))?;
+		let required = element_count(shape)?
+			.checked_mul(element_size)
+			.ok_or_else(|| Error::ShapeTooLarge(shape.to_vec()))?;
+		if buf.len() < required {
+			return Err(Error::OutputBufferTooSmall { required, actual: buf.len() });
+		}
+		// ONNX Runtime writes typed elements through the buffer
+		if buf.as_ptr() as usize % element_size != 0 {
+			return Err(Error::OutputBufferMisaligned { alignment: element_size });
+		}
+
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default)?;
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr,
+				buf.as_mut_ptr() as *mut std::ffi::c_void,
+				required as _,
+				shape.as_ptr(),
+				shape.len() as _,
+				element_type.into(),
+				&mut value_ptr
+			) -> Error::CreateTensorWithData;
+			nonNull(value_ptr)
+		];
+		let value = Value {
+			inner: ValueInner::RustOwned {
+				ptr: value_ptr,
+				_array: Box::new(()),
+				_memory_info: memory_info,
+				// the buffer belongs to the caller
+				exclusive: false
+			}
+		};
+
+		let name = name.as_ref();
+		let cname = CString::new(name)?;
+		ortsys![unsafe BindOutput(self.ptr, cname.as_ptr(), value.ptr()) -> Error::BindOutput];
+		self.output_values.push(value);
+		self.output_names.push(name.to_string());
+		Ok(())
+	}
+
 	pub fn run<'i: 's>(&'i self) -> Result<SessionOutputs<'s>> {
 		self.run_inner(None)
 	}
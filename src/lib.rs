@@ -18,6 +18,10 @@ pub(crate) mod memory;
 pub(crate) mod metadata;
 pub(crate) mod session;
 pub(crate) mod tensor;
+#[cfg(test)]
+pub(crate) mod test_util;
+#[cfg(feature = "training")]
+pub(crate) mod training;
 pub(crate) mod value;
 
 #[cfg(feature = "load-dynamic")]
@@ -26,27 +30,45 @@ use std::{
 	ffi::{self, CStr},
 	os::raw::c_char,
 	ptr,
-	sync::{atomic::AtomicPtr, Arc, Mutex, OnceLock}
+	sync::{atomic::{AtomicPtr, AtomicUsize, Ordering}, Arc, Mutex, OnceLock}
 };
 
 use tracing::Level;
 
 #[cfg(feature = "load-dynamic")]
 pub use self::environment::init_from;
-pub use self::environment::{init, EnvironmentBuilder, EnvironmentGlobalThreadPoolOptions};
+pub use self::environment::{init, Environment, EnvironmentBuilder, EnvironmentGlobalThreadPoolOptions};
 #[cfg(feature = "fetch-models")]
 #[cfg_attr(docsrs, doc(cfg(feature = "fetch-models")))]
 pub use self::error::FetchModelError;
 pub use self::error::{Error, ErrorInternal, Result};
 pub use self::execution_providers::*;
 pub use self::io_binding::IoBinding;
-pub use self::memory::{AllocationDevice, Allocator, MemoryInfo};
+pub use self::memory::{AllocationDevice, Allocator, ArenaConfig, CustomAllocator, MemoryDeviceType, MemoryInfo};
 pub use self::metadata::ModelMetadata;
-pub use self::session::{InMemorySession, RunOptions, Session, SessionBuilder, SessionInputs, SessionOutputs, SharedSessionInner};
+#[cfg(feature = "profiling")]
+#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+pub use self::session::{OpTiming, ProfilingGuard};
+pub use self::session::{
+	ExecutionProviderConfig, GraphStats, InMemorySession, IntoInputs, RunFuture, RunOptions, Session, SessionBuilder, SessionConfig, SessionConfigSummary,
+	SessionInputs, SessionOutputs, SharedSessionInner
+};
 #[cfg(feature = "ndarray")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
 pub use self::tensor::{ArrayExtensions, ArrayViewHolder, Tensor, TensorData};
-pub use self::tensor::{ExtractTensorData, IntoTensorElementType, TensorElementType};
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+pub use self::tensor::{Float8E4M3FN, Float8E4M3FNUZ, Float8E5M2, Float8E5M2FNUZ};
+pub use self::tensor::{ExtractTensorData, IntoTensorElementType, QuantizedElement, QuantizedTensor, TensorElementType};
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub use self::tensor::ImageLayout;
+#[cfg(feature = "test-utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub use self::tensor::TensorMismatch;
+#[cfg(feature = "training")]
+#[cfg_attr(docsrs, doc(cfg(feature = "training")))]
+pub use self::training::{Checkpoint, TrainingSession};
 pub use self::value::{Value, ValueRef, ValueType};
 
 #[cfg(not(all(target_arch = "x86", target_os = "windows")))]
@@ -68,8 +90,15 @@ pub(crate) use extern_system_fn;
 
 #[cfg(feature = "load-dynamic")]
 pub(crate) static G_ORT_DYLIB_PATH: OnceLock<Arc<String>> = OnceLock::new();
+/// The loaded ONNX Runtime dylib, or the path & error message if it failed to load.
+///
+/// The library is loaded at most once per process and is intentionally never unloaded, since ONNX Runtime may still
+/// have threads running (e.g. from a global thread pool) when statics would otherwise be dropped.
 #[cfg(feature = "load-dynamic")]
-pub(crate) static G_ORT_LIB: OnceLock<Arc<Mutex<libloading::Library>>> = OnceLock::new();
+pub(crate) static G_ORT_LIB: OnceLock<std::result::Result<Mutex<libloading::Library>, (String, String)>> = OnceLock::new();
+/// The number of times the ONNX Runtime dylib has been loaded; used to test that the library is only loaded once.
+#[cfg(all(test, feature = "load-dynamic"))]
+static G_ORT_LIB_LOADS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
 
 #[cfg(feature = "load-dynamic")]
 pub(crate) fn dylib_path() -> &'static String {
@@ -88,38 +117,161 @@ pub(crate) fn dylib_path() -> &'static String {
 }
 
 #[cfg(feature = "load-dynamic")]
-pub(crate) fn lib_handle() -> MutexGuard<'static, libloading::Library> {
+fn load_library() -> Result<&'static Mutex<libloading::Library>> {
 	G_ORT_LIB
 		.get_or_init(|| {
-			unsafe {
-				// resolve path relative to executable
-				let path: std::path::PathBuf = dylib_path().into();
-				let absolute_path = if path.is_absolute() {
-					path
-				} else {
-					let relative = std::env::current_exe()
-						.expect("could not get current executable path")
-						.parent()
-						.unwrap()
-						.join(&path);
-					if relative.exists() { relative } else { path }
-				};
-				let lib = libloading::Library::new(&absolute_path)
-					.unwrap_or_else(|e| panic!("An error occurred while attempting to load the ONNX Runtime binary at `{}`: {e}", absolute_path.display()));
-				Arc::new(Mutex::new(lib))
-			}
+			#[cfg(test)]
+			G_ORT_LIB_LOADS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+			// resolve path relative to executable
+			let path: std::path::PathBuf = dylib_path().into();
+			let absolute_path = if path.is_absolute() {
+				path
+			} else {
+				let relative = std::env::current_exe()
+					.ok()
+					.and_then(|exe| exe.parent().map(|dir| dir.join(&path)))
+					.filter(|relative| relative.exists());
+				relative.unwrap_or(path)
+			};
+			unsafe { libloading::Library::new(&absolute_path) }
+				.map(Mutex::new)
+				.map_err(|e| (absolute_path.display().to_string(), e.to_string()))
 		})
+		.as_ref()
+		.map_err(|(path, error)| Error::LoadLibrary {
+			path: path.clone(),
+			error: error.clone()
+		})
+}
+
+#[cfg(feature = "load-dynamic")]
+pub(crate) fn lib_handle() -> MutexGuard<'static, libloading::Library> {
+	load_library()
+		.unwrap_or_else(|e| panic!("{e}"))
 		.lock()
 		.expect("failed to acquire ONNX Runtime dylib lock; another thread panicked?")
 }
 
+/// Eagerly loads and initializes ONNX Runtime, so that the cost isn't paid when the first session or environment is
+/// created.
+///
+/// With the `load-dynamic` feature, this loads the ONNX Runtime dylib (from `ORT_DYLIB_PATH` or the path passed to
+/// `ort::init_from`) and checks that its version is compatible, returning an error instead of panicking if either step
+/// fails. The library is only ever loaded once per process, and stays loaded until the process exits. Without
+/// `load-dynamic`, this simply initializes the [`api`].
+pub fn preload_library() -> Result<()> {
+	#[cfg(feature = "load-dynamic")]
+	{
+		load_library()?;
+		check_dylib_version()?;
+	}
+	api();
+	Ok(())
+}
+
+/// The oldest ONNX Runtime version, as `(major, minor)`, that this version of `ort` is compatible with.
+pub const MIN_ORT_VERSION: (u32, u32) = (1, 17);
+/// The newest ONNX Runtime version, as `(major, minor)`, that this version of `ort` has been tested with. ONNX Runtime's
+/// C API is forward compatible, so newer versions are still allowed, but log a warning.
+pub const MAX_ORT_VERSION: (u32, u32) = (1, 17);
+
+/// Returns the version string reported by the `OrtApiBase` exported by the loaded ONNX Runtime dylib.
+#[cfg(feature = "load-dynamic")]
+unsafe fn api_base_version_string(base: *const ort_sys::OrtApiBase) -> String {
+	let get_version_string: extern_system_fn! { unsafe fn () -> *const ffi::c_char } =
+		(*base).GetVersionString.expect("`GetVersionString` must be present in `OrtApiBase`");
+	CStr::from_ptr(get_version_string()).to_string_lossy().into_owned()
+}
+
+/// Loads the ONNX Runtime dylib (if it hasn't been loaded already) and checks that its version is at least
+/// [`MIN_ORT_VERSION`].
+#[cfg(feature = "load-dynamic")]
+pub(crate) fn check_dylib_version() -> Result<()> {
+	let version_string = unsafe {
+		let dylib = lib_handle();
+		let base_getter: libloading::Symbol<unsafe extern "C" fn() -> *const ort_sys::OrtApiBase> =
+			dylib.get(b"OrtGetApiBase").map_err(|_| Error::IncompatibleVersion {
+				path: dylib_path().to_string(),
+				version: "unknown (`OrtGetApiBase` is missing)".to_string()
+			})?;
+		let base: *const ort_sys::OrtApiBase = base_getter();
+		assert_ne!(base, ptr::null());
+		api_base_version_string(base)
+	};
+	check_version(&version_string, version_check_skipped())
+}
+
+/// Checks that `version_string` (as returned by ONNX Runtime's `GetVersionString`) is at least [`MIN_ORT_VERSION`],
+/// unless `skip` is set (which users do by setting the `ORT_SKIP_VERSION_CHECK` environment variable to `1`; see
+/// [`version_check_skipped`]). Versions newer than [`MAX_ORT_VERSION`] are allowed, but log a warning.
+#[cfg(feature = "load-dynamic")]
+fn check_version(version_string: &str, skip: bool) -> Result<()> {
+	if skip {
+		tracing::warn!("Skipping compatibility check for ONNX Runtime version '{version_string}' since `ORT_SKIP_VERSION_CHECK` is set");
+		return Ok(());
+	}
+	if !is_compatible_version(version_string) {
+		return Err(Error::IncompatibleVersion {
+			path: dylib_path().to_string(),
+			version: version_string.to_string()
+		});
+	}
+	if parse_version(version_string).is_some_and(|version| version > MAX_ORT_VERSION) {
+		tracing::warn!(
+			"ONNX Runtime version '{version_string}' is newer than the latest version this version of ort was tested with ({}.{}); it should be compatible, \
+			 but you may encounter issues",
+			MAX_ORT_VERSION.0,
+			MAX_ORT_VERSION.1
+		);
+	}
+	Ok(())
+}
+
+#[cfg(feature = "load-dynamic")]
+fn version_check_skipped() -> bool {
+	matches!(std::env::var("ORT_SKIP_VERSION_CHECK").as_deref(), Ok("1"))
+}
+
+#[cfg(feature = "load-dynamic")]
+fn is_compatible_version(version_string: &str) -> bool {
+	match parse_version(version_string) {
+		Some(version) => version >= MIN_ORT_VERSION,
+		None => false
+	}
+}
+
+/// Parses the `(major, minor)` version from an ONNX Runtime version string like `1.17.1`.
+#[cfg(feature = "load-dynamic")]
+fn parse_version(version_string: &str) -> Option<(u32, u32)> {
+	let mut parts = version_string.split('.').map(|x| x.parse::<u32>());
+	match (parts.next(), parts.next()) {
+		(Some(Ok(major)), Some(Ok(minor))) => Some((major, minor)),
+		_ => None
+	}
+}
+
+/// Returns the `(major, minor)` version of the loaded ONNX Runtime dylib, for features which depend on a newer version
+/// than the one `ort` targets.
+#[cfg(feature = "load-dynamic")]
+pub(crate) fn runtime_version() -> Option<(u32, u32)> {
+	let version_string = unsafe {
+		let dylib = lib_handle();
+		let base_getter: libloading::Symbol<unsafe extern "C" fn() -> *const ort_sys::OrtApiBase> = dylib.get(b"OrtGetApiBase").ok()?;
+		api_base_version_string(base_getter())
+	};
+	parse_version(&version_string)
+}
+
 pub(crate) static G_ORT_API: OnceLock<Arc<Mutex<AtomicPtr<ort_sys::OrtApi>>>> = OnceLock::new();
 
 /// Attempts to acquire the global [`ort_sys::OrtApi`] object.
 ///
 /// # Panics
 ///
-/// Panics if another thread panicked while holding the API lock, or if the ONNX Runtime API could not be initialized.
+/// Panics if another thread panicked while holding the API lock, or if the ONNX Runtime API could not be initialized
+/// (e.g. with `load-dynamic`, if the loaded binary is older than [`MIN_ORT_VERSION`]). Use [`preload_library`] or
+/// `ort::init().commit()` to handle an incompatible version as an error instead.
 pub fn api() -> ort_sys::OrtApi {
 	let mut api_ref = G_ORT_API
 		.get_or_init(|| {
@@ -132,24 +284,13 @@ pub fn api() -> ort_sys::OrtApi {
 				let base: *const ort_sys::OrtApiBase = base_getter();
 				assert_ne!(base, ptr::null());
 
-				let get_version_string: extern_system_fn! { unsafe fn () -> *const ffi::c_char } =
-					(*base).GetVersionString.expect("`GetVersionString` must be present in `OrtApiBase`");
-				let version_string = get_version_string();
-				let version_string = CStr::from_ptr(version_string).to_string_lossy();
+				let version_string = api_base_version_string(base);
 				tracing::info!("Using ONNX Runtime version '{version_string}'");
+				// `ort::init().commit()` & `preload_library` report incompatible versions as errors; here, we can only panic
+				if let Err(e) = check_version(&version_string, version_check_skipped()) {
+					panic!("{e}");
+				}
 
-				let lib_minor_version = version_string.split('.').nth(1).map(|x| x.parse::<u32>().unwrap_or(0)).unwrap_or(0);
-				match lib_minor_version.cmp(&17) {
-					std::cmp::Ordering::Less => panic!(
-						"ort 2.0 is not compatible with the ONNX Runtime binary found at `{}`; expected GetVersionString to return '1.17.x', but got '{version_string}'",
-						dylib_path()
-					),
-					std::cmp::Ordering::Greater => tracing::warn!(
-						"ort 2.0 may have compatibility issues with the ONNX Runtime binary found at `{}`; expected GetVersionString to return '1.17.x', but got '{version_string}'",
-						dylib_path()
-					),
-					std::cmp::Ordering::Equal => {}
-				};
 				let get_api: extern_system_fn! { unsafe fn(u32) -> *const ort_sys::OrtApi } = (*base).GetApi.expect("`GetApi` must be present in `OrtApiBase`");
 				let api: *const ort_sys::OrtApi = get_api(ort_sys::ORT_API_VERSION);
 				Arc::new(Mutex::new(AtomicPtr::new(api as *mut ort_sys::OrtApi)))
@@ -174,6 +315,29 @@ pub fn api() -> ort_sys::OrtApi {
 	unsafe { *api_ptr_mut }
 }
 
+/// Returns ONNX Runtime's build information, including the git commit it was built from, the build type, and the
+/// compiler flags used. This is useful to include in bug reports.
+///
+/// Returns [`Error::Unsupported`] if the loaded ONNX Runtime binary doesn't provide build information.
+///
+/// ```
+/// # fn main() -> ort::Result<()> {
+/// let build_info = ort::onnxruntime_build_info()?;
+/// println!("{build_info}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn onnxruntime_build_info() -> Result<String> {
+	let get_build_info_string = api()
+		.GetBuildInfoString
+		.ok_or_else(|| Error::Unsupported("build information requires `GetBuildInfoString`, which this ONNX Runtime binary does not provide".to_string()))?;
+	let build_info = unsafe { get_build_info_string() };
+	if build_info.is_null() {
+		return Err(Error::Unsupported("ONNX Runtime did not report any build information".to_string()));
+	}
+	char_p_to_string(build_info)
+}
+
 macro_rules! ortsys {
 	($method:ident) => {
 		$crate::api().$method.unwrap()
@@ -254,21 +418,71 @@ impl<'a> From<&'a str> for CodeLocation<'a> {
 	}
 }
 
+/// Log IDs whose messages are being collected by a [`LogCapture`], along with the messages collected so far.
+static LOG_CAPTURES: Mutex<Vec<(String, Vec<String>)>> = Mutex::new(Vec::new());
+/// The number of live [`LogCapture`]s, so the logger can skip locking [`LOG_CAPTURES`] when there are none.
+static LOG_CAPTURE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Collects the messages ONNX Runtime logs under a log ID (i.e. a session's log ID), in addition to forwarding them to
+/// `tracing`, until it is dropped.
+pub(crate) struct LogCapture {
+	log_id: String
+}
+
+impl LogCapture {
+	pub(crate) fn new(log_id: String) -> Self {
+		LOG_CAPTURES.lock().unwrap_or_else(|e| e.into_inner()).push((log_id.clone(), Vec::new()));
+		LOG_CAPTURE_COUNT.fetch_add(1, Ordering::Release);
+		Self { log_id }
+	}
+
+	/// Returns the messages collected so far.
+	pub(crate) fn messages(&self) -> Vec<String> {
+		let captures = LOG_CAPTURES.lock().unwrap_or_else(|e| e.into_inner());
+		captures.iter().find(|(id, _)| *id == self.log_id).map(|(_, messages)| messages.clone()).unwrap_or_default()
+	}
+}
+
+impl Drop for LogCapture {
+	fn drop(&mut self) {
+		LOG_CAPTURE_COUNT.fetch_sub(1, Ordering::Release);
+		LOG_CAPTURES.lock().unwrap_or_else(|e| e.into_inner()).retain(|(id, _)| *id != self.log_id);
+	}
+}
+
 extern_system_fn! {
 	/// Callback from C that will handle ONNX logging, forwarding ONNX's logs to the `tracing` crate.
-	pub(crate) fn custom_logger(_params: *mut ffi::c_void, severity: ort_sys::OrtLoggingLevel, category: *const c_char, _: *const c_char, code_location: *const c_char, message: *const c_char) {
+	///
+	/// `params` is either null or points to the environment's [`LogCategoryFilter`](environment::LogCategoryFilter).
+	pub(crate) fn custom_logger(params: *mut ffi::c_void, severity: ort_sys::OrtLoggingLevel, category: *const c_char, log_id: *const c_char, code_location: *const c_char, message: *const c_char) {
 		assert_ne!(category, ptr::null());
 		let category = unsafe { CStr::from_ptr(category) };
+		if !params.is_null() {
+			let filter = unsafe { &*(params as *const environment::LogCategoryFilter) };
+			if !filter.allows(category.to_str().unwrap_or("<unknown>")) {
+				return;
+			}
+		}
+		// the log ID is the environment's name (see `EnvironmentBuilder::with_name`), or the session's log ID
+		let log_id = if log_id.is_null() { None } else { unsafe { CStr::from_ptr(log_id) }.to_str().ok() };
 		assert_ne!(code_location, ptr::null());
 		let code_location_str = unsafe { CStr::from_ptr(code_location) }.to_str().unwrap();
 		assert_ne!(message, ptr::null());
 		let message = unsafe { CStr::from_ptr(message) }.to_str().unwrap();
 
+		if let Some(log_id) = log_id.filter(|_| LOG_CAPTURE_COUNT.load(Ordering::Acquire) > 0) {
+			let mut captures = LOG_CAPTURES.lock().unwrap_or_else(|e| e.into_inner());
+			if let Some((_, messages)) = captures.iter_mut().find(|(id, _)| id == log_id) {
+				messages.push(message.to_owned());
+			}
+		}
+
 		let code_location = CodeLocation::from(code_location_str);
 		let span = tracing::span!(
 			Level::TRACE,
 			"ort",
 			category = category.to_str().unwrap_or("<unknown>"),
+			id = log_id.unwrap_or("<unknown>"),
 			file = code_location.file,
 			line = code_location.line,
 			function = code_location.function
@@ -313,7 +527,8 @@ extern_system_fn! {
 /// - When layout optimizations are enabled, the offline mode can only be used on compatible hardware to the environment
 ///   when the offline model is saved. For example, if model has layout optimized for AVX2, the offline model would
 ///   require CPUs that support AVX2.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GraphOptimizationLevel {
 	/// Disables all graph optimizations.
 	Disable,
@@ -375,6 +590,75 @@ impl From<GraphOptimizationLevel> for ort_sys::GraphOptimizationLevel {
 	}
 }
 
+/// Controls how the nodes of a graph are executed. See [`SessionBuilder::with_execution_mode`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExecutionMode {
+	/// (Default) Nodes are executed one at a time, in topological order.
+	#[default]
+	Sequential,
+	/// Independent branches of the graph are executed in parallel using the inter-op thread pool. This only helps
+	/// models with parallel branches, and only when the number of inter-op threads (see
+	/// [`SessionBuilder::with_inter_threads`]) is greater than 1.
+	Parallel
+}
+
+impl From<ExecutionMode> for ort_sys::ExecutionMode {
+	fn from(val: ExecutionMode) -> Self {
+		match val {
+			ExecutionMode::Sequential => ort_sys::ExecutionMode::ORT_SEQUENTIAL,
+			ExecutionMode::Parallel => ort_sys::ExecutionMode::ORT_PARALLEL
+		}
+	}
+}
+
+/// The serialization format of a model. See [`SessionBuilder::with_model_format`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ModelFileFormat {
+	/// The standard ONNX protobuf format, usually with a `.onnx` extension.
+	Onnx,
+	/// ONNX Runtime's compact FlatBuffers-based format, usually with a `.ort` extension. Models can be converted to this
+	/// format with `python -m onnxruntime.tools.convert_onnx_models_to_ort`.
+	Ort
+}
+
+impl ModelFileFormat {
+	pub(crate) fn as_config_value(&self) -> &'static str {
+		match self {
+			ModelFileFormat::Onnx => "ONNX",
+			ModelFileFormat::Ort => "ORT"
+		}
+	}
+}
+
+/// The severity of a log message emitted by ONNX Runtime. See [`SessionBuilder::with_log_severity_level`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogSeverity {
+	/// Verbose messages, including `VLOG` output (if enabled via [`SessionBuilder::with_log_verbosity_level`]).
+	Verbose,
+	/// Informational messages.
+	Info,
+	/// (Default) Warnings.
+	#[default]
+	Warning,
+	/// Errors.
+	Error,
+	/// Fatal errors, after which ONNX Runtime cannot continue.
+	Fatal
+}
+
+impl From<LogSeverity> for ort_sys::OrtLoggingLevel {
+	fn from(val: LogSeverity) -> Self {
+		match val {
+			LogSeverity::Verbose => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_VERBOSE,
+			LogSeverity::Info => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_INFO,
+			LogSeverity::Warning => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_WARNING,
+			LogSeverity::Error => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_ERROR,
+			LogSeverity::Fatal => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_FATAL
+		}
+	}
+}
+
 /// Execution provider allocator type.
 #[derive(Debug, Copy, Clone)]
 pub enum AllocatorType {
@@ -430,4 +714,105 @@ mod test {
 		let ptr = s.as_c_str().as_ptr();
 		assert_eq!("foo", char_p_to_string(ptr).unwrap());
 	}
+
+	#[test]
+	#[cfg(feature = "load-dynamic")]
+	fn test_version_check() {
+		assert!(is_compatible_version("1.17.0"));
+		assert!(is_compatible_version("1.17.3"));
+		assert!(!is_compatible_version("1.16.3"));
+		assert!(!is_compatible_version("0.17.0"));
+		// the C API is forward compatible, so newer versions are only warned about
+		assert!(is_compatible_version("1.99.0"));
+		assert!(is_compatible_version("2.17.0"));
+		assert!(!is_compatible_version("garbage"));
+
+		assert!(check_version("1.17.1", false).is_ok());
+		assert!(matches!(check_version("1.16.3", false), Err(Error::IncompatibleVersion { version, .. }) if version == "1.16.3"));
+		assert!(check_version("1.99.0", false).is_ok());
+		assert!(matches!(check_version("garbage", false), Err(Error::IncompatibleVersion { .. })));
+
+		// `ORT_SKIP_VERSION_CHECK=1` bypasses the check
+		assert!(check_version("1.16.3", true).is_ok());
+		assert!(check_version("1.99.0", true).is_ok());
+	}
+
+	#[test]
+	#[cfg(feature = "load-dynamic")]
+	fn test_library_loaded_once() -> Result<()> {
+		preload_library()?;
+		let model = include_bytes!("../tests/data/branches.onnx");
+		drop(session::Session::builder()?.with_model_from_memory(model)?);
+		drop(session::Session::builder()?.with_model_from_memory(model)?);
+		assert_eq!(G_ORT_LIB_LOADS.load(std::sync::atomic::Ordering::SeqCst), 1);
+		Ok(())
+	}
+
+	#[test]
+	fn test_build_info() -> Result<()> {
+		let build_info = onnxruntime_build_info()?;
+		assert!(!build_info.is_empty());
+		Ok(())
+	}
+
+	/// Calls [`custom_logger`] with the given logger parameter & category, returning what was logged to `tracing`.
+	fn capture_log(params: *mut ffi::c_void, category: &str, log_id: &str, message: &str) -> String {
+		let category = ffi::CString::new(category).unwrap();
+		let log_id = ffi::CString::new(log_id).unwrap();
+		let code_location = ffi::CString::new("environment.cc:42 Create").unwrap();
+		let message = ffi::CString::new(message).unwrap();
+		let ((), output) = test_util::capture_logs(Level::TRACE, || {
+			custom_logger(
+				params,
+				ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_WARNING,
+				category.as_ptr(),
+				log_id.as_ptr(),
+				code_location.as_ptr(),
+				message.as_ptr()
+			);
+		});
+		output
+	}
+
+	#[test]
+	fn test_logger_log_id() {
+		let output = capture_log(ptr::null_mut(), "onnxruntime", "my_component", "hello");
+		assert!(output.contains("id=\"my_component\""), "log ID missing from output: {output}");
+		assert!(output.contains("category=\"onnxruntime\""), "category missing from output: {output}");
+		assert!(output.contains("hello"));
+	}
+
+	#[test]
+	fn test_logger_category_filter() {
+		let seen = Arc::new(Mutex::new(Vec::new()));
+		let filter = environment::LogCategoryFilter(Box::new({
+			let seen = Arc::clone(&seen);
+			move |category: &str| {
+				seen.lock().unwrap().push(category.to_string());
+				!category.starts_with("VLOG")
+			}
+		}));
+		let params = &filter as *const environment::LogCategoryFilter as *mut ffi::c_void;
+
+		let output = capture_log(params, "onnxruntime", "my_component", "kept");
+		assert!(output.contains("kept"), "allowed message missing from output: {output}");
+		let output = capture_log(params, "VLOG1", "my_component", "suppressed");
+		assert!(output.is_empty(), "filtered message was logged: {output}");
+
+		assert_eq!(*seen.lock().unwrap(), ["onnxruntime", "VLOG1"]);
+	}
+
+	#[test]
+	fn test_log_capture() {
+		let capture = LogCapture::new("captured_session".to_string());
+		capture_log(ptr::null_mut(), "onnxruntime", "captured_session", "first");
+		capture_log(ptr::null_mut(), "onnxruntime", "other_session", "not captured");
+		// captured messages are still forwarded to `tracing`
+		let output = capture_log(ptr::null_mut(), "onnxruntime", "captured_session", "second");
+		assert!(output.contains("second"));
+		assert_eq!(capture.messages(), ["first", "second"]);
+
+		drop(capture);
+		assert!(LOG_CAPTURES.lock().unwrap().iter().all(|(id, _)| id != "captured_session"));
+	}
 }
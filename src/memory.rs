@@ -1,8 +1,11 @@
-use std::ffi::{c_char, c_int, CString};
+use std::{
+	ffi::{c_char, c_int, c_void, CString},
+	panic::{self, AssertUnwindSafe}
+};
 
 use super::{
 	error::{Error, Result},
-	ortsys, AllocatorType, MemoryType
+	extern_system_fn, ortsys, AllocatorType, ArenaExtendStrategy, MemoryType
 };
 use crate::{char_p_to_string, error::status_to_result};
 
@@ -85,6 +88,25 @@ impl TryFrom<&str> for AllocationDevice {
 	}
 }
 
+/// The kind of device memory described by a [`MemoryInfo`] resides on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MemoryDeviceType {
+	/// Host memory, which includes pinned memory allocated by accelerators like CUDA.
+	CPU,
+	GPU,
+	FPGA
+}
+
+impl From<ort_sys::OrtMemoryInfoDeviceType> for MemoryDeviceType {
+	fn from(value: ort_sys::OrtMemoryInfoDeviceType) -> Self {
+		match value {
+			ort_sys::OrtMemoryInfoDeviceType::OrtMemoryInfoDeviceType_CPU => MemoryDeviceType::CPU,
+			ort_sys::OrtMemoryInfoDeviceType::OrtMemoryInfoDeviceType_GPU => MemoryDeviceType::GPU,
+			ort_sys::OrtMemoryInfoDeviceType::OrtMemoryInfoDeviceType_FPGA => MemoryDeviceType::FPGA
+		}
+	}
+}
+
 #[derive(Debug)]
 pub struct MemoryInfo {
 	pub(crate) ptr: *mut ort_sys::OrtMemoryInfo,
@@ -92,6 +114,10 @@ pub struct MemoryInfo {
 	should_release: bool
 }
 
+// `OrtMemoryInfo` is never mutated after creation.
+unsafe impl Send for MemoryInfo {}
+unsafe impl Sync for MemoryInfo {}
+
 impl MemoryInfo {
 	#[tracing::instrument]
 	pub fn new_cpu(allocator: AllocatorType, memory_type: MemoryType) -> Result<Self> {
@@ -107,6 +133,19 @@ impl MemoryInfo {
 		})
 	}
 
+	/// Creates a [`MemoryInfo`] describing CPU memory which resides on the NUMA node `numa_node`, for use with
+	/// [`Value::from_slice_with_memory_info`](crate::Value::from_slice_with_memory_info).
+	///
+	/// **NOTE**: ONNX Runtime has no notion of NUMA nodes; the node is only recorded as the memory info's
+	/// [device ID](MemoryInfo::device_id), and neither ONNX Runtime nor `ort` will allocate or move memory to it. The
+	/// data itself must already reside on the node, e.g. by allocating it with `libnuma`, or by first writing to it
+	/// from a thread pinned to that node. Since the CPU execution provider's own memory is described with a device ID
+	/// of `0`, ONNX Runtime may copy inputs described with any other node before using them.
+	#[tracing::instrument]
+	pub fn new_cpu_on_numa_node(numa_node: c_int, allocator: AllocatorType, memory_type: MemoryType) -> Result<Self> {
+		Self::new(AllocationDevice::CPU, numa_node, allocator, memory_type)
+	}
+
 	#[tracing::instrument]
 	pub fn new(allocation_device: AllocationDevice, device_id: c_int, allocator_type: AllocatorType, memory_type: MemoryType) -> Result<Self> {
 		let mut memory_info_ptr: *mut ort_sys::OrtMemoryInfo = std::ptr::null_mut();
@@ -123,11 +162,49 @@ impl MemoryInfo {
 		})
 	}
 
+	/// Creates a new, independently owned [`MemoryInfo`] describing the same device & allocator as this one.
+	pub(crate) fn try_clone(&self) -> Result<Self> {
+		let mut name_ptr: *const c_char = std::ptr::null_mut();
+		ortsys![unsafe MemoryInfoGetName(self.ptr, &mut name_ptr) -> Error::CreateMemoryInfo; nonNull(name_ptr)];
+		let mut device_id: c_int = 0;
+		ortsys![unsafe MemoryInfoGetId(self.ptr, &mut device_id) -> Error::CreateMemoryInfo];
+		let mut allocator_type = ort_sys::OrtAllocatorType::OrtDeviceAllocator;
+		ortsys![unsafe MemoryInfoGetType(self.ptr, &mut allocator_type) -> Error::CreateMemoryInfo];
+		let mut memory_type = ort_sys::OrtMemType::OrtMemTypeDefault;
+		ortsys![unsafe MemoryInfoGetMemType(self.ptr, &mut memory_type) -> Error::CreateMemoryInfo];
+
+		let mut memory_info_ptr: *mut ort_sys::OrtMemoryInfo = std::ptr::null_mut();
+		ortsys![
+			unsafe CreateMemoryInfo(name_ptr, allocator_type, device_id, memory_type, &mut memory_info_ptr) -> Error::CreateMemoryInfo;
+			nonNull(memory_info_ptr)
+		];
+		Ok(Self {
+			ptr: memory_info_ptr,
+			memory_type: self.memory_type,
+			should_release: true
+		})
+	}
+
 	/// Returns the [`MemoryType`] this struct was created with.
 	pub fn memory_type(&self) -> MemoryType {
 		self.memory_type
 	}
 
+	/// Returns the ID of the device this struct was created with; for CPU memory, this is usually `0`, or the NUMA node
+	/// given to [`MemoryInfo::new_cpu_on_numa_node`].
+	pub fn device_id(&self) -> Result<i32> {
+		let mut device_id: c_int = 0;
+		ortsys![unsafe MemoryInfoGetId(self.ptr, &mut device_id) -> Error::GetAllocationDevice];
+		Ok(device_id)
+	}
+
+	/// Returns the kind of device the memory described by this struct resides on.
+	pub fn device_type(&self) -> MemoryDeviceType {
+		let mut device_type = ort_sys::OrtMemoryInfoDeviceType::OrtMemoryInfoDeviceType_CPU;
+		ortsys![unsafe MemoryInfoGetDeviceType(self.ptr, &mut device_type)];
+		device_type.into()
+	}
+
 	/// Returns the [`AllocationDevice`] this struct was created with.
 	pub fn allocation_device(&self) -> Result<AllocationDevice> {
 		let mut name_ptr: *const c_char = std::ptr::null_mut();
@@ -150,6 +227,167 @@ impl Drop for MemoryInfo {
 	}
 }
 
+/// Configuration for a memory arena allocator, used with
+/// [`EnvironmentBuilder::with_shared_allocator`](crate::EnvironmentBuilder::with_shared_allocator). Options left as
+/// `None` use ONNX Runtime's defaults.
+#[derive(Debug, Default, Clone)]
+pub struct ArenaConfig {
+	/// The maximum amount of memory the arena may allocate, in bytes.
+	pub max_mem: Option<usize>,
+	/// The strategy used to grow the arena.
+	pub arena_extend_strategy: Option<ArenaExtendStrategy>,
+	/// The size of the first allocation in the arena, in bytes.
+	pub initial_chunk_size_bytes: Option<usize>,
+	/// The maximum amount of unused memory, in bytes, allowed in an allocated chunk before it is split.
+	pub max_dead_bytes_per_chunk: Option<usize>,
+	/// The size of the second allocation in the arena, in bytes; subsequent allocations grow according to
+	/// `arena_extend_strategy`.
+	pub initial_growth_chunk_size_bytes: Option<usize>
+}
+
+impl ArenaConfig {
+	/// The size of a huge page on x86-64 & most AArch64 Linux systems (2 MiB).
+	pub const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+
+	/// Rounds the arena's initial chunk sizes up to multiples of `huge_page_size` (typically
+	/// [`ArenaConfig::HUGE_PAGE_SIZE`]), so that each chunk can be fully backed by huge pages, which can improve TLB
+	/// behavior for large models. Chunk sizes left as `None` are rounded up from ONNX Runtime's defaults (1 MiB for the
+	/// initial chunk & 2 MiB for the first growth chunk).
+	///
+	/// ONNX Runtime has no way to request huge pages for its arenas - chunks are allocated with a plain aligned
+	/// allocation - so this is only a hint to the kernel's transparent huge page support. It only has an effect on
+	/// Linux, with transparent huge pages enabled in `always` mode (`/sys/kernel/mm/transparent_hugepage/enabled`); in
+	/// `madvise` mode, nothing ever requests them for ONNX Runtime's memory. Later chunks stay huge-page multiples with
+	/// [`ArenaExtendStrategy::NextPowerOfTwo`], but not with [`ArenaExtendStrategy::SameAsRequested`].
+	///
+	/// ```
+	/// # use ort::ArenaConfig;
+	/// let config = ArenaConfig {
+	/// 	initial_chunk_size_bytes: Some(3 * 1024 * 1024),
+	/// 	..ArenaConfig::default()
+	/// }
+	/// .with_huge_page_chunks(ArenaConfig::HUGE_PAGE_SIZE);
+	/// assert_eq!(config.initial_chunk_size_bytes, Some(4 * 1024 * 1024));
+	/// assert_eq!(config.initial_growth_chunk_size_bytes, Some(2 * 1024 * 1024));
+	/// ```
+	pub fn with_huge_page_chunks(mut self, huge_page_size: usize) -> Self {
+		let huge_page_size = huge_page_size.max(1);
+		let round_up = |size: usize| size.saturating_add(huge_page_size - 1) / huge_page_size * huge_page_size;
+		self.initial_chunk_size_bytes = Some(round_up(self.initial_chunk_size_bytes.unwrap_or(1024 * 1024)));
+		self.initial_growth_chunk_size_bytes = Some(round_up(self.initial_growth_chunk_size_bytes.unwrap_or(2 * 1024 * 1024)));
+		self
+	}
+
+	/// Creates an [`ort_sys::OrtArenaCfg`] from this configuration. The caller is responsible for releasing it.
+	pub(crate) fn create_raw(&self) -> Result<*mut ort_sys::OrtArenaCfg> {
+		let entries = [
+			("max_mem", self.max_mem),
+			(
+				"arena_extend_strategy",
+				self.arena_extend_strategy.as_ref().map(|strategy| match strategy {
+					ArenaExtendStrategy::NextPowerOfTwo => 0,
+					ArenaExtendStrategy::SameAsRequested => 1
+				})
+			),
+			("initial_chunk_size_bytes", self.initial_chunk_size_bytes),
+			("max_dead_bytes_per_chunk", self.max_dead_bytes_per_chunk),
+			("initial_growth_chunk_size_bytes", self.initial_growth_chunk_size_bytes)
+		];
+		let (keys, values): (Vec<CString>, Vec<ort_sys::size_t>) = entries
+			.into_iter()
+			.filter_map(|(key, value)| value.map(|v| (CString::new(key).unwrap(), v as ort_sys::size_t)))
+			.unzip();
+		let key_ptrs: Vec<*const c_char> = keys.iter().map(|k| k.as_ptr()).collect();
+
+		let mut arena_cfg_ptr: *mut ort_sys::OrtArenaCfg = std::ptr::null_mut();
+		ortsys![
+			unsafe CreateArenaCfgV2(key_ptrs.as_ptr(), values.as_ptr(), key_ptrs.len() as _, &mut arena_cfg_ptr) -> Error::RegisterSharedAllocator;
+			nonNull(arena_cfg_ptr)
+		];
+		Ok(arena_cfg_ptr)
+	}
+}
+
+/// A custom allocator implemented in Rust that ONNX Runtime can allocate memory through, registered with
+/// [`EnvironmentBuilder::with_custom_allocator`](crate::EnvironmentBuilder::with_custom_allocator).
+///
+/// Allocators may be called from many threads at once, so they must be thread-safe.
+pub trait CustomAllocator: Send + Sync {
+	/// Allocates a block of at least `size` bytes, returning a null pointer if the allocation failed.
+	///
+	/// CPU kernels in ONNX Runtime assume allocations are aligned to at least 64 bytes.
+	fn alloc(&self, size: usize) -> *mut c_void;
+
+	/// Frees a block of memory previously returned by [`CustomAllocator::alloc`]. ONNX Runtime does not pass the size of
+	/// the block, so the allocator must keep track of it itself if it needs it.
+	fn free(&self, ptr: *mut c_void);
+
+	/// Describes the memory this allocator allocates. Called once when the allocator is registered.
+	///
+	/// ONNX Runtime only accepts allocators with [`AllocatorType::Device`], even if the allocator is internally an
+	/// arena. Defaults to CPU memory.
+	fn info(&self) -> Result<MemoryInfo> {
+		MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default)
+	}
+}
+
+/// An [`ort_sys::OrtAllocator`] vtable which forwards to a [`CustomAllocator`]. ONNX Runtime passes a pointer to the
+/// vtable to each callback, which is also a pointer to the shim since the vtable is its first field.
+#[repr(C)]
+pub(crate) struct CustomAllocatorShim {
+	vtable: ort_sys::OrtAllocator,
+	pub(crate) memory_info: MemoryInfo,
+	allocator: Box<dyn CustomAllocator>
+}
+
+impl CustomAllocatorShim {
+	pub(crate) fn new(allocator: Box<dyn CustomAllocator>) -> Result<Box<Self>> {
+		let memory_info = allocator.info()?;
+		Ok(Box::new(Self {
+			vtable: ort_sys::OrtAllocator {
+				version: ort_sys::ORT_API_VERSION,
+				Alloc: Some(custom_allocator_alloc),
+				Free: Some(custom_allocator_free),
+				Info: Some(custom_allocator_info)
+			},
+			memory_info,
+			allocator
+		}))
+	}
+
+	pub(crate) fn as_ptr(&mut self) -> *mut ort_sys::OrtAllocator {
+		&mut self.vtable
+	}
+}
+
+impl std::fmt::Debug for CustomAllocatorShim {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("CustomAllocatorShim").field("memory_info", &self.memory_info).finish_non_exhaustive()
+	}
+}
+
+// panics must not unwind into ONNX Runtime, so a panicking allocation is treated as a failed one
+extern_system_fn! {
+	unsafe fn custom_allocator_alloc(this: *mut ort_sys::OrtAllocator, size: ort_sys::size_t) -> *mut c_void {
+		let shim = &*(this as *const CustomAllocatorShim);
+		panic::catch_unwind(AssertUnwindSafe(|| shim.allocator.alloc(size as _))).unwrap_or(std::ptr::null_mut())
+	}
+}
+
+extern_system_fn! {
+	unsafe fn custom_allocator_free(this: *mut ort_sys::OrtAllocator, ptr: *mut c_void) {
+		let shim = &*(this as *const CustomAllocatorShim);
+		let _ = panic::catch_unwind(AssertUnwindSafe(|| shim.allocator.free(ptr)));
+	}
+}
+
+extern_system_fn! {
+	unsafe fn custom_allocator_info(this: *const ort_sys::OrtAllocator) -> *const ort_sys::OrtMemoryInfo {
+		let shim = &*(this as *const CustomAllocatorShim);
+		shim.memory_info.ptr
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use test_log::test;
@@ -161,4 +399,86 @@ mod tests {
 		let memory_info = MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default).unwrap();
 		std::mem::drop(memory_info);
 	}
+
+	#[test]
+	fn test_memory_info_device() -> Result<()> {
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Arena, MemoryType::Default)?;
+		assert_eq!(memory_info.allocation_device()?, AllocationDevice::CPU);
+		assert_eq!(memory_info.device_type(), MemoryDeviceType::CPU);
+		assert_eq!(memory_info.device_id()?, 0);
+
+		let memory_info = MemoryInfo::new_cpu_on_numa_node(1, AllocatorType::Device, MemoryType::Default)?;
+		assert_eq!(memory_info.allocation_device()?, AllocationDevice::CPU);
+		assert_eq!(memory_info.device_type(), MemoryDeviceType::CPU);
+		assert_eq!(memory_info.device_id()?, 1);
+		assert_eq!(memory_info.try_clone()?.device_id()?, 1);
+
+		let memory_info = MemoryInfo::new(AllocationDevice::CUDA, 2, AllocatorType::Device, MemoryType::Default)?;
+		assert_eq!(memory_info.device_type(), MemoryDeviceType::GPU);
+		assert_eq!(memory_info.device_id()?, 2);
+		// pinned memory is accessible from the host
+		let memory_info = MemoryInfo::new(AllocationDevice::CUDAPinned, 0, AllocatorType::Device, MemoryType::CPUOutput)?;
+		assert_eq!(memory_info.device_type(), MemoryDeviceType::CPU);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_huge_page_chunks() -> Result<()> {
+		const MIB: usize = 1024 * 1024;
+
+		let config = ArenaConfig::default().with_huge_page_chunks(ArenaConfig::HUGE_PAGE_SIZE);
+		assert_eq!((config.initial_chunk_size_bytes, config.initial_growth_chunk_size_bytes), (Some(2 * MIB), Some(2 * MIB)));
+
+		let config = ArenaConfig {
+			max_mem: Some(100 * MIB),
+			initial_chunk_size_bytes: Some(16 * MIB),
+			initial_growth_chunk_size_bytes: Some(17 * MIB),
+			..ArenaConfig::default()
+		}
+		.with_huge_page_chunks(ArenaConfig::HUGE_PAGE_SIZE);
+		assert_eq!(config.max_mem, Some(100 * MIB));
+		assert_eq!((config.initial_chunk_size_bytes, config.initial_growth_chunk_size_bytes), (Some(16 * MIB), Some(18 * MIB)));
+
+		// 1 GiB pages
+		let config = config.with_huge_page_chunks(1024 * MIB);
+		assert_eq!((config.initial_chunk_size_bytes, config.initial_growth_chunk_size_bytes), (Some(1024 * MIB), Some(1024 * MIB)));
+
+		// ONNX Runtime accepts the rounded sizes
+		let arena_cfg_ptr = config.create_raw()?;
+		ortsys![unsafe ReleaseArenaCfg(arena_cfg_ptr)];
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_custom_allocator_shim() {
+		use std::sync::atomic::{AtomicUsize, Ordering};
+
+		#[derive(Default)]
+		struct Counting(AtomicUsize);
+
+		impl CustomAllocator for Counting {
+			fn alloc(&self, _size: usize) -> *mut c_void {
+				self.0.fetch_add(1, Ordering::Relaxed);
+				std::ptr::null_mut()
+			}
+
+			fn free(&self, _ptr: *mut c_void) {
+				panic!("free should not be called with a failed allocation");
+			}
+		}
+
+		let counting = Box::new(Counting::default());
+		let counter: *const Counting = &*counting;
+		let mut shim = CustomAllocatorShim::new(counting).unwrap();
+		let ptr = shim.as_ptr();
+		unsafe {
+			assert!(((*ptr).Alloc.unwrap())(ptr, 16).is_null());
+			assert_eq!((*counter).0.load(Ordering::Relaxed), 1);
+			// the panic is caught rather than unwinding through the FFI boundary
+			((*ptr).Free.unwrap())(ptr, std::ptr::null_mut());
+			assert_eq!(((*ptr).Info.unwrap())(ptr), shim.memory_info.ptr as *const _);
+		}
+	}
 }
@@ -0,0 +1,172 @@
+//! A plain-data description of [`crate::SessionBuilder`] options, which can be loaded from a configuration file.
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	ArbitrarilyConfigurableExecutionProvider, CPUExecutionProvider, CUDAExecutionProvider, DirectMLExecutionProvider, ExecutionMode, ExecutionProvider,
+	ExecutionProviderDispatch, GraphOptimizationLevel, ROCmExecutionProvider, Result, TensorRTExecutionProvider
+};
+
+/// Options to apply to a [`crate::SessionBuilder`] via [`crate::SessionBuilder::apply_config`]. Options left as `None`
+/// (or empty) leave the builder's configuration as-is.
+///
+/// With the `serde` feature enabled, this can be (de)serialized, e.g. to keep model configurations in a TOML file:
+///
+/// ```toml
+/// optimization_level = "Level2"
+/// intra_threads = 4
+///
+/// [config_entries]
+/// "session.set_denormal_as_zero" = "1"
+///
+/// [[execution_providers]]
+/// type = "CUDA"
+/// device_id = 1
+/// options = { cudnn_conv_use_max_workspace = "1" }
+///
+/// [[execution_providers]]
+/// type = "CPU"
+/// arena_allocator = true
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct SessionConfig {
+	/// See [`crate::SessionBuilder::with_optimization_level`].
+	pub optimization_level: Option<GraphOptimizationLevel>,
+	/// See [`crate::SessionBuilder::with_execution_mode`].
+	pub execution_mode: Option<ExecutionMode>,
+	/// See [`crate::SessionBuilder::with_intra_threads`].
+	pub intra_threads: Option<i16>,
+	/// See [`crate::SessionBuilder::with_inter_threads`].
+	pub inter_threads: Option<i16>,
+	/// See [`crate::SessionBuilder::with_memory_pattern`].
+	pub memory_pattern: Option<bool>,
+	/// The execution providers to register, in order. If non-empty, these replace any execution providers previously
+	/// configured on the builder; see [`crate::SessionBuilder::with_execution_providers`].
+	pub execution_providers: Vec<ExecutionProviderConfig>,
+	/// Session configuration entries (such as `session.*` keys) to add, as documented in ONNX Runtime's
+	/// `onnxruntime_session_options_config_keys.h`.
+	pub config_entries: BTreeMap<String, String>
+}
+
+/// The configuration of a single execution provider in a [`SessionConfig`]. When (de)serialized, the execution
+/// provider is identified by a `type` field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", deny_unknown_fields))]
+#[non_exhaustive]
+pub enum ExecutionProviderConfig {
+	/// A [`CPUExecutionProvider`].
+	CPU {
+		/// See [`CPUExecutionProvider::with_arena_allocator`].
+		#[cfg_attr(feature = "serde", serde(default))]
+		arena_allocator: bool
+	},
+	/// A [`CUDAExecutionProvider`].
+	CUDA {
+		/// See [`CUDAExecutionProvider::with_device_id`].
+		#[cfg_attr(feature = "serde", serde(default))]
+		device_id: Option<i32>,
+		/// Provider options by their string keys; see [`ArbitrarilyConfigurableExecutionProvider::with_arbitrary_config`].
+		#[cfg_attr(feature = "serde", serde(default))]
+		options: BTreeMap<String, String>
+	},
+	/// A [`TensorRTExecutionProvider`].
+	TensorRT {
+		/// See [`TensorRTExecutionProvider::with_device_id`].
+		#[cfg_attr(feature = "serde", serde(default))]
+		device_id: Option<i32>,
+		/// Provider options by their string keys; see [`ArbitrarilyConfigurableExecutionProvider::with_arbitrary_config`].
+		#[cfg_attr(feature = "serde", serde(default))]
+		options: BTreeMap<String, String>
+	},
+	/// A [`DirectMLExecutionProvider`].
+	DirectML {
+		/// See [`DirectMLExecutionProvider::with_device_id`].
+		#[cfg_attr(feature = "serde", serde(default))]
+		device_id: Option<i32>
+	},
+	/// A [`ROCmExecutionProvider`].
+	ROCm {
+		/// See [`ROCmExecutionProvider::with_device_id`].
+		#[cfg_attr(feature = "serde", serde(default))]
+		device_id: Option<i32>
+	}
+}
+
+impl ExecutionProviderConfig {
+	/// Creates the execution provider described by this configuration.
+	///
+	/// Returns an error if any of its options can't be passed to ONNX Runtime, e.g. if they contain a NUL byte.
+	pub fn build(&self) -> Result<ExecutionProviderDispatch> {
+		match self {
+			Self::CPU { arena_allocator } => {
+				let ep = CPUExecutionProvider::default();
+				Ok(if *arena_allocator { ep.with_arena_allocator() } else { ep }.build())
+			}
+			Self::CUDA { device_id, options } => {
+				let mut ep = CUDAExecutionProvider::default();
+				if let Some(device_id) = device_id {
+					ep = ep.with_device_id(*device_id);
+				}
+				options.iter().fold(ep, |ep, (k, v)| ep.with_arbitrary_config(k, v)).try_build()
+			}
+			Self::TensorRT { device_id, options } => {
+				let mut ep = TensorRTExecutionProvider::default();
+				if let Some(device_id) = device_id {
+					ep = ep.with_device_id(*device_id);
+				}
+				options.iter().fold(ep, |ep, (k, v)| ep.with_arbitrary_config(k, v)).try_build()
+			}
+			Self::DirectML { device_id } => {
+				let ep = DirectMLExecutionProvider::default();
+				Ok(match device_id {
+					Some(device_id) => ep.with_device_id(*device_id),
+					None => ep
+				}
+				.build())
+			}
+			Self::ROCm { device_id } => {
+				let ep = ROCmExecutionProvider::default();
+				Ok(match device_id {
+					Some(device_id) => ep.with_device_id(*device_id),
+					None => ep
+				}
+				.build())
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Error;
+
+	#[test]
+	fn test_build_execution_providers() -> Result<()> {
+		let cpu = ExecutionProviderConfig::CPU { arena_allocator: true }.build()?;
+		assert!(matches!(&cpu, ExecutionProviderDispatch::CPU(cpu) if cpu.use_arena()));
+
+		let cuda = ExecutionProviderConfig::CUDA {
+			device_id: Some(1),
+			options: BTreeMap::from([("cudnn_conv_use_max_workspace".to_string(), "1".to_string())])
+		}
+		.build()?;
+		let options = cuda.effective_options();
+		assert_eq!(options["device_id"], "1");
+		assert_eq!(options["cudnn_conv_use_max_workspace"], "1");
+
+		let invalid = ExecutionProviderConfig::TensorRT {
+			device_id: None,
+			options: BTreeMap::from([("trt_engine_cache_path".to_string(), "a\0b".to_string())])
+		};
+		assert!(matches!(invalid.build(), Err(Error::FfiStringNull(_))));
+
+		Ok(())
+	}
+}
@@ -0,0 +1,113 @@
+//! A summary of the configuration applied to a [`crate::SessionBuilder`].
+
+use crate::{ExecutionMode, ExecutionProvider, ExecutionProviderDispatch, GraphOptimizationLevel, LogSeverity};
+
+/// The effective configuration of a [`crate::SessionBuilder`], as reported by [`crate::SessionBuilder::describe`].
+///
+/// Options that have not been configured report ONNX Runtime's defaults. Only options set through the builder are
+/// tracked; options set on the underlying `OrtSessionOptions` by other means (i.e. before being passed to
+/// [`crate::SessionBuilder::from_raw_options`], or by execution providers when they're registered) are not reflected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionConfigSummary {
+	/// The graph optimization level. ONNX Runtime enables all optimizations by default.
+	pub optimization_level: GraphOptimizationLevel,
+	/// The execution mode.
+	pub execution_mode: ExecutionMode,
+	/// The number of intra-op threads, or `None` to let ONNX Runtime decide.
+	pub intra_threads: Option<i16>,
+	/// The number of inter-op threads, or `None` to let ONNX Runtime decide.
+	pub inter_threads: Option<i16>,
+	/// Whether the session creates its own thread pools, as opposed to using the environment's global thread pools.
+	pub per_session_threads: bool,
+	/// The names of the execution providers configured on the builder, in the order they will be registered. When a
+	/// provider has fallbacks (see [`ExecutionProviderDispatch::or_else`]), the alternatives are listed separated by
+	/// ` | `.
+	///
+	/// The execution providers configured on the session's environment are registered after these, and are not included.
+	pub execution_providers: Vec<String>,
+	/// Whether the CPU memory arena is enabled. This is configured via
+	/// [`CPUExecutionProvider::with_arena_allocator`](crate::CPUExecutionProvider::with_arena_allocator) when a
+	/// [`crate::CPUExecutionProvider`] is configured, and is enabled by ONNX Runtime otherwise.
+	pub cpu_mem_arena: bool,
+	/// Whether memory pattern optimization is enabled.
+	pub memory_pattern: bool,
+	/// The minimum severity of log messages emitted by the session, or `None` to use the environment's.
+	pub log_severity_level: Option<LogSeverity>,
+	/// The verbosity of the session's `VLOG` messages.
+	pub log_verbosity_level: i32,
+	/// Session configuration entries (such as `session.*` keys) set by builder methods, in the order they were first set.
+	pub config_entries: Vec<(String, String)>
+}
+
+impl Default for SessionConfigSummary {
+	fn default() -> Self {
+		Self {
+			optimization_level: GraphOptimizationLevel::Level3,
+			execution_mode: ExecutionMode::Sequential,
+			intra_threads: None,
+			inter_threads: None,
+			per_session_threads: true,
+			execution_providers: Vec::new(),
+			cpu_mem_arena: true,
+			memory_pattern: true,
+			log_severity_level: None,
+			log_verbosity_level: 0,
+			config_entries: Vec::new()
+		}
+	}
+}
+
+impl SessionConfigSummary {
+	pub(crate) fn set_config_entry(&mut self, key: &str, value: &str) {
+		match self.config_entries.iter_mut().find(|(k, _)| k == key) {
+			Some((_, v)) => *v = value.to_owned(),
+			None => self.config_entries.push((key.to_owned(), value.to_owned()))
+		}
+	}
+
+	pub(crate) fn set_execution_providers(&mut self, execution_providers: &[ExecutionProviderDispatch]) {
+		self.execution_providers = execution_providers
+			.iter()
+			.map(|ep| ep.alternatives().iter().map(|alt| alt.as_str()).collect::<Vec<_>>().join(" | "))
+			.collect();
+		self.cpu_mem_arena = execution_providers
+			.iter()
+			.find_map(|ep| match ep {
+				ExecutionProviderDispatch::CPU(cpu) => Some(cpu.use_arena()),
+				_ => None
+			})
+			.unwrap_or(true);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{CPUExecutionProvider, CUDAExecutionProvider};
+
+	#[test]
+	fn test_set_execution_providers() {
+		let mut summary = SessionConfigSummary::default();
+		summary.set_execution_providers(&[
+			CUDAExecutionProvider::default().build().or_else(CPUExecutionProvider::default()),
+			CPUExecutionProvider::default().build()
+		]);
+		assert_eq!(summary.execution_providers, ["CUDAExecutionProvider | CPUExecutionProvider", "CPUExecutionProvider"]);
+		assert!(!summary.cpu_mem_arena);
+
+		summary.set_execution_providers(&[CPUExecutionProvider::default().with_arena_allocator().build()]);
+		assert!(summary.cpu_mem_arena);
+		summary.set_execution_providers(&[]);
+		assert!(summary.execution_providers.is_empty());
+		assert!(summary.cpu_mem_arena);
+	}
+
+	#[test]
+	fn test_set_config_entry() {
+		let mut summary = SessionConfigSummary::default();
+		summary.set_config_entry("session.a", "1");
+		summary.set_config_entry("session.b", "0");
+		summary.set_config_entry("session.a", "0");
+		assert_eq!(summary.config_entries, [("session.a".to_string(), "0".to_string()), ("session.b".to_string(), "0".to_string())]);
+	}
+}
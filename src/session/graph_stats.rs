@@ -0,0 +1,310 @@
+//! Computation of basic statistics about a model's graph.
+//!
+//! ONNX Runtime does not expose any APIs to introspect a loaded graph, so the statistics are instead computed by
+//! scanning the serialized `ModelProto` directly. Only the handful of fields required are decoded; everything else is
+//! skipped over without being parsed.
+
+use std::{
+	fmt,
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex, OnceLock}
+};
+
+use crate::Error;
+
+/// Basic statistics about a model's graph, as reported by [`crate::Session::graph_stats`].
+///
+/// Statistics only cover the model's main graph as it is stored in the model file, i.e. before any optimizations are
+/// applied by ONNX Runtime. Nodes & initializers inside subgraphs of control flow operators (such as `If` or `Loop`)
+/// and model-local functions are not counted.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GraphStats {
+	/// The number of nodes in the graph.
+	pub node_count: usize,
+	/// The number of initializers (including sparse initializers) in the graph.
+	pub initializer_count: usize,
+	/// The total number of elements across all initializers.
+	pub parameter_count: u64,
+	/// The total size of all initializers' data, in bytes. For sparse initializers, this includes the size of both their
+	/// values and indices. Initializers stored in external data files are included.
+	pub parameter_bytes: u64
+}
+
+/// Where a session can get its [`GraphStats`] from.
+pub(crate) enum GraphStatsSource {
+	/// The model was loaded from a file, which is re-read whenever statistics are requested.
+	File(PathBuf),
+	/// The model was loaded from memory. Statistics are computed from the model bytes when first requested, after which
+	/// the bytes are released.
+	Memory {
+		model: Mutex<Option<Arc<[u8]>>>,
+		stats: OnceLock<Result<GraphStats, String>>
+	},
+	/// The session was created from a raw pointer, so the model is not available.
+	Unavailable
+}
+
+impl fmt::Debug for GraphStatsSource {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::File(path) => f.debug_tuple("File").field(path).finish(),
+			// don't dump the whole model
+			Self::Memory { stats, .. } => f.debug_struct("Memory").field("stats", stats).finish_non_exhaustive(),
+			Self::Unavailable => f.write_str("Unavailable")
+		}
+	}
+}
+
+impl GraphStatsSource {
+	pub(crate) fn from_file(model_filepath: &Path) -> Self {
+		// canonicalize so statistics still work if the working directory changes
+		GraphStatsSource::File(std::fs::canonicalize(model_filepath).unwrap_or_else(|_| model_filepath.to_path_buf()))
+	}
+
+	pub(crate) fn from_memory(model: Arc<[u8]>) -> Self {
+		GraphStatsSource::Memory {
+			model: Mutex::new(Some(model)),
+			stats: OnceLock::new()
+		}
+	}
+
+	/// Returns the statistics of the model, computing them if necessary.
+	pub(crate) fn get(&self) -> crate::Result<GraphStats> {
+		match self {
+			GraphStatsSource::File(path) => {
+				let model = std::fs::read(path).map_err(Error::ReadModel)?;
+				compute_graph_stats(&model).map_err(Error::ComputeGraphStats)
+			}
+			GraphStatsSource::Memory { model, stats } => stats
+				.get_or_init(|| {
+					let model = model.lock().unwrap_or_else(|e| e.into_inner()).take();
+					compute_graph_stats(model.as_deref().unwrap_or_default())
+				})
+				.clone()
+				.map_err(Error::ComputeGraphStats),
+			GraphStatsSource::Unavailable => Err(Error::ComputeGraphStats("the model is not available for sessions created from a raw pointer".to_string()))
+		}
+	}
+}
+
+const WIRE_VARINT: u64 = 0;
+const WIRE_FIXED64: u64 = 1;
+const WIRE_LEN: u64 = 2;
+const WIRE_FIXED32: u64 = 5;
+
+/// A field decoded from a protobuf message.
+enum Field<'a> {
+	Varint(u64),
+	Len(&'a [u8]),
+	Fixed
+}
+
+struct Fields<'a> {
+	data: &'a [u8]
+}
+
+impl<'a> Fields<'a> {
+	fn new(data: &'a [u8]) -> Self {
+		Self { data }
+	}
+
+	fn varint(&mut self) -> Result<u64, String> {
+		let mut value = 0;
+		for (i, byte) in self.data.iter().enumerate().take(10) {
+			value |= ((byte & 0x7f) as u64) << (i * 7);
+			if byte & 0x80 == 0 {
+				self.data = &self.data[i + 1..];
+				return Ok(value);
+			}
+		}
+		Err("malformed varint".to_string())
+	}
+
+	fn take(&mut self, len: u64) -> Result<&'a [u8], String> {
+		if len > self.data.len() as u64 {
+			return Err("unexpected end of message".to_string());
+		}
+		let (bytes, rest) = self.data.split_at(len as usize);
+		self.data = rest;
+		Ok(bytes)
+	}
+}
+
+impl<'a> Iterator for Fields<'a> {
+	type Item = Result<(u64, Field<'a>), String>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.data.is_empty() {
+			return None;
+		}
+		let field = (|| {
+			let key = self.varint()?;
+			let value = match key & 0x7 {
+				WIRE_VARINT => Field::Varint(self.varint()?),
+				WIRE_FIXED64 => self.take(8).map(|_| Field::Fixed)?,
+				WIRE_LEN => {
+					let len = self.varint()?;
+					Field::Len(self.take(len)?)
+				}
+				WIRE_FIXED32 => self.take(4).map(|_| Field::Fixed)?,
+				wire_type => return Err(format!("unsupported wire type {wire_type}"))
+			};
+			Ok((key >> 3, value))
+		})();
+		if field.is_err() {
+			// don't yield garbage after an error
+			self.data = &[];
+		}
+		Some(field)
+	}
+}
+
+/// Returns the size in bits of a single element of the given `TensorProto.DataType`, or `None` for strings & unknown
+/// types.
+fn element_bits(data_type: u64) -> Option<u64> {
+	match data_type {
+		// UINT4, INT4
+		21 | 22 => Some(4),
+		// UINT8, INT8, BOOL, FLOAT8E4M3FN, FLOAT8E4M3FNUZ, FLOAT8E5M2, FLOAT8E5M2FNUZ
+		2 | 3 | 9 | 17..=20 => Some(8),
+		// UINT16, INT16, FLOAT16, BFLOAT16
+		4 | 5 | 10 | 16 => Some(16),
+		// FLOAT, INT32, UINT32
+		1 | 6 | 12 => Some(32),
+		// INT64, DOUBLE, UINT64, COMPLEX64
+		7 | 11 | 13 | 14 => Some(64),
+		// COMPLEX128
+		15 => Some(128),
+		_ => None
+	}
+}
+
+/// Decodes a `TensorProto`, returning its element count and size in bytes.
+fn tensor_size(tensor: &[u8]) -> Result<(u64, u64), String> {
+	let mut element_count = 1_u64;
+	let mut data_type = 0;
+	let mut string_bytes = 0;
+	for field in Fields::new(tensor) {
+		match field? {
+			// dims
+			(1, Field::Varint(dim)) => element_count = element_count.saturating_mul(dim),
+			(1, Field::Len(packed)) => {
+				let mut dims = Fields::new(packed);
+				while !dims.data.is_empty() {
+					element_count = element_count.saturating_mul(dims.varint()?);
+				}
+			}
+			// data_type
+			(2, Field::Varint(ty)) => data_type = ty,
+			// string_data
+			(6, Field::Len(string)) => string_bytes += string.len() as u64,
+			_ => {}
+		}
+	}
+	let bytes = match element_bits(data_type) {
+		Some(bits) => (element_count.saturating_mul(bits).saturating_add(7)) / 8,
+		None => string_bytes
+	};
+	Ok((element_count, bytes))
+}
+
+/// Computes statistics for the serialized `ModelProto` in `model`.
+pub(crate) fn compute_graph_stats(model: &[u8]) -> Result<GraphStats, String> {
+	// models in the ORT format are FlatBuffers with the file identifier `ORTM`
+	if model.get(4..8) == Some(b"ORTM") {
+		return Err("graph statistics are not available for models in the ORT format".to_string());
+	}
+
+	let mut graph = None;
+	for field in Fields::new(model) {
+		if let (7, Field::Len(g)) = field? {
+			graph = Some(g);
+		}
+	}
+	let graph = graph.ok_or_else(|| "model does not contain a graph".to_string())?;
+
+	fn add_tensor(stats: &mut GraphStats, tensor: &[u8]) -> Result<(), String> {
+		let (count, bytes) = tensor_size(tensor)?;
+		stats.parameter_count += count;
+		stats.parameter_bytes += bytes;
+		Ok(())
+	}
+
+	let mut stats = GraphStats::default();
+	for field in Fields::new(graph) {
+		match field? {
+			// node
+			(1, Field::Len(_)) => stats.node_count += 1,
+			// initializer
+			(5, Field::Len(tensor)) => {
+				stats.initializer_count += 1;
+				add_tensor(&mut stats, tensor)?;
+			}
+			// sparse_initializer
+			(15, Field::Len(sparse)) => {
+				stats.initializer_count += 1;
+				for field in Fields::new(sparse) {
+					match field? {
+						// values
+						(1, Field::Len(values)) => add_tensor(&mut stats, values)?,
+						// indices; these aren't parameters, but do take up space
+						(2, Field::Len(indices)) => stats.parameter_bytes += tensor_size(indices)?.1,
+						_ => {}
+					}
+				}
+			}
+			_ => {}
+		}
+	}
+	Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_compute_graph_stats() {
+		// `qdq_relu.onnx` has 3 nodes and two scalar initializers: a `float` scale and a `uint8` zero point
+		let stats = compute_graph_stats(include_bytes!("../../tests/data/qdq_relu.onnx")).unwrap();
+		assert_eq!(
+			stats,
+			GraphStats {
+				node_count: 3,
+				initializer_count: 2,
+				parameter_count: 2,
+				parameter_bytes: 5
+			}
+		);
+
+		let stats = compute_graph_stats(include_bytes!("../../tests/data/branches.onnx")).unwrap();
+		assert_eq!(stats.node_count, 3);
+		assert_eq!(stats.initializer_count, 0);
+
+		assert!(compute_graph_stats(include_bytes!("../../tests/data/upsample.ort")).is_err());
+		assert!(compute_graph_stats(&[0x3a, 0xff]).is_err());
+	}
+
+	#[test]
+	fn test_lazy_memory_stats() {
+		let source = GraphStatsSource::from_memory(include_bytes!("../../tests/data/qdq_relu.onnx").as_slice().into());
+		let (model, stats) = match &source {
+			GraphStatsSource::Memory { model, stats } => (model, stats),
+			_ => unreachable!()
+		};
+		// nothing is computed until the statistics are requested...
+		assert!(stats.get().is_none());
+		assert_eq!(source.get().unwrap().node_count, 3);
+		// ...after which the model bytes are no longer needed
+		assert!(model.lock().unwrap().is_none());
+		assert_eq!(source.get().unwrap().node_count, 3);
+	}
+
+	#[test]
+	fn test_tensor_size() {
+		// dims: [2, 3] (packed), data_type: INT64
+		assert_eq!(tensor_size(&[0x0a, 0x02, 0x02, 0x03, 0x10, 0x07]).unwrap(), (6, 48));
+		// dims: [5] (unpacked), data_type: INT4
+		assert_eq!(tensor_size(&[0x08, 0x05, 0x10, 0x16]).unwrap(), (5, 3));
+	}
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use compact_str::CompactString;
 
-use crate::Value;
+use crate::{Result, Value};
 
 pub enum SessionInputs<'i, const N: usize = 0> {
 	ValueMap(HashMap<CompactString, Value>),
@@ -28,6 +28,56 @@ impl<'i, const N: usize> From<[Value; N]> for SessionInputs<'i, N> {
 	}
 }
 
+impl<'i, K: Into<CompactString>> From<Vec<(K, Value)>> for SessionInputs<'i> {
+	fn from(val: Vec<(K, Value)>) -> Self {
+		SessionInputs::ValueMap(val.into_iter().map(|c| (c.0.into(), c.1)).collect())
+	}
+}
+
+/// Types which can be used as the inputs to [`crate::Session::run`].
+///
+/// This is implemented for everything that can be converted into [`SessionInputs`], including the output of the
+/// [`inputs!`](crate::inputs) macro, `HashMap`s of named values, and `Vec`s of `(name, value)` pairs. It can also be
+/// implemented for your own types, i.e. to pass the fields of a struct as named inputs to a model:
+///
+/// ```no_run
+/// # use ort::{IntoInputs, Session, SessionInputs, Value};
+/// struct TextInputs {
+/// 	input_ids: Vec<i64>,
+/// 	attention_mask: Vec<i64>
+/// }
+///
+/// impl IntoInputs<'static> for TextInputs {
+/// 	fn into_inputs(self) -> ort::Result<SessionInputs<'static>> {
+/// 		let shape = vec![1, self.input_ids.len() as i64];
+/// 		Ok(vec![
+/// 			("input_ids", Value::from_array((shape.clone(), &self.input_ids[..]))?),
+/// 			("attention_mask", Value::from_array((shape, &self.attention_mask[..]))?)
+/// 		]
+/// 		.into())
+/// 	}
+/// }
+///
+/// # fn main() -> ort::Result<()> {
+/// let session = Session::builder()?.with_model_from_file("model.onnx")?;
+/// let outputs = session.run(TextInputs {
+/// 	input_ids: vec![101, 2023, 102],
+/// 	attention_mask: vec![1, 1, 1]
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait IntoInputs<'i, const N: usize = 0> {
+	/// Converts `self` into [`SessionInputs`].
+	fn into_inputs(self) -> Result<SessionInputs<'i, N>>;
+}
+
+impl<'i, const N: usize, T: Into<SessionInputs<'i, N>>> IntoInputs<'i, N> for T {
+	fn into_inputs(self) -> Result<SessionInputs<'i, N>> {
+		Ok(self.into())
+	}
+}
+
 /// Construct the inputs to a session from an array or map of values.
 ///
 /// The result of this macro is an `Result<SessionInputs, OrtError>`, so make sure you `?` on the result.
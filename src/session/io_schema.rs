@@ -0,0 +1,139 @@
+//! Machine-readable descriptions of a session's inputs & outputs.
+
+use std::{ffi::CStr, os::raw::c_char};
+
+use serde_json::{json, Value as JsonValue};
+
+use super::{Input, Output};
+use crate::{error::status_to_result, ortsys, tensor::TensorElementType, value::ValueType, Error, Result};
+
+/// Returns the name ONNX uses for the given element type, i.e. `float` for [`TensorElementType::Float32`].
+fn onnx_type_name(ty: TensorElementType) -> &'static str {
+	match ty {
+		TensorElementType::Float32 => "float",
+		TensorElementType::Uint8 => "uint8",
+		TensorElementType::Int8 => "int8",
+		TensorElementType::Uint16 => "uint16",
+		TensorElementType::Int16 => "int16",
+		TensorElementType::Int32 => "int32",
+		TensorElementType::Int64 => "int64",
+		TensorElementType::String => "string",
+		TensorElementType::Bool => "bool",
+		#[cfg(feature = "half")]
+		TensorElementType::Float16 => "float16",
+		TensorElementType::Float64 => "double",
+		TensorElementType::Uint32 => "uint32",
+		TensorElementType::Uint64 => "uint64",
+		#[cfg(feature = "half")]
+		TensorElementType::Bfloat16 => "bfloat16",
+		#[cfg(feature = "float8")]
+		TensorElementType::Float8E4M3FN => "float8e4m3fn",
+		#[cfg(feature = "float8")]
+		TensorElementType::Float8E4M3FNUZ => "float8e4m3fnuz",
+		#[cfg(feature = "float8")]
+		TensorElementType::Float8E5M2 => "float8e5m2",
+		#[cfg(feature = "float8")]
+		TensorElementType::Float8E5M2FNUZ => "float8e5m2fnuz"
+	}
+}
+
+/// Describes a value type as JSON. `symbols` are the symbolic names of a tensor's dimensions, if known; an empty name
+/// means the dimension has no symbol.
+fn describe_type(value_type: &ValueType, symbols: Option<&[String]>) -> JsonValue {
+	match value_type {
+		ValueType::Tensor { ty, dimensions } => {
+			let dimensions: Vec<JsonValue> = dimensions
+				.iter()
+				.enumerate()
+				.map(|(i, &dim)| match symbols.and_then(|s| s.get(i)) {
+					Some(symbol) if dim < 0 && !symbol.is_empty() => JsonValue::from(symbol.as_str()),
+					_ if dim < 0 => JsonValue::Null,
+					_ => JsonValue::from(dim)
+				})
+				.collect();
+			json!({ "type": "tensor", "element_type": onnx_type_name(*ty), "dimensions": dimensions })
+		}
+		ValueType::Sequence(element) => json!({ "type": "sequence", "element": describe_type(element, None) }),
+		ValueType::Map { key, value } => json!({ "type": "map", "key_type": onnx_type_name(*key), "value_type": onnx_type_name(*value) })
+	}
+}
+
+/// Retrieves the symbolic dimension names of the `i`th input or output, or `None` if it isn't a tensor.
+fn symbolic_dimensions(
+	f: crate::extern_system_fn! { unsafe fn(*const ort_sys::OrtSession, ort_sys::size_t, *mut *mut ort_sys::OrtTypeInfo) -> *mut ort_sys::OrtStatus },
+	session_ptr: *mut ort_sys::OrtSession,
+	i: usize
+) -> Result<Option<Vec<String>>> {
+	let mut typeinfo_ptr: *mut ort_sys::OrtTypeInfo = std::ptr::null_mut();
+	status_to_result(unsafe { f(session_ptr, i as _, &mut typeinfo_ptr) }).map_err(Error::GetTypeInfo)?;
+	if typeinfo_ptr.is_null() {
+		return Err(Error::GetTypeInfo(crate::ErrorInternal::Msg("TypeInfo was null".to_string())));
+	}
+
+	let result = (|| {
+		let mut info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+		ortsys![unsafe CastTypeInfoToTensorInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToTensorInfo];
+		// `CastTypeInfoToTensorInfo` succeeds with a null pointer for non-tensor types
+		if info_ptr.is_null() {
+			return Ok(None);
+		}
+
+		let mut num_dims = 0;
+		ortsys![unsafe GetDimensionsCount(info_ptr, &mut num_dims) -> Error::GetDimensionsCount];
+		let mut symbols: Vec<*const c_char> = vec![std::ptr::null(); num_dims as _];
+		ortsys![unsafe GetSymbolicDimensions(info_ptr, symbols.as_mut_ptr(), num_dims as _) -> Error::GetSymbolicDimensions];
+		Ok(Some(
+			symbols
+				.into_iter()
+				.map(|s| if s.is_null() { String::new() } else { unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned() })
+				.collect()
+		))
+	})();
+	ortsys![unsafe ReleaseTypeInfo(typeinfo_ptr)];
+	result
+}
+
+pub(crate) fn io_schema(session_ptr: *mut ort_sys::OrtSession, inputs: &[Input], outputs: &[Output]) -> Result<String> {
+	let get_input_type_info = crate::api().SessionGetInputTypeInfo.unwrap();
+	let get_output_type_info = crate::api().SessionGetOutputTypeInfo.unwrap();
+
+	let mut input_schemas = Vec::with_capacity(inputs.len());
+	for (i, input) in inputs.iter().enumerate() {
+		let symbols = symbolic_dimensions(get_input_type_info, session_ptr, i)?;
+		input_schemas.push(json!({ "name": input.name, "type": describe_type(&input.input_type, symbols.as_deref()) }));
+	}
+	let mut output_schemas = Vec::with_capacity(outputs.len());
+	for (i, output) in outputs.iter().enumerate() {
+		let symbols = symbolic_dimensions(get_output_type_info, session_ptr, i)?;
+		output_schemas.push(json!({ "name": output.name, "type": describe_type(&output.output_type, symbols.as_deref()) }));
+	}
+
+	Ok(json!({ "inputs": input_schemas, "outputs": output_schemas }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_describe_type() {
+		let tensor = ValueType::Tensor {
+			ty: TensorElementType::Float32,
+			dimensions: vec![-1, 3, -1]
+		};
+		assert_eq!(
+			describe_type(&tensor, Some(&["batch".to_string(), String::new(), String::new()])),
+			json!({ "type": "tensor", "element_type": "float", "dimensions": ["batch", 3, null] })
+		);
+		assert_eq!(describe_type(&tensor, None), json!({ "type": "tensor", "element_type": "float", "dimensions": [null, 3, null] }));
+
+		let sequence = ValueType::Sequence(Box::new(ValueType::Map {
+			key: TensorElementType::Int64,
+			value: TensorElementType::Float32
+		}));
+		assert_eq!(
+			describe_type(&sequence, None),
+			json!({ "type": "sequence", "element": { "type": "map", "key_type": "int64", "value_type": "float" } })
+		);
+	}
+}
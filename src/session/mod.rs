@@ -7,13 +7,17 @@ use std::os::windows::ffi::OsStrExt;
 use std::{
 	ffi::CString,
 	fmt,
+	io::Read,
 	marker::PhantomData,
 	ops::Deref,
 	os::raw::c_char,
 	path::Path,
 	ptr,
-	rc::Rc,
-	sync::{atomic::Ordering, Arc}
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc
+	},
+	time::Instant
 };
 #[cfg(feature = "fetch-models")]
 use std::{path::PathBuf, time::Duration};
@@ -25,21 +29,41 @@ use super::error::FetchModelError;
 use super::{
 	api, char_p_to_string,
 	environment::get_environment,
-	error::{assert_non_null_pointer, assert_null_pointer, status_to_result, Error, ErrorInternal, Result},
-	execution_providers::{apply_execution_providers, ExecutionProviderDispatch},
+	error::{assert_non_null_pointer, assert_null_pointer, create_session_error, status_to_result, Error, ErrorInternal, Result},
+	execution_providers::{apply_execution_providers, EpEventCallback, ExecutionProviderDispatch},
 	extern_system_fn,
 	io_binding::IoBinding,
 	memory::Allocator,
 	metadata::ModelMetadata,
 	ortsys,
+	tensor::dims_to_usize,
 	value::{Value, ValueType},
-	GraphOptimizationLevel
+	ExecutionMode, GraphOptimizationLevel, LogCapture, LogSeverity, ModelFileFormat
 };
 use crate::{environment::Environment, MemoryInfo};
 
+pub(crate) mod config;
+pub(crate) mod config_summary;
+pub(crate) mod graph_stats;
 pub(crate) mod input;
+#[cfg(feature = "io-schema")]
+pub(crate) mod io_schema;
 pub(crate) mod output;
-pub use self::{input::SessionInputs, output::SessionOutputs};
+#[cfg(feature = "profiling")]
+pub(crate) mod profiling;
+pub(crate) mod run_async;
+#[cfg(feature = "profiling")]
+pub use self::profiling::{OpTiming, ProfilingGuard};
+use self::graph_stats::GraphStatsSource;
+pub use self::{
+	config::{ExecutionProviderConfig, SessionConfig},
+	config_summary::SessionConfigSummary,
+	graph_stats::GraphStats,
+	input::{IntoInputs, SessionInputs},
+	output::SessionOutputs,
+	run_async::RunFuture
+};
+use self::run_async::AsyncInputs;
 
 /// Type used to create a session using the _builder pattern_. Once created with [`Session::builder`], you can use the
 /// different methods to configure the session.
@@ -62,12 +86,31 @@ pub use self::{input::SessionInputs, output::SessionOutputs};
 /// ```
 pub struct SessionBuilder {
 	pub(crate) session_options_ptr: *mut ort_sys::OrtSessionOptions,
-	memory_info: Option<Rc<MemoryInfo>>,
+	memory_info: Option<Arc<MemoryInfo>>,
+	#[cfg(feature = "custom-ops")]
+	custom_runtime_handles: Vec<Arc<CustomOpsLibHandle>>,
+	#[cfg(feature = "custom-ops")]
+	custom_op_domains: Vec<Arc<CustomOpDomain>>,
 	#[cfg(feature = "custom-ops")]
-	custom_runtime_handles: Vec<*mut std::os::raw::c_void>,
-	execution_providers: Vec<ExecutionProviderDispatch>
+	custom_op_domain_names: Vec<String>,
+	pub(crate) execution_providers: Vec<ExecutionProviderDispatch>,
+	/// Called after each attempt to register an execution provider.
+	pub(crate) ep_event_callback: Option<EpEventCallback>,
+	environment: Option<Arc<Environment>>,
+	/// The custom metadata key to read dimension overrides from; see [`SessionBuilder::with_metadata_driven_overrides`].
+	metadata_overrides_key: Option<String>,
+	/// The maximum size of the environment's CPU arena; see [`SessionBuilder::with_cpu_mem_limit`].
+	cpu_mem_limit: Option<usize>,
+	config: SessionConfigSummary,
+	/// The execution providers that were successfully registered when the session was created.
+	registered_execution_providers: Vec<&'static str>
 }
 
+// `OrtSessionOptions` is only mutated through builder methods taking `self`, and `Session`s created from the builder keep
+// it alive to reload with, so it must be safe to send them across threads.
+unsafe impl Send for SessionBuilder {}
+unsafe impl Sync for SessionBuilder {}
+
 impl fmt::Debug for SessionBuilder {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
 		f.debug_struct("SessionBuilder").field("memory_info", &self.memory_info).finish()
@@ -84,7 +127,17 @@ impl Clone for SessionBuilder {
 			memory_info: self.memory_info.clone(),
 			#[cfg(feature = "custom-ops")]
 			custom_runtime_handles: self.custom_runtime_handles.clone(),
-			execution_providers: self.execution_providers.clone()
+			#[cfg(feature = "custom-ops")]
+			custom_op_domains: self.custom_op_domains.clone(),
+			#[cfg(feature = "custom-ops")]
+			custom_op_domain_names: self.custom_op_domain_names.clone(),
+			execution_providers: self.execution_providers.clone(),
+			ep_event_callback: self.ep_event_callback.clone(),
+			environment: self.environment.clone(),
+			metadata_overrides_key: self.metadata_overrides_key.clone(),
+			cpu_mem_limit: self.cpu_mem_limit,
+			config: self.config.clone(),
+			registered_execution_providers: self.registered_execution_providers.clone()
 		}
 	}
 }
@@ -92,11 +145,6 @@ impl Clone for SessionBuilder {
 impl Drop for SessionBuilder {
 	#[tracing::instrument]
 	fn drop(&mut self) {
-		#[cfg(feature = "custom-ops")]
-		for &handle in self.custom_runtime_handles.iter() {
-			close_lib_handle(handle);
-		}
-
 		if !self.session_options_ptr.is_null() {
 			ortsys![unsafe ReleaseSessionOptions(self.session_options_ptr)];
 		}
@@ -114,10 +162,52 @@ impl SessionBuilder {
 			memory_info: None,
 			#[cfg(feature = "custom-ops")]
 			custom_runtime_handles: Vec::new(),
-			execution_providers: Vec::new()
+			#[cfg(feature = "custom-ops")]
+			custom_op_domains: Vec::new(),
+			#[cfg(feature = "custom-ops")]
+			custom_op_domain_names: Vec::new(),
+			execution_providers: Vec::new(),
+			ep_event_callback: None,
+			environment: None,
+			metadata_overrides_key: None,
+			cpu_mem_limit: None,
+			config: SessionConfigSummary::default(),
+			registered_execution_providers: Vec::new()
 		})
 	}
 
+	/// Creates a session builder from an [`ort_sys::OrtSessionOptions`] that was created outside of `ort`, i.e. by a
+	/// C++ host application that has already configured it.
+	///
+	/// Ownership of the options is transferred to the returned builder: any builder methods called afterwards further
+	/// modify the same options, and they will be released when the builder is dropped.
+	///
+	/// # Safety
+	///
+	/// - `ptr` must be a valid, non-null pointer to an `OrtSessionOptions`, created with the same ONNX Runtime library
+	///   `ort` is using.
+	/// - The options must not be used or released by anything else after calling this function.
+	pub unsafe fn from_raw_options(ptr: *mut ort_sys::OrtSessionOptions) -> SessionBuilder {
+		assert!(!ptr.is_null(), "session options pointer must not be null");
+		Self {
+			session_options_ptr: ptr,
+			memory_info: None,
+			#[cfg(feature = "custom-ops")]
+			custom_runtime_handles: Vec::new(),
+			#[cfg(feature = "custom-ops")]
+			custom_op_domains: Vec::new(),
+			#[cfg(feature = "custom-ops")]
+			custom_op_domain_names: Vec::new(),
+			execution_providers: Vec::new(),
+			ep_event_callback: None,
+			environment: None,
+			metadata_overrides_key: None,
+			cpu_mem_limit: None,
+			config: SessionConfigSummary::default(),
+			registered_execution_providers: Vec::new()
+		}
+	}
+
 	/// Configures a list of execution providers to attempt to use for the session.
 	///
 	/// Execution providers are loaded in the order they are provided until a suitable execution provider is found. Most
@@ -161,6 +251,50 @@ impl SessionBuilder {
 		Ok(self)
 	}
 
+	/// Sets a callback to be invoked after each attempt to register an execution provider when the session is created,
+	/// with the name of the execution provider and whether or not it was registered successfully.
+	///
+	/// The callback is invoked for every alternative of an [`ExecutionProviderDispatch::or_else`] chain that is attempted,
+	/// and for execution providers which are skipped because they are not available in the loaded build of ONNX Runtime
+	/// (with [`Error::ExecutionProviderUnavailable`]). This can be used to e.g. report which accelerators a service
+	/// ended up using; see also [`Session::registered_execution_providers`].
+	///
+	/// ```no_run
+	/// # use ort::{CUDAExecutionProvider, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?
+	/// 	.with_execution_providers([CUDAExecutionProvider::default().build()])?
+	/// 	.on_ep_event(|name, result| match result {
+	/// 		Ok(()) => println!("registered {name}"),
+	/// 		Err(e) => println!("failed to register {name}: {e}")
+	/// 	})?
+	/// 	.with_model_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn on_ep_event(mut self, f: impl Fn(&str, Result<(), &Error>) + Send + Sync + 'static) -> Result<Self> {
+		self.ep_event_callback = Some(Arc::new(f));
+		Ok(self)
+	}
+
+	/// Creates the session in the given [`Environment`] rather than the global environment.
+	///
+	/// Sharing one explicitly created environment between many sessions ensures environment-level state is only
+	/// initialized once. Sessions keep their environment alive for as long as they exist.
+	pub fn with_environment(mut self, environment: &Arc<Environment>) -> Result<Self> {
+		self.environment = Some(Arc::clone(environment));
+		Ok(self)
+	}
+
+	/// Returns the environment the session will be created in; either the one configured via
+	/// [`SessionBuilder::with_environment`], or the global environment.
+	pub(crate) fn environment(&self) -> Result<Arc<Environment>> {
+		match &self.environment {
+			Some(environment) => Ok(Arc::clone(environment)),
+			None => get_environment().map(Arc::clone)
+		}
+	}
+
 	/// Configure the session to use a number of threads to parallelize the execution within nodes. If ONNX Runtime was
 	/// built with OpenMP (as is the case with Microsoft's prebuilt binaries), this will have no effect on the number of
 	/// threads used. Instead, you can configure the number of threads OpenMP uses via the `OMP_NUM_THREADS` environment
@@ -168,10 +302,41 @@ impl SessionBuilder {
 	///
 	/// For configuring the number of threads used when the session execution mode is set to `Parallel`, see
 	/// [`SessionBuilder::with_inter_threads()`].
-	pub fn with_intra_threads(self, num_threads: i16) -> Result<Self> {
+	pub fn with_intra_threads(mut self, num_threads: i16) -> Result<Self> {
 		// We use a u16 in the builder to cover the 16-bits positive values of a i32.
-		let num_threads = num_threads as i32;
-		ortsys![unsafe SetIntraOpNumThreads(self.session_options_ptr, num_threads) -> Error::CreateSessionOptions];
+		ortsys![unsafe SetIntraOpNumThreads(self.session_options_ptr, num_threads as i32) -> Error::CreateSessionOptions];
+		self.config.intra_threads = Some(num_threads);
+		Ok(self)
+	}
+
+	/// Pins the session's intra-op threads to specific logical processors, which can improve latency consistency on
+	/// NUMA systems.
+	///
+	/// `affinities` specifies the affinity of each thread in the intra-op thread pool, except for the main thread
+	/// (which is the thread calling [`Session::run`]), separated by `;`. Each affinity is either a comma-separated list
+	/// of logical processor IDs, or a range of IDs written as `first-last`. Logical processor IDs start at **1**. For
+	/// example, with [`SessionBuilder::with_intra_threads`] set to 3, `"1,2;3-4"` pins the first pool thread to
+	/// processors 1 and 2, and the second to processors 3 and 4.
+	///
+	/// This only applies to sessions with their own thread pool. To set the affinity of the global thread pool, see
+	/// [`EnvironmentGlobalThreadPoolOptions::intra_op_thread_affinity`](crate::EnvironmentGlobalThreadPoolOptions).
+	pub fn with_intra_op_thread_affinity(mut self, affinities: &str) -> Result<Self> {
+		self.add_config_entry("session.intra_op_thread_affinities", affinities)?;
+		Ok(self)
+	}
+
+	/// Sets whether the session's intra-op threads spin (busy-wait) for new work after finishing a task, rather than
+	/// immediately going to sleep. Spinning is enabled by default.
+	///
+	/// Spinning reduces latency, since threads can pick up the next task without being woken by the OS, at the cost of
+	/// CPU time: idle threads keep cores busy for a while after each run. Disabling it is recommended when many sessions
+	/// (or other processes) share the same cores, i.e. in dense multi-tenant deployments, so that cycles aren't wasted
+	/// between requests.
+	///
+	/// This only applies to sessions with their own thread pool. For the global thread pool, see
+	/// [`EnvironmentGlobalThreadPoolOptions::spin_control`](crate::EnvironmentGlobalThreadPoolOptions::spin_control).
+	pub fn with_intra_op_spinning(mut self, enable: bool) -> Result<Self> {
+		self.add_config_entry("session.intra_op.allow_spinning", if enable { "1" } else { "0" })?;
 		Ok(self)
 	}
 
@@ -179,8 +344,9 @@ impl SessionBuilder {
 	/// This must be used with an environment created with
 	/// [`EnvironmentBuilder::with_global_thread_pool`](crate::environment::EnvironmentBuilder::with_global_thread_pool)
 	/// enabled.
-	pub fn with_disable_per_session_threads(self) -> Result<Self> {
+	pub fn with_disable_per_session_threads(mut self) -> Result<Self> {
 		ortsys![unsafe DisablePerSessionThreads(self.session_options_ptr) -> Error::CreateSessionOptions];
+		self.config.per_session_threads = false;
 		Ok(self)
 	}
 
@@ -191,10 +357,10 @@ impl SessionBuilder {
 	///
 	/// For configuring the number of threads used to parallelize the execution within nodes, see
 	/// [`SessionBuilder::with_intra_threads()`].
-	pub fn with_inter_threads(self, num_threads: i16) -> Result<Self> {
+	pub fn with_inter_threads(mut self, num_threads: i16) -> Result<Self> {
 		// We use a u16 in the builder to cover the 16-bits positive values of a i32.
-		let num_threads = num_threads as i32;
-		ortsys![unsafe SetInterOpNumThreads(self.session_options_ptr, num_threads) -> Error::CreateSessionOptions];
+		ortsys![unsafe SetInterOpNumThreads(self.session_options_ptr, num_threads as i32) -> Error::CreateSessionOptions];
+		self.config.inter_threads = Some(num_threads);
 		Ok(self)
 	}
 
@@ -204,19 +370,46 @@ impl SessionBuilder {
 	/// You can configure the amount of threads used to parallelize the execution of the graph via
 	/// [`SessionBuilder::with_inter_threads()`].
 	pub fn with_parallel_execution(self, parallel_execution: bool) -> Result<Self> {
-		let execution_mode = if parallel_execution {
-			ort_sys::ExecutionMode::ORT_PARALLEL
-		} else {
-			ort_sys::ExecutionMode::ORT_SEQUENTIAL
-		};
-		ortsys![unsafe SetSessionExecutionMode(self.session_options_ptr, execution_mode) -> Error::CreateSessionOptions];
+		self.with_execution_mode(if parallel_execution { ExecutionMode::Parallel } else { ExecutionMode::Sequential })
+	}
+
+	/// Set the session's execution mode. See [`ExecutionMode`] for more information. By default, the graph is executed
+	/// sequentially.
+	///
+	/// [`ExecutionMode::Parallel`] only improves performance for models with parallel branches, and only when the
+	/// number of inter-op threads (configured via [`SessionBuilder::with_inter_threads()`]) is greater than 1.
+	pub fn with_execution_mode(mut self, execution_mode: ExecutionMode) -> Result<Self> {
+		ortsys![unsafe SetSessionExecutionMode(self.session_options_ptr, execution_mode.into()) -> Error::CreateSessionOptions];
+		self.config.execution_mode = execution_mode;
+		Ok(self)
+	}
+
+	/// Sets the minimum severity of log messages emitted by this session, independently of the environment. ONNX Runtime
+	/// messages are forwarded to [`tracing`](https://crates.io/crates/tracing) by the environment's logger, so this can
+	/// be used to quiet a noisy session (or make a single session more verbose) without affecting other sessions.
+	///
+	/// By default, sessions use ONNX Runtime's default severity of [`LogSeverity::Warning`].
+	pub fn with_log_severity_level(mut self, severity: LogSeverity) -> Result<Self> {
+		let level: ort_sys::OrtLoggingLevel = severity.into();
+		ortsys![unsafe SetSessionLogSeverityLevel(self.session_options_ptr, level as _) -> Error::CreateSessionOptions];
+		self.config.log_severity_level = Some(severity);
+		Ok(self)
+	}
+
+	/// Sets the verbosity of `VLOG` messages emitted by this session; higher levels produce more output. This only has an
+	/// effect if the session's log severity is set to [`LogSeverity::Verbose`] via
+	/// [`SessionBuilder::with_log_severity_level`], and ONNX Runtime was built in debug mode.
+	pub fn with_log_verbosity_level(mut self, verbosity: i32) -> Result<Self> {
+		ortsys![unsafe SetSessionLogVerbosityLevel(self.session_options_ptr, verbosity as _) -> Error::CreateSessionOptions];
+		self.config.log_verbosity_level = verbosity;
 		Ok(self)
 	}
 
 	/// Set the session's optimization level. See [`GraphOptimizationLevel`] for more information on the different
 	/// optimization levels.
-	pub fn with_optimization_level(self, opt_level: GraphOptimizationLevel) -> Result<Self> {
+	pub fn with_optimization_level(mut self, opt_level: GraphOptimizationLevel) -> Result<Self> {
 		ortsys![unsafe SetSessionGraphOptimizationLevel(self.session_options_ptr, opt_level.into()) -> Error::CreateSessionOptions];
+		self.config.optimization_level = opt_level;
 		Ok(self)
 	}
 
@@ -234,20 +427,307 @@ impl SessionBuilder {
 	}
 
 	/// Enables/disables memory pattern optimization. Disable it if the input size varies, i.e., dynamic batch
-	pub fn with_memory_pattern(self, enable: bool) -> Result<Self> {
+	pub fn with_memory_pattern(mut self, enable: bool) -> Result<Self> {
 		if enable {
 			ortsys![unsafe EnableMemPattern(self.session_options_ptr) -> Error::CreateSessionOptions];
 		} else {
 			ortsys![unsafe DisableMemPattern(self.session_options_ptr) -> Error::CreateSessionOptions];
 		}
+		self.config.memory_pattern = enable;
+		Ok(self)
+	}
+
+	/// Disables the fusion of quantize/dequantize (QDQ) node groups into quantized operators.
+	///
+	/// By default, ONNX Runtime fuses `DequantizeLinear -> Op -> QuantizeLinear` patterns in QDQ models into quantized
+	/// kernels (e.g. `QLinearConv`), which is faster but may produce results that differ slightly from the float
+	/// reference. Disabling this runs the float operators between the Q/DQ nodes as-is, which is slower but more closely
+	/// matches the model's float semantics. This is useful for debugging accuracy issues in quantized models.
+	pub fn with_disable_quant_qdq(mut self, disable: bool) -> Result<Self> {
+		self.add_config_entry("session.disable_quant_qdq", if disable { "1" } else { "0" })?;
+		Ok(self)
+	}
+
+	/// Sets whether QDQ node groups using signed 8-bit (`int8`) quantization may be fused into quantized operators.
+	///
+	/// On x86-64, ONNX Runtime does not fuse `int8` QDQ groups by default, because the `uint8` kernels are generally
+	/// faster there and `int8` activations can saturate in some kernels, leading to accuracy loss. On ARM, `int8`
+	/// kernels are efficient and this is allowed by default. Enabling this on x86-64 can speed up `int8` models, but
+	/// their accuracy should be verified.
+	pub fn with_qdq_is_int8_allowed(mut self, allowed: bool) -> Result<Self> {
+		self.add_config_entry("session.qdqisint8allowed", if allowed { "1" } else { "0" })?;
+		Ok(self)
+	}
+
+	/// Sets whether denormal (subnormal) floating point numbers are flushed to zero during inference.
+	///
+	/// Arithmetic on denormals is extremely slow on many CPUs, so models that produce them (i.e. from very small
+	/// activations or weights) can see huge slowdowns; flushing them to zero avoids this. However, results may differ
+	/// slightly: any value smaller in magnitude than `f32::MIN_POSITIVE` (~1.18e-38) becomes `0.0`, which can affect
+	/// models that depend on such tiny values, e.g. via division.
+	///
+	/// This only affects ONNX Runtime's intra-op thread pool threads, and only on CPUs that support it (x86-64 with SSE3).
+	pub fn with_denormal_as_zero(mut self, enable: bool) -> Result<Self> {
+		self.add_config_entry("session.set_denormal_as_zero", if enable { "1" } else { "0" })?;
 		Ok(self)
 	}
 
+	/// Disables specific graph optimizers by name, e.g. `ConstantFolding` or `QDQSelectorActionTransformer`, while
+	/// keeping the rest of the optimizations enabled by the [`GraphOptimizationLevel`]. This is useful when a single
+	/// optimization pass causes a numeric regression for a model.
+	///
+	/// The names are the names of ONNX Runtime's graph transformers & rewrite rules, and are **not** validated by ONNX
+	/// Runtime; misspelled or unknown names are silently ignored.
+	pub fn with_disabled_optimizers(mut self, names: &[&str]) -> Result<Self> {
+		self.add_config_entry("optimization.disable_specified_optimizers", &names.join(","))?;
+		Ok(self)
+	}
+
+	/// Makes ONNX Runtime reference the model bytes passed to [`SessionBuilder::with_model_from_memory`] directly for
+	/// the lifetime of the session, rather than copying them while loading the model. This is only supported for models
+	/// in the ORT format (see [`ModelFileFormat::Ort`]); ONNX models are always parsed into a separate representation.
+	///
+	/// Since the session references the bytes, [`SessionBuilder::with_model_from_memory`] keeps a copy of them alive
+	/// for as long as the session exists. To avoid that copy, load the model with
+	/// [`SessionBuilder::with_model_from_memory_directly`] instead, which borrows the bytes and enables this
+	/// automatically.
+	pub fn with_model_bytes_directly(mut self, enable: bool) -> Result<Self> {
+		self.add_config_entry("session.use_ort_model_bytes_directly", if enable { "1" } else { "0" })?;
+		Ok(self)
+	}
+
+	/// Fixes the size of the free (symbolic) dimension `dim_name` to `size` in all of the model's inputs, i.e. to fix
+	/// the `batch` dimension of an input with shape `[batch, 3, 224, 224]`. Knowing the size of a dimension up front
+	/// allows ONNX Runtime to optimize the graph further.
+	pub fn with_dimension_override(self, dim_name: &str, size: i64) -> Result<Self> {
+		self.add_dimension_override(dim_name, size)?;
+		Ok(self)
+	}
+
+	fn add_dimension_override(&self, dim_name: &str, size: i64) -> Result<()> {
+		let dim_name = CString::new(dim_name)?;
+		ortsys![unsafe AddFreeDimensionOverrideByName(self.session_options_ptr, dim_name.as_ptr(), size) -> Error::CreateSessionOptions];
+		Ok(())
+	}
+
+	/// Applies dimension overrides (see [`SessionBuilder::with_dimension_override`]) read from the model's custom
+	/// metadata when the session is created. The value of the metadata key `key` should be a comma-separated list of
+	/// `name=size` pairs, e.g. `batch=8,sequence=128`. If the model doesn't have the key, no overrides are applied.
+	///
+	/// Since ONNX Runtime can only read a model's metadata from a loaded session, **the model is loaded twice**: once
+	/// with default options (and graph optimizations disabled) to read its metadata, then again with the overrides
+	/// applied. This roughly doubles the time taken & peak memory usage of creating the session.
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> ort::Result<()> {
+	/// // for a model with the metadata entry `preferred_batch: batch=8`
+	/// let session = Session::builder()?.with_metadata_driven_overrides("preferred_batch")?.with_model_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_metadata_driven_overrides(mut self, key: &str) -> Result<Self> {
+		self.metadata_overrides_key = Some(key.to_owned());
+		Ok(self)
+	}
+
+	/// Reads the dimension overrides configured via [`SessionBuilder::with_metadata_driven_overrides`] from the
+	/// metadata of a throwaway session created by `load`, and applies them to this builder.
+	fn apply_metadata_overrides(&mut self, env: &Arc<Environment>, load: impl FnOnce(SessionBuilder) -> Result<Session>) -> Result<()> {
+		let key = match self.metadata_overrides_key.take() {
+			Some(key) => key,
+			None => return Ok(())
+		};
+
+		let session = load(
+			SessionBuilder::new()?
+				.with_environment(env)?
+				.with_optimization_level(GraphOptimizationLevel::Disable)?
+		)?;
+		let value = match session.metadata()?.custom(&key)? {
+			Some(value) => value,
+			None => {
+				tracing::debug!("Model has no metadata key `{key}`; not applying dimension overrides");
+				return Ok(());
+			}
+		};
+
+		let invalid = || Error::InvalidMetadataOverrides { key: key.clone(), value: value.clone() };
+		let overrides = value
+			.split(',')
+			.map(|pair| {
+				let (name, size) = pair.split_once('=').ok_or_else(invalid)?;
+				Ok((name.trim(), size.trim().parse::<i64>().map_err(|_| invalid())?))
+			})
+			.collect::<Result<Vec<_>>>()?;
+		for (name, size) in overrides {
+			tracing::debug!("Overriding dimension `{name}` to {size}");
+			self.add_dimension_override(name, size)?;
+		}
+		Ok(())
+	}
+
+	/// Configures ONNX Runtime to dump an "EP context" model when the session is created: a copy of the model in which
+	/// the subgraphs compiled by execution providers that support it (such as TensorRT or QNN) are replaced by nodes
+	/// containing the compiled blobs. Loading the context model later skips compilation entirely.
+	///
+	/// `file_path` is where the context model is written; if `None`, it is written next to the original model with a
+	/// `_ctx.onnx` suffix. If `embed` is `true`, the compiled blobs are embedded in the context model itself; otherwise
+	/// they are written to separate files referenced by it.
+	///
+	/// ```no_run
+	/// # use std::path::Path;
+	/// # use ort::{QNNExecutionProvider, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?
+	/// 	.with_execution_providers([QNNExecutionProvider::default().build()])?
+	/// 	.with_ep_context(true, Some(Path::new("model_ctx.onnx")), true)?
+	/// 	.with_model_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_ep_context(mut self, enable: bool, file_path: Option<&Path>, embed: bool) -> Result<Self> {
+		self.add_config_entry("ep.context_enable", if enable { "1" } else { "0" })?;
+		if let Some(file_path) = file_path {
+			let file_path = file_path.to_str().ok_or_else(|| Error::NonUtf8Path { path: file_path.to_path_buf() })?;
+			self.add_config_entry("ep.context_file_path", file_path)?;
+		}
+		self.add_config_entry("ep.context_embed_mode", if embed { "1" } else { "0" })?;
+		Ok(self)
+	}
+
+	/// Controls whether nodes not supported by any of the registered execution providers may fall back to the CPU
+	/// execution provider (which is the default).
+	///
+	/// With fallback disabled, creating the session fails with an error if any node would be assigned to the CPU, so an
+	/// accelerated session either runs entirely on its execution providers or not at all, rather than silently copying
+	/// data back and forth to run some operators on the CPU. The session will also fail to build if none of its
+	/// execution providers could be registered, since then every node would run on the CPU.
+	///
+	/// ```no_run
+	/// # use ort::{CUDAExecutionProvider, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?
+	/// 	.with_execution_providers([CUDAExecutionProvider::default().build()])?
+	/// 	.with_cpu_ep_fallback(false)?
+	/// 	.with_model_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_cpu_ep_fallback(mut self, enable: bool) -> Result<Self> {
+		self.add_config_entry("session.disable_cpu_ep_fallback", if enable { "0" } else { "1" })?;
+		Ok(self)
+	}
+
+	/// Sets the format of the model the session will load.
+	///
+	/// By default, ONNX Runtime detects the format from the file extension when loading from a file (`.ort` files are
+	/// loaded as [`ModelFileFormat::Ort`], anything else as [`ModelFileFormat::Onnx`]), and from the model's header when
+	/// loading from memory. This only needs to be set if a model in the ORT format has a different file extension.
+	pub fn with_model_format(mut self, format: ModelFileFormat) -> Result<Self> {
+		self.add_config_entry("session.load_model_format", format.as_config_value())?;
+		Ok(self)
+	}
+
+	/// Makes the session use the allocators shared by its environment (registered with
+	/// [`EnvironmentBuilder::with_shared_allocator`](crate::EnvironmentBuilder::with_shared_allocator)) instead of
+	/// creating its own.
+	pub fn with_use_env_allocators(mut self) -> Result<Self> {
+		self.add_config_entry("session.use_env_allocators", "1")?;
+		Ok(self)
+	}
+
+	/// Caps the CPU memory arena at `bytes` bytes, so that models which need more memory fail instead of the process
+	/// growing unbounded (and e.g. being OOM-killed in a memory-constrained container).
+	///
+	/// When the limit is exceeded, the allocation fails and the error is surfaced by ONNX Runtime; typically from
+	/// [`Session::run`], or when the session is created if the model's initializers alone don't fit. Note that inputs
+	/// allocated by `ort` (e.g. via [`Value::from_array`]) are not counted against the limit.
+	///
+	/// ONNX Runtime can only limit an arena which is shared via the environment, so this registers a CPU arena allocator
+	/// with the session's [`Environment`] and enables [`SessionBuilder::with_use_env_allocators`]. The limit therefore
+	/// applies to all sessions sharing the environment's allocators *combined*. Only one limit may be registered per
+	/// environment; building a session with a different limit in the same environment fails with
+	/// [`Error::CpuMemLimitConflict`], and so does building one in an environment with a CPU allocator registered via
+	/// [`EnvironmentBuilder::with_shared_allocator`](crate::EnvironmentBuilder::with_shared_allocator) (unless that
+	/// allocator's [`ArenaConfig::max_mem`](crate::ArenaConfig::max_mem) is the same limit).
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_cpu_mem_limit(512 * 1024 * 1024)?.with_model_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_cpu_mem_limit(mut self, bytes: usize) -> Result<Self> {
+		self.cpu_mem_limit = Some(bytes);
+		self.with_use_env_allocators()
+	}
+
+	pub(crate) fn add_config_entry(&mut self, key: &str, value: &str) -> Result<()> {
+		let key_cstr = CString::new(key)?;
+		let value_cstr = CString::new(value)?;
+		ortsys![unsafe AddSessionConfigEntry(self.session_options_ptr, key_cstr.as_ptr(), value_cstr.as_ptr()) -> Error::CreateSessionOptions];
+		self.config.set_config_entry(key, value);
+		Ok(())
+	}
+
+	/// Applies the options in `config`, i.e. loaded from a configuration file with the `serde` feature enabled. Options
+	/// left unset in `config` keep their current values; see [`SessionConfig`].
+	///
+	/// ```no_run
+	/// # use ort::{ExecutionProviderConfig, GraphOptimizationLevel, Session, SessionConfig};
+	/// # fn main() -> ort::Result<()> {
+	/// let config = SessionConfig {
+	/// 	optimization_level: Some(GraphOptimizationLevel::Level2),
+	/// 	intra_threads: Some(4),
+	/// 	execution_providers: vec![ExecutionProviderConfig::CPU { arena_allocator: true }],
+	/// 	..SessionConfig::default()
+	/// };
+	/// let session = Session::builder()?.apply_config(&config)?.with_model_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn apply_config(mut self, config: &SessionConfig) -> Result<Self> {
+		if let Some(optimization_level) = config.optimization_level {
+			self = self.with_optimization_level(optimization_level)?;
+		}
+		if let Some(execution_mode) = config.execution_mode {
+			self = self.with_execution_mode(execution_mode)?;
+		}
+		if let Some(intra_threads) = config.intra_threads {
+			self = self.with_intra_threads(intra_threads)?;
+		}
+		if let Some(inter_threads) = config.inter_threads {
+			self = self.with_inter_threads(inter_threads)?;
+		}
+		if let Some(memory_pattern) = config.memory_pattern {
+			self = self.with_memory_pattern(memory_pattern)?;
+		}
+		if !config.execution_providers.is_empty() {
+			let execution_providers = config.execution_providers.iter().map(ExecutionProviderConfig::build).collect::<Result<Vec<_>>>()?;
+			self = self.with_execution_providers(execution_providers)?;
+		}
+		for (key, value) in &config.config_entries {
+			self.add_config_entry(key, value)?;
+		}
+		Ok(self)
+	}
+
+	/// Returns a summary of the configuration applied to this builder so far, i.e. for debugging why a session is
+	/// slower than expected or produces different results than another. See [`SessionConfigSummary`] for which options
+	/// are reported.
+	pub fn describe(&self) -> SessionConfigSummary {
+		let mut summary = self.config.clone();
+		summary.set_execution_providers(&self.execution_providers);
+		summary
+	}
+
 	/// Set the session's allocator options from a [`MemoryInfo`].
 	///
 	/// If not provided, the session is created using ONNX Runtime's default device allocator.
 	pub fn with_allocator(mut self, info: MemoryInfo) -> Result<Self> {
-		self.memory_info = Some(Rc::new(info));
+		self.memory_info = Some(Arc::new(info));
 		Ok(self)
 	}
 
@@ -272,11 +752,49 @@ impl SessionBuilder {
 			return Err(e);
 		}
 
-		self.custom_runtime_handles.push(handle);
+		self.custom_runtime_handles.push(Arc::new(CustomOpsLibHandle(handle)));
 
 		Ok(self)
 	}
 
+	/// Registers a domain of custom operators in the session.
+	///
+	/// The domain is kept alive for as long as this builder or any session created from it.
+	///
+	/// # Safety
+	/// Each pointer in `ops` must point to a valid [`ort_sys::OrtCustomOp`] that outlives this builder and any session
+	/// created from it.
+	#[cfg(feature = "custom-ops")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "custom-ops")))]
+	pub unsafe fn with_custom_op_domain(mut self, domain: impl AsRef<str>, ops: &[*const ort_sys::OrtCustomOp]) -> Result<Self> {
+		let domain_cstr = CString::new(domain.as_ref())?;
+
+		let mut domain_ptr: *mut ort_sys::OrtCustomOpDomain = std::ptr::null_mut();
+		ortsys![unsafe CreateCustomOpDomain(domain_cstr.as_ptr(), &mut domain_ptr) -> Error::CreateCustomOpDomain; nonNull(domain_ptr)];
+		// wrap immediately so the domain is released if adding an op fails
+		let custom_op_domain = Arc::new(CustomOpDomain { ptr: domain_ptr });
+		for &op in ops {
+			ortsys![unsafe CustomOpDomain_Add(domain_ptr, op) -> Error::CreateCustomOpDomain];
+		}
+		ortsys![unsafe AddCustomOpDomain(self.session_options_ptr, domain_ptr) -> Error::AddCustomOpDomain];
+
+		self.custom_op_domains.push(custom_op_domain);
+		self.custom_op_domain_names.push(domain.as_ref().to_owned());
+
+		Ok(self)
+	}
+
+	/// Returns the names of the custom operator domains registered on this builder via
+	/// [`SessionBuilder::with_custom_op_domain`], in the order they were registered.
+	///
+	/// Domains registered by libraries loaded with [`SessionBuilder::with_custom_ops_lib`] or
+	/// [`SessionBuilder::with_enable_custom_ops`] are not included, since ONNX Runtime does not expose them.
+	#[cfg(feature = "custom-ops")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "custom-ops")))]
+	pub fn custom_op_domains(&self) -> &[String] {
+		&self.custom_op_domain_names
+	}
+
 	/// Enable custom operators. See onnxruntime-extensions: https://github.com/microsoft/onnxruntime-extensions
 	#[cfg(feature = "custom-ops")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "custom-ops")))]
@@ -377,12 +895,70 @@ impl SessionBuilder {
 	// TODO: Add all functions changing the options.
 	//       See all OrtApi methods taking a `options: *mut OrtSessionOptions`.
 
+	/// Checks whether every node of the model at `model_path` would be placed on the execution provider `ep` (e.g.
+	/// `"CUDAExecutionProvider"`) with this builder's configuration, rather than (partially) falling back to another
+	/// execution provider such as the CPU. This is intended as a preflight check before deployment.
+	///
+	/// This performs a trial build of the session with verbose logging, and inspects the node placements ONNX Runtime
+	/// logs; the builder itself is left untouched, and the trial session is dropped immediately. Since it builds the
+	/// session, this is as expensive as [`SessionBuilder::with_model_from_file`].
+	///
+	/// ```no_run
+	/// # use ort::{CUDAExecutionProvider, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let builder = Session::builder()?.with_execution_providers([CUDAExecutionProvider::default().build()])?;
+	/// if !builder.check_full_ep_coverage("model.onnx", "CUDAExecutionProvider")? {
+	/// 	eprintln!("warning: parts of the model will run on the CPU");
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn check_full_ep_coverage(&self, model_path: impl AsRef<Path>, ep: &str) -> Result<bool> {
+		static TRIAL_BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+		// a unique log ID, so that only the trial session's logs are captured
+		let log_id = format!("ort-ep-coverage-{}", TRIAL_BUILDS.fetch_add(1, Ordering::Relaxed));
+		let log_id_cstr = CString::new(log_id.as_str())?;
+		let builder = self.clone().with_log_severity_level(LogSeverity::Verbose)?;
+		ortsys![unsafe SetSessionLogId(builder.session_options_ptr, log_id_cstr.as_ptr()) -> Error::CreateSessionOptions];
+
+		let capture = LogCapture::new(log_id);
+		drop(builder.with_model_from_file(model_path)?);
+		full_ep_coverage(&capture.messages(), ep).ok_or(Error::NodePlacementsNotLogged)
+	}
+
 	/// Loads an ONNX model from a file and builds the session.
-	pub fn with_model_from_file<P>(self, model_filepath_ref: P) -> Result<Session>
+	///
+	/// The time ONNX Runtime spends parsing & optimizing the model is covered by a `build` [`tracing`] span, which records
+	/// the size of the model in `model_bytes` along with the `optimization_level`.
+	pub fn with_model_from_file<P>(mut self, model_filepath_ref: P) -> Result<Session>
 	where
 		P: AsRef<Path>
 	{
 		let model_filepath = model_filepath_ref.as_ref();
+
+		let env = self.environment()?;
+		self.apply_metadata_overrides(&env, |builder| builder.with_model_from_file(model_filepath))?;
+		self.apply_execution_providers(&env)?;
+		if let Some(bytes) = self.cpu_mem_limit {
+			env.register_cpu_arena(bytes)?;
+		}
+
+		let builder = Arc::new(self);
+		let session_ptr = builder.create_session_from_file(&env, model_filepath)?;
+		builder.wrap_session(session_ptr, env, GraphStatsSource::from_file(model_filepath), None)
+	}
+
+	/// Registers the builder's execution providers, followed by the environment's, with the session options.
+	fn apply_execution_providers(&mut self, env: &Environment) -> Result<()> {
+		let registrations = apply_execution_providers(self, self.execution_providers.iter().chain(&env.execution_providers).cloned())?;
+		self.registered_execution_providers = registrations.into_iter().filter(|r| r.registered).map(|r| r.name).collect();
+		Ok(())
+	}
+
+	/// Creates an [`ort_sys::OrtSession`] from the model at `model_filepath` using this builder's options as-is; execution
+	/// providers must have already been applied.
+	fn create_session_from_file(&self, env: &Environment, model_filepath: &Path) -> Result<*mut ort_sys::OrtSession> {
 		if !model_filepath.exists() {
 			return Err(Error::FileDoesNotExist {
 				filename: model_filepath.to_path_buf()
@@ -404,41 +980,75 @@ impl SessionBuilder {
             .map(|b| *b as std::os::raw::c_char)
             .collect();
 
-		let env = get_environment()?;
-		apply_execution_providers(&self, self.execution_providers.iter().chain(&env.execution_providers).cloned());
-
 		let env_ptr = env.env_ptr.load(Ordering::Relaxed);
 
-		let mut session_ptr: *mut ort_sys::OrtSession = std::ptr::null_mut();
-		ortsys![unsafe CreateSession(env_ptr, model_path.as_ptr(), self.session_options_ptr, &mut session_ptr) -> Error::CreateSession; nonNull(session_ptr)];
+		let model_bytes = std::fs::metadata(model_filepath).map(|m| m.len()).unwrap_or_default();
+		self.traced_build(model_bytes, || {
+			let mut session_ptr: *mut ort_sys::OrtSession = std::ptr::null_mut();
+			ortsys![
+				unsafe CreateSession(env_ptr, model_path.as_ptr(), self.session_options_ptr, &mut session_ptr) -> create_session_error;
+				nonNull(session_ptr)
+			];
+			Ok(session_ptr)
+		})
+	}
 
-		let allocator = match &self.memory_info {
-			Some(info) => {
-				let mut allocator_ptr: *mut ort_sys::OrtAllocator = std::ptr::null_mut();
-				ortsys![unsafe CreateAllocator(session_ptr, info.ptr, &mut allocator_ptr) -> Error::CreateAllocator; nonNull(allocator_ptr)];
-				Allocator::from_raw(allocator_ptr)
-			}
-			None => Allocator::default()
+	/// Calls `create`, which should create the session, inside a `build` span recording the size of the model and the
+	/// optimization level, so the time ONNX Runtime spends parsing & optimizing the model can be attributed. An event
+	/// with the elapsed time is emitted once `create` returns.
+	fn traced_build<T>(&self, model_bytes: u64, create: impl FnOnce() -> Result<T>) -> Result<T> {
+		let _span = tracing::info_span!("build", model_bytes, optimization_level = ?self.config.optimization_level).entered();
+		let start = Instant::now();
+		let result = create();
+		let elapsed = start.elapsed();
+		match &result {
+			Ok(_) => tracing::info!(?elapsed, "Created session in {elapsed:.2?}"),
+			Err(e) => tracing::info!(?elapsed, "Failed to create session after {elapsed:.2?}: {e}")
+		}
+		result
+	}
+
+	/// Wraps a newly created `session_ptr` in a [`Session`], which keeps the builder alive so the session can later be
+	/// reloaded with the same options.
+	fn wrap_session(
+		self: &Arc<Self>,
+		session_ptr: *mut ort_sys::OrtSession,
+		env: Arc<Environment>,
+		graph_stats: GraphStatsSource,
+		model_bytes: Option<Arc<[u8]>>
+	) -> Result<Session> {
+		// wrap immediately so the session is released if anything below fails
+		let mut inner = SharedSessionInner {
+			session_ptr,
+			allocator: Allocator::default(),
+			owns_session: true,
+			#[cfg(feature = "custom-ops")]
+			_custom_op_domains: self.custom_op_domains.clone(),
+			graph_stats,
+			_model_bytes: model_bytes,
+			_environment: env
 		};
+		if let Some(info) = &self.memory_info {
+			let mut allocator_ptr: *mut ort_sys::OrtAllocator = std::ptr::null_mut();
+			ortsys![unsafe CreateAllocator(session_ptr, info.ptr, &mut allocator_ptr) -> Error::CreateAllocator; nonNull(allocator_ptr)];
+			inner.allocator = Allocator::from_raw(allocator_ptr);
+		}
 
 		// Extract input and output properties
 		let num_input_nodes = dangerous::extract_inputs_count(session_ptr)?;
 		let num_output_nodes = dangerous::extract_outputs_count(session_ptr)?;
 		let inputs = (0..num_input_nodes)
-			.map(|i| dangerous::extract_input(session_ptr, allocator.ptr, i))
+			.map(|i| dangerous::extract_input(session_ptr, inner.allocator.ptr, i))
 			.collect::<Result<Vec<Input>>>()?;
 		let outputs = (0..num_output_nodes)
-			.map(|i| dangerous::extract_output(session_ptr, allocator.ptr, i))
+			.map(|i| dangerous::extract_output(session_ptr, inner.allocator.ptr, i))
 			.collect::<Result<Vec<Output>>>()?;
 
 		Ok(Session {
-			inner: Arc::new(SharedSessionInner {
-				session_ptr,
-				allocator,
-				_environment: Arc::clone(env)
-			}),
+			inner: Arc::new(inner),
 			inputs,
-			outputs
+			outputs,
+			builder: Some(Arc::clone(self))
 		})
 	}
 
@@ -460,55 +1070,129 @@ impl SessionBuilder {
 		ortsys![unsafe AddSessionConfigEntry(self.session_options_ptr, str_to_char("session.use_ort_model_bytes_directly").as_ptr(), str_to_char("1").as_ptr())];
 		ortsys![unsafe AddSessionConfigEntry(self.session_options_ptr, str_to_char("session.use_ort_model_bytes_for_initializers").as_ptr(), str_to_char("1").as_ptr())];
 
-		let session = self.with_model_from_memory(model_bytes)?;
+		// the returned session borrows `model_bytes`, so they don't need to be retained
+		let session = self.commit_from_memory(model_bytes, false)?;
 
 		Ok(InMemorySession { session, phantom: PhantomData })
 	}
 
 	/// Load an ONNX graph from memory and commit the session.
+	///
+	/// If [`SessionBuilder::with_model_bytes_directly`] is enabled, `model_bytes` are copied into a buffer owned by the
+	/// session, since ONNX Runtime references them for the lifetime of the session. Use
+	/// [`SessionBuilder::with_model_from_memory_directly`] to borrow the bytes instead.
 	pub fn with_model_from_memory(self, model_bytes: &[u8]) -> Result<Session> {
-		let mut session_ptr: *mut ort_sys::OrtSession = std::ptr::null_mut();
+		let retain_bytes = self.config.config_entries.iter().any(|(k, v)| k == "session.use_ort_model_bytes_directly" && v == "1");
+		self.commit_from_memory(model_bytes, retain_bytes)
+	}
 
-		let env = get_environment()?;
-		apply_execution_providers(&self, self.execution_providers.iter().chain(&env.execution_providers).cloned());
+	fn commit_from_memory(mut self, model_bytes: &[u8], retain_bytes: bool) -> Result<Session> {
+		let retained_bytes: Option<Arc<[u8]>> = if retain_bytes { Some(model_bytes.into()) } else { None };
+		// graph statistics are computed on demand, so they need their own reference to the model bytes
+		let graph_stats = GraphStatsSource::from_memory(retained_bytes.clone().unwrap_or_else(|| model_bytes.into()));
+		let model_bytes = retained_bytes.as_deref().unwrap_or(model_bytes);
+
+		let env = self.environment()?;
+		self.apply_metadata_overrides(&env, |builder| builder.with_model_from_memory(model_bytes))?;
+		self.apply_execution_providers(&env)?;
+		if let Some(bytes) = self.cpu_mem_limit {
+			env.register_cpu_arena(bytes)?;
+		}
 
 		let env_ptr = env.env_ptr.load(Ordering::Relaxed);
 
 		let model_data = model_bytes.as_ptr() as *const std::ffi::c_void;
 		let model_data_length = model_bytes.len();
-		ortsys![
-			unsafe CreateSessionFromArray(env_ptr, model_data, model_data_length as _, self.session_options_ptr, &mut session_ptr) -> Error::CreateSession;
-			nonNull(session_ptr)
-		];
+		let session_ptr = self.traced_build(model_data_length as u64, || {
+			let mut session_ptr: *mut ort_sys::OrtSession = std::ptr::null_mut();
+			ortsys![
+				unsafe CreateSessionFromArray(env_ptr, model_data, model_data_length as _, self.session_options_ptr, &mut session_ptr) -> create_session_error;
+				nonNull(session_ptr)
+			];
+			Ok(session_ptr)
+		})?;
+
+		Arc::new(self).wrap_session(session_ptr, env, graph_stats, retained_bytes)
+	}
 
-		let allocator = match &self.memory_info {
-			Some(info) => {
-				let mut allocator_ptr: *mut ort_sys::OrtAllocator = std::ptr::null_mut();
-				ortsys![unsafe CreateAllocator(session_ptr, info.ptr, &mut allocator_ptr) -> Error::CreateAllocator; nonNull(allocator_ptr)];
-				Allocator::from_raw(allocator_ptr)
-			}
-			None => Allocator::default()
-		};
+	/// Load an ONNX graph from a reader, such as a stream from an object store or a compressed archive, and commit the
+	/// session. The builder is not consumed, so it can be used to commit more sessions afterwards.
+	///
+	/// The reader is read to completion and the whole model is buffered in memory before being passed to
+	/// [`SessionBuilder::with_model_from_memory`], so loading a model this way temporarily requires (at least) enough
+	/// memory to hold both the serialized model and the session created from it. Prefer
+	/// [`SessionBuilder::with_model_from_file`] for models that are already on disk.
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let file = std::fs::File::open("model.onnx")?;
+	/// let session = Session::builder()?.commit_from_reader(std::io::BufReader::new(file))?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn commit_from_reader(&self, mut reader: impl Read) -> Result<Session> {
+		let mut model_bytes = Vec::new();
+		reader.read_to_end(&mut model_bytes).map_err(Error::ReadModel)?;
+		self.clone().with_model_from_memory(&model_bytes)
+	}
 
-		// Extract input and output properties
-		let num_input_nodes = dangerous::extract_inputs_count(session_ptr)?;
-		let num_output_nodes = dangerous::extract_outputs_count(session_ptr)?;
-		let inputs = (0..num_input_nodes)
-			.map(|i| dangerous::extract_input(session_ptr, allocator.ptr, i))
-			.collect::<Result<Vec<Input>>>()?;
-		let outputs = (0..num_output_nodes)
-			.map(|i| dangerous::extract_output(session_ptr, allocator.ptr, i))
-			.collect::<Result<Vec<Output>>>()?;
+	/// Loads an ONNX model from a file, builds the session, and runs a single warmup inference with zero-filled inputs
+	/// before returning it. The builder is not consumed, so the same configuration can be used to warm up several models.
+	///
+	/// Some execution providers do expensive work on the first run, e.g. TensorRT builds its engines. Combined with
+	/// TensorRT's engine cache ([`TensorRTExecutionProvider::with_engine_cache`](crate::TensorRTExecutionProvider::with_engine_cache)),
+	/// this can be used to build & persist engines at deploy time rather than when the first request is served.
+	///
+	/// `sample_shapes` contains the shape of each of the model's inputs, in the order they are declared in the graph
+	/// (see [`Session::inputs`]). Only tensor inputs are supported; string inputs are filled with empty strings.
+	///
+	/// ```no_run
+	/// # use ort::{Session, TensorRTExecutionProvider};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?
+	/// 	.with_execution_providers([TensorRTExecutionProvider::default()
+	/// 		.with_engine_cache(true)
+	/// 		.with_engine_cache_path("/var/cache/trt")
+	/// 		.build()])?
+	/// 	.commit_and_warmup("model.onnx", &[&[1, 3, 224, 224]])?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn commit_and_warmup<P>(&self, model_filepath: P, sample_shapes: &[&[i64]]) -> Result<Session>
+	where
+		P: AsRef<Path>
+	{
+		// clones share `self`'s custom op library handles, so dropping the clone (or the session) leaves them open
+		let session = self.clone().with_model_from_file(model_filepath)?;
+		if sample_shapes.len() != session.inputs.len() {
+			return Err(Error::WarmupShapeCount {
+				expected: session.inputs.len(),
+				actual: sample_shapes.len()
+			});
+		}
 
-		let session = Session {
-			inner: Arc::new(SharedSessionInner {
-				session_ptr,
-				allocator,
-				_environment: Arc::clone(env)
-			}),
-			inputs,
-			outputs
-		};
+		let inputs = session
+			.inputs
+			.iter()
+			.zip(sample_shapes)
+			.map(|(input, shape)| {
+				let ty = match &input.input_type {
+					ValueType::Tensor { ty, .. } => *ty,
+					t => return Err(Error::NotTensor(t.clone()))
+				};
+				// all zeroes is a valid encoding for every element type; for strings, it encodes empty strings
+				let element_size = ty.byte_size().unwrap_or(std::mem::size_of::<u64>());
+				let byte_len = dims_to_usize(shape)?
+					.into_iter()
+					.try_fold(element_size, |acc, dim| acc.checked_mul(dim))
+					.ok_or_else(|| Error::ShapeTooLarge(shape.to_vec()))?;
+				Value::from_bytes(ty, shape, &vec![0; byte_len])
+			})
+			.collect::<Result<Vec<_>>>()?;
+		let start = Instant::now();
+		session.run(&inputs[..])?;
+		tracing::info!("Warmup run completed in {:.2?}", start.elapsed());
 		Ok(session)
 	}
 }
@@ -519,6 +1203,15 @@ impl SessionBuilder {
 pub struct SharedSessionInner {
 	pub(crate) session_ptr: *mut ort_sys::OrtSession,
 	allocator: Allocator,
+	/// Whether the session pointer should be released when this struct is dropped; `false` for sessions borrowed via
+	/// [`Session::from_raw`].
+	owns_session: bool,
+	#[cfg(feature = "custom-ops")]
+	_custom_op_domains: Vec<Arc<CustomOpDomain>>,
+	graph_stats: GraphStatsSource,
+	/// The model bytes the session was created from, if ONNX Runtime references them directly; see
+	/// [`SessionBuilder::with_model_bytes_directly`]. Dropped after the session is released.
+	_model_bytes: Option<Arc<[u8]>>,
 	_environment: Arc<Environment>
 }
 
@@ -529,7 +1222,7 @@ impl Drop for SharedSessionInner {
 	#[tracing::instrument]
 	fn drop(&mut self) {
 		tracing::debug!("dropping SharedSessionInner");
-		if !self.session_ptr.is_null() {
+		if !self.session_ptr.is_null() && self.owns_session {
 			tracing::debug!("dropping session ptr");
 			ortsys![unsafe ReleaseSession(self.session_ptr)];
 		}
@@ -537,6 +1230,27 @@ impl Drop for SharedSessionInner {
 	}
 }
 
+/// An owned [`ort_sys::OrtCustomOpDomain`], shared between a [`SessionBuilder`] and the sessions created from it since
+/// ONNX Runtime requires the domain to outlive any session using it.
+#[cfg(feature = "custom-ops")]
+#[derive(Debug)]
+struct CustomOpDomain {
+	ptr: *mut ort_sys::OrtCustomOpDomain
+}
+
+// the domain is never mutated after it is added to the session options
+#[cfg(feature = "custom-ops")]
+unsafe impl Send for CustomOpDomain {}
+#[cfg(feature = "custom-ops")]
+unsafe impl Sync for CustomOpDomain {}
+
+#[cfg(feature = "custom-ops")]
+impl Drop for CustomOpDomain {
+	fn drop(&mut self) {
+		ortsys![unsafe ReleaseCustomOpDomain(self.ptr)];
+	}
+}
+
 /// Type storing the session information, built from an [`Environment`](crate::environment::Environment)
 #[derive(Debug)]
 pub struct Session {
@@ -544,7 +1258,10 @@ pub struct Session {
 	/// Information about the ONNX's inputs as stored in loaded file
 	pub inputs: Vec<Input>,
 	/// Information about the ONNX's outputs as stored in loaded file
-	pub outputs: Vec<Output>
+	pub outputs: Vec<Output>,
+	/// The builder the session was created from, used to reload the session with the same options. `None` for sessions
+	/// created via [`Session::from_raw`].
+	builder: Option<Arc<SessionBuilder>>
 }
 
 /// A [`Session`] with data stored in-memory.
@@ -561,7 +1278,7 @@ impl<'s> Deref for InMemorySession<'s> {
 }
 
 /// Information about an ONNX's input as stored in loaded file
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Input {
 	/// Name of the input layer
 	pub name: String,
@@ -570,7 +1287,7 @@ pub struct Input {
 }
 
 /// Information about an ONNX's output as stored in loaded file
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Output {
 	/// Name of the output layer
 	pub name: String,
@@ -623,11 +1340,151 @@ impl Session {
 		SessionBuilder::new()
 	}
 
+	/// Wraps an [`ort_sys::OrtSession`] that was created outside of `ort`, i.e. by a C++ component in the same process.
+	///
+	/// If `owns` is `true`, the session is adopted and will be released when the returned [`Session`] (and all values
+	/// it produced) are dropped. Otherwise, the session is only borrowed, and the caller remains responsible for
+	/// releasing it.
+	///
+	/// # Safety
+	///
+	/// - `ptr` must be a valid, non-null pointer to an `OrtSession`.
+	/// - The session must have been created with the same ONNX Runtime library `ort` is using, with an API version
+	///   compatible with [`ort_sys::ORT_API_VERSION`].
+	/// - If `owns` is `false`, the session must outlive the returned [`Session`] and all values it produced.
+	/// - If `owns` is `true`, the session must not be released by anything else.
+	pub unsafe fn from_raw(ptr: *mut ort_sys::OrtSession, owns: bool) -> Result<Session> {
+		assert_non_null_pointer(ptr, "OrtSession")?;
+
+		let allocator = Allocator::default();
+		let num_input_nodes = dangerous::extract_inputs_count(ptr)?;
+		let num_output_nodes = dangerous::extract_outputs_count(ptr)?;
+		let inputs = (0..num_input_nodes)
+			.map(|i| dangerous::extract_input(ptr, allocator.ptr, i))
+			.collect::<Result<Vec<Input>>>()?;
+		let outputs = (0..num_output_nodes)
+			.map(|i| dangerous::extract_output(ptr, allocator.ptr, i))
+			.collect::<Result<Vec<Output>>>()?;
+
+		Ok(Session {
+			inner: Arc::new(SharedSessionInner {
+				session_ptr: ptr,
+				allocator,
+				owns_session: owns,
+				#[cfg(feature = "custom-ops")]
+				_custom_op_domains: Vec::new(),
+				graph_stats: GraphStatsSource::Unavailable,
+				_model_bytes: None,
+				_environment: Arc::clone(get_environment()?)
+			}),
+			inputs,
+			outputs,
+			builder: None
+		})
+	}
+
+	/// Creates a new handle to this session that shares the same underlying ONNX Runtime session, so the model is not
+	/// loaded again. ONNX Runtime sessions are thread-safe, so clones can be freely sent to and run on other threads.
+	///
+	/// The underlying session is only released once the last handle (the original or any clone) is dropped.
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_model_from_file("model.onnx")?;
+	/// let clone = session.try_clone()?;
+	/// std::thread::spawn(move || {
+	/// 	// ... run `clone` ...
+	/// });
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn try_clone(&self) -> Result<Session> {
+		Ok(Session {
+			inner: Arc::clone(&self.inner),
+			inputs: self.inputs.clone(),
+			outputs: self.outputs.clone(),
+			builder: self.builder.clone()
+		})
+	}
+
+	/// Replaces the session's model with the one at `model_filepath`, reusing the same environment and session options
+	/// (including execution providers and configuration entries) the session was originally built with. This is useful
+	/// for hot-reloading a model during development without reconfiguring everything.
+	///
+	/// The new model does not need to have the same inputs and outputs as the old one; [`Session::inputs`] and
+	/// [`Session::outputs`] are updated to match it. Per-session thread pools are recreated along with the underlying
+	/// ONNX Runtime session. Other handles created with [`Session::try_clone`] and values produced by the old model are
+	/// unaffected. If loading the new model fails, the session is left unchanged.
+	///
+	/// Sessions created via [`Session::from_raw`] cannot be reloaded, since their options are unknown.
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> ort::Result<()> {
+	/// let mut session = Session::builder()?.with_intra_threads(4)?.with_model_from_file("model.onnx")?;
+	/// // ... `model.onnx` is replaced on disk ...
+	/// session.reload_from_file("model.onnx")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn reload_from_file(&mut self, model_filepath: impl AsRef<Path>) -> Result<()> {
+		let builder = self.builder.as_ref().ok_or(Error::ReloadUnavailable)?;
+		let model_filepath = model_filepath.as_ref();
+
+		let env = Arc::clone(&self.inner._environment);
+		let session_ptr = builder.create_session_from_file(&env, model_filepath)?;
+		*self = builder.wrap_session(session_ptr, env, GraphStatsSource::from_file(model_filepath), None)?;
+		Ok(())
+	}
+
+	/// Returns the names of the execution providers that were successfully registered for this session, in the order
+	/// they were registered. This includes execution providers configured on the session's environment (see
+	/// [`EnvironmentBuilder::with_execution_providers`](crate::EnvironmentBuilder::with_execution_providers)).
+	///
+	/// ONNX Runtime always falls back to the CPU execution provider for nodes other execution providers can't handle,
+	/// so it is not listed unless it was configured explicitly. Sessions created via [`Session::from_raw`] report no
+	/// execution providers, since they were registered outside of `ort`.
+	pub fn registered_execution_providers(&self) -> &[&'static str] {
+		self.builder.as_ref().map_or(&[], |builder| &builder.registered_execution_providers)
+	}
+
+	/// Returns the underlying [`ort_sys::OrtSession`] pointer. The pointer remains valid for as long as this session is
+	/// alive.
+	pub fn ptr(&self) -> *mut ort_sys::OrtSession {
+		self.inner.session_ptr
+	}
+
 	/// Returns this session's [`Allocator`].
 	pub fn allocator(&self) -> &Allocator {
 		&self.inner.allocator
 	}
 
+	/// Returns the names of the model's inputs, in the order they are declared in the graph.
+	///
+	/// The names are borrowed from [`Session::inputs`], so they remain valid (and point to the same data across calls)
+	/// for as long as the session is alive.
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_model_from_file("model.onnx")?;
+	/// let [image] = session.input_names()[..] else { panic!("expected a single input") };
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn input_names(&self) -> Vec<&str> {
+		self.inputs.iter().map(|input| input.name.as_str()).collect()
+	}
+
+	/// Returns the names of the model's outputs, in the order they are declared in the graph.
+	///
+	/// Like [`Session::input_names`], the names are borrowed from [`Session::outputs`] and remain valid for as long as
+	/// the session is alive.
+	pub fn output_names(&self) -> Vec<&str> {
+		self.outputs.iter().map(|output| output.name.as_str()).collect()
+	}
+
 	/// Creates a new [`IoBinding`] for this session.
 	pub fn create_binding(&self) -> Result<IoBinding> {
 		IoBinding::new(self)
@@ -638,9 +1495,10 @@ impl Session {
 		Arc::clone(&self.inner)
 	}
 
-	/// Run the input data through the ONNX graph, performing inference.
-	pub fn run<'s, 'i, const N: usize>(&'s self, input_values: impl Into<SessionInputs<'i, N>>) -> Result<SessionOutputs<'s>> {
-		match input_values.into() {
+	/// Run the input data through the ONNX graph, performing inference. See [`IntoInputs`] for the types that can be
+	/// used as inputs.
+	pub fn run<'s, 'i, const N: usize>(&'s self, input_values: impl IntoInputs<'i, N>) -> Result<SessionOutputs<'s>> {
+		match input_values.into_inputs()? {
 			SessionInputs::ValueSlice(input_values) => {
 				let outputs = self.run_inner(
 					&self
@@ -672,13 +1530,170 @@ impl Session {
 		}
 	}
 
+	/// Runs the model with `inputs` given by position, in the order the model's inputs are declared (see
+	/// [`Session::input_names`]), rather than by name. This is useful for models with awkward or numeric input names.
+	///
+	/// Returns [`Error::PositionalInputCount`] if the number of inputs doesn't match the number of model inputs.
+	///
+	/// ```no_run
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_model_from_file("model.onnx")?;
+	/// let outputs = session.run_positional(vec![
+	/// 	Value::from_array((vec![3], &[1.0_f32, 2.0, 3.0][..]))?,
+	/// 	Value::from_array((vec![3], &[0.5_f32, 0.5, 0.5][..]))?
+	/// ])?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn run_positional(&self, inputs: Vec<Value>) -> Result<SessionOutputs<'_>> {
+		check_input_count(self.inputs.len(), inputs.len())?;
+		self.run(self.inputs.iter().map(|input| input.name.as_str()).zip(inputs).collect::<Vec<_>>())
+	}
+
+	/// Like [`Session::run`], but for sessions whose input shapes vary between runs, e.g. when batching inputs padded to
+	/// a handful of bucket sizes.
+	///
+	/// Memory pattern optimization plans allocations based on the input shapes of previous runs, so it must be disabled
+	/// via [`SessionBuilder::with_memory_pattern`] for such sessions; this returns [`Error::MemoryPatternEnabled`] if it
+	/// isn't. Sessions created with [`Session::from_raw`] aren't checked, since their options are unknown.
+	///
+	/// ```no_run
+	/// # use ort::{inputs, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_memory_pattern(false)?.with_model_from_file("model.onnx")?;
+	/// for bucket in [8, 16, 32] {
+	/// 	let input_ids = vec![0_i64; bucket];
+	/// 	let outputs = session.run_dynamic(inputs![(vec![1, bucket as i64], &input_ids[..])]?)?;
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn run_dynamic<'s, 'i, const N: usize>(&'s self, input_values: impl IntoInputs<'i, N>) -> Result<SessionOutputs<'s>> {
+		if self.builder.as_ref().is_some_and(|builder| builder.config.memory_pattern) {
+			return Err(Error::MemoryPatternEnabled);
+		}
+		self.run(input_values)
+	}
+
+	/// Runs the session and extracts the output named `output_name` into an owned [`ndarray::Array`]. This is a
+	/// shorthand for running the session, extracting the output with [`Value::extract_tensor`], and copying it into an
+	/// array of the requested dimensionality.
+	///
+	/// Returns an error if the model has no output named `output_name`, if the output's element type isn't `T`, or if
+	/// its shape doesn't match the dimensionality `D`; use [`ndarray::IxDyn`] to accept any shape.
+	///
+	/// ```no_run
+	/// # use ndarray::Array2;
+	/// # use ort::{inputs, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_model_from_file("model.onnx")?;
+	/// let logits: Array2<f32> = session.run_extract(inputs![(vec![1, 3], &[1_i64, 2, 3][..])]?, "logits")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn run_extract<'i, T, D, const N: usize>(&self, input_values: impl IntoInputs<'i, N>, output_name: &str) -> Result<ndarray::Array<T, D>>
+	where
+		T: crate::ExtractTensorData + Clone + fmt::Debug,
+		D: ndarray::Dimension
+	{
+		// check before running so a typo doesn't cost a whole inference
+		if !self.outputs.iter().any(|output| output.name == output_name) {
+			return Err(Error::UnknownOutputName(output_name.to_string()));
+		}
+
+		let outputs = self.run(input_values)?;
+		extract_array(&outputs[output_name])
+	}
+
+	/// Runs the session and returns the outputs named in `output_names`, in the same order. This is a shorthand for
+	/// running the session and removing each output from the [`SessionOutputs`] by name; the returned values keep the
+	/// session alive, and other outputs are dropped.
+	///
+	/// Returns an error if the model has no output with one of the names, or if a name is repeated.
+	///
+	/// ```no_run
+	/// # use ort::{inputs, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_model_from_file("detector.onnx")?;
+	/// let outputs = session.run_extract_many(inputs![(vec![1, 3], &[0.5_f32, 1.0, 1.5][..])]?, &["boxes", "scores"])?;
+	/// let (boxes, scores) = (&outputs[0], &outputs[1]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn run_extract_many<'i, const N: usize>(&self, input_values: impl IntoInputs<'i, N>, output_names: &[&str]) -> Result<Vec<Value>> {
+		for (i, &name) in output_names.iter().enumerate() {
+			if !self.outputs.iter().any(|output| output.name == name) {
+				return Err(Error::UnknownOutputName(name.to_string()));
+			}
+			if output_names[..i].contains(&name) {
+				return Err(Error::DuplicateOutputName(name.to_string()));
+			}
+		}
+
+		let mut outputs = self.run(input_values)?;
+		Ok(output_names.iter().map(|name| outputs.remove(name).expect("output names were checked")).collect())
+	}
+
+	/// Like [`Session::run_extract`], but extracts two outputs with (potentially) different element types &
+	/// dimensionalities, i.e. from a model with multiple heads.
+	///
+	/// ```no_run
+	/// # use ndarray::{Array1, Array2};
+	/// # use ort::{inputs, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_model_from_file("tagger.onnx")?;
+	/// let (logits, tags): (Array2<f32>, Array1<i64>) = session.run_extract2(inputs![(vec![1, 3], &[1_i64, 2, 3][..])]?, ["logits", "tags"])?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn run_extract2<'i, T1, D1, T2, D2, const N: usize>(
+		&self,
+		input_values: impl IntoInputs<'i, N>,
+		output_names: [&str; 2]
+	) -> Result<(ndarray::Array<T1, D1>, ndarray::Array<T2, D2>)>
+	where
+		T1: crate::ExtractTensorData + Clone + fmt::Debug,
+		D1: ndarray::Dimension,
+		T2: crate::ExtractTensorData + Clone + fmt::Debug,
+		D2: ndarray::Dimension
+	{
+		let outputs = self.run_extract_many(input_values, &output_names)?;
+		Ok((extract_array(&outputs[0])?, extract_array(&outputs[1])?))
+	}
+
+	/// Like [`Session::run_extract2`], but for three outputs.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	#[allow(clippy::type_complexity)]
+	pub fn run_extract3<'i, T1, D1, T2, D2, T3, D3, const N: usize>(
+		&self,
+		input_values: impl IntoInputs<'i, N>,
+		output_names: [&str; 3]
+	) -> Result<(ndarray::Array<T1, D1>, ndarray::Array<T2, D2>, ndarray::Array<T3, D3>)>
+	where
+		T1: crate::ExtractTensorData + Clone + fmt::Debug,
+		D1: ndarray::Dimension,
+		T2: crate::ExtractTensorData + Clone + fmt::Debug,
+		D2: ndarray::Dimension,
+		T3: crate::ExtractTensorData + Clone + fmt::Debug,
+		D3: ndarray::Dimension
+	{
+		let outputs = self.run_extract_many(input_values, &output_names)?;
+		Ok((extract_array(&outputs[0])?, extract_array(&outputs[1])?, extract_array(&outputs[2])?))
+	}
+
 	/// Run the input data through the ONNX graph, performing inference.
 	pub fn run_with_options<'s, 'i, const N: usize>(
 		&'s self,
-		input_values: impl Into<SessionInputs<'i, N>>,
+		input_values: impl IntoInputs<'i, N>,
 		run_options: Arc<RunOptions>
 	) -> Result<SessionOutputs<'s>> {
-		match input_values.into() {
+		match input_values.into_inputs()? {
 			SessionInputs::ValueSlice(input_values) => {
 				let outputs = self.run_inner(
 					&self
@@ -710,7 +1725,72 @@ impl Session {
 		}
 	}
 
+	/// Asynchronously runs the input data through the ONNX graph. Inference is performed on the session's intra-op
+	/// thread pool, so awaiting the returned [`RunFuture`] does not block the calling thread.
+	///
+	/// This works with any async runtime. With the `tokio` feature, [`Session::spawn_run`] can instead be used to run
+	/// the synchronous [`Session::run`] on tokio's blocking thread pool.
+	///
+	/// ONNX Runtime requires the session to have an intra-op thread pool with more than one thread for asynchronous
+	/// runs; see [`SessionBuilder::with_intra_threads`].
+	///
+	/// Since the run may outlive the returned future (e.g. if it is leaked with [`std::mem::forget`]), inputs must be
+	/// owned by the run; borrowed input slices must be `'static`.
+	///
+	/// ```no_run
+	/// # use ort::{inputs, Session};
+	/// # async fn run() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_intra_threads(2)?.with_model_from_file("model.onnx")?;
+	/// let outputs = session.run_async(inputs![(vec![3], &[1.0_f32, 2.0, 3.0][..])]?)?.await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn run_async<const N: usize>(&self, input_values: impl IntoInputs<'static, N>) -> Result<RunFuture<'_>> {
+		let input_names = || self.inputs.iter().map(|input| CString::new(input.name.as_str())).collect::<Result<Vec<_>, _>>();
+		let (input_names, inputs) = match input_values.into_inputs()? {
+			SessionInputs::ValueSlice(input_values) => (input_names()?, AsyncInputs::Borrowed(input_values)),
+			SessionInputs::ValueArray(input_values) => (input_names()?, AsyncInputs::Owned(input_values.into())),
+			SessionInputs::ValueMap(input_values) => {
+				let (input_names, values): (Vec<CompactString>, Vec<Value>) = input_values.into_iter().unzip();
+				let input_names = input_names.iter().map(|name| CString::new(name.as_str())).collect::<Result<Vec<_>, _>>()?;
+				(input_names, AsyncInputs::Owned(values))
+			}
+		};
+		RunFuture::new(self, input_names, inputs)
+	}
+
+	/// Runs the input data through the ONNX graph on tokio's blocking thread pool via
+	/// [`tokio::task::spawn_blocking`], returning the session's output values in the same order as
+	/// [`Session::outputs`].
+	///
+	/// Unlike [`Session::run_async`], this does not require an intra-op thread pool, but it does occupy one of tokio's
+	/// blocking threads for the duration of the run. This must be called from within a tokio runtime.
+	///
+	/// ```no_run
+	/// # use std::sync::Arc;
+	/// # use ort::{inputs, Session};
+	/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+	/// let session = Arc::new(Session::builder()?.with_model_from_file("model.onnx")?);
+	/// let outputs = session.spawn_run(inputs![(vec![3], &[1.0_f32, 2.0, 3.0][..])]?).await??;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "tokio")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+	pub fn spawn_run<const N: usize>(
+		self: &Arc<Self>,
+		input_values: impl IntoInputs<'static, N> + Send + 'static
+	) -> tokio::task::JoinHandle<Result<Vec<Value>>> {
+		let session = Arc::clone(self);
+		tokio::task::spawn_blocking(move || {
+			let mut outputs = session.run(input_values)?;
+			Ok(session.outputs.iter().filter_map(|output| outputs.remove(output.name.as_str())).collect())
+		})
+	}
+
 	fn run_inner(&self, input_names: &[CompactString], input_values: &[Value], run_options: Option<Arc<RunOptions>>) -> Result<SessionOutputs<'_>> {
+		check_input_count(input_names.len(), input_values.len())?;
+
 		let input_names_ptr: Vec<*const c_char> = input_names
 			.iter()
 			.map(|n| CString::new(n.as_bytes()).unwrap())
@@ -767,6 +1847,15 @@ impl Session {
 		Ok(SessionOutputs::new(self.outputs.iter().map(|o| o.name.as_str()), outputs))
 	}
 
+	/// Returns the statistics reported by the session's allocator, such as `Limit`, `InUse`, and `TotalAllocated`
+	/// (all in bytes), which can be used to gauge how much memory ONNX Runtime's arenas hold.
+	///
+	/// Allocator statistics are not exposed by the ONNX Runtime C API version `ort` currently binds to (v1.17), so
+	/// this always returns [`Error::Unsupported`] for now.
+	pub fn allocator_stats(&self) -> Result<std::collections::HashMap<String, i64>> {
+		Err(Error::Unsupported("allocator statistics require `AllocatorGetStats`, which is not available in the ONNX Runtime v1.17 API".to_string()))
+	}
+
 	/// Gets the session model metadata. See [`ModelMetadata`] for more info.
 	pub fn metadata(&self) -> Result<ModelMetadata> {
 		let mut metadata_ptr: *mut ort_sys::OrtModelMetadata = std::ptr::null_mut();
@@ -774,9 +1863,58 @@ impl Session {
 		Ok(ModelMetadata::new(metadata_ptr, self.inner.allocator.ptr))
 	}
 
+	/// Returns basic statistics about the model's graph: the number of nodes and initializers, and the total size of
+	/// the model's parameters.
+	///
+	/// ONNX Runtime does not provide APIs to introspect a loaded graph, so statistics are computed from the model file
+	/// itself; see [`GraphStats`] for what is and isn't counted. Note that:
+	/// - For sessions loaded from a file, the file is re-read each time this is called, so it must not have been moved
+	///   or deleted since the session was created.
+	/// - For sessions loaded from memory, the session keeps a copy of the model until this is first called, and the
+	///   statistics computed then are reused afterwards.
+	/// - Statistics are not available for models in the ORT format, or for sessions created via
+	///   [`Session::from_raw`].
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_model_from_file("model.onnx")?;
+	/// let stats = session.graph_stats()?;
+	/// println!("{} nodes, {} parameters ({} bytes)", stats.node_count, stats.parameter_count, stats.parameter_bytes);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn graph_stats(&self) -> Result<GraphStats> {
+		self.inner.graph_stats.get()
+	}
+
+	/// Returns a JSON description of the session's inputs & outputs, for use by tools that need a machine-readable
+	/// description of the model's interface.
+	///
+	/// The schema has the form:
+	/// ```json
+	/// {
+	/// 	"inputs": [
+	/// 		{ "name": "x", "type": { "type": "tensor", "element_type": "float", "dimensions": ["batch", 3, 224, 224] } }
+	/// 	],
+	/// 	"outputs": [
+	/// 		{ "name": "labels", "type": { "type": "sequence", "element": { "type": "map", "key_type": "int64", "value_type": "float" } } }
+	/// 	]
+	/// }
+	/// ```
+	///
+	/// Element types use ONNX's names (i.e. `float`, `int64`, `string`). Dimensions are numbers when fixed, the name of
+	/// the dimension when symbolic, or `null` when dynamic but unnamed.
+	#[cfg(feature = "io-schema")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "io-schema")))]
+	pub fn io_schema(&self) -> Result<String> {
+		io_schema::io_schema(self.inner.session_ptr, &self.inputs, &self.outputs)
+	}
+
 	/// Ends profiling for this session.
 	///
 	/// Note that this must be explicitly called at the end of profiling, otherwise the profiing file will be empty.
+	/// [`Session::profiling_scope`] can be used to do this automatically.
 	#[cfg(feature = "profiling")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
 	pub fn end_profiling(&self) -> Result<String> {
@@ -786,12 +1924,125 @@ impl Session {
 		assert_non_null_pointer(profiling_name, "ProfilingName")?;
 		dangerous::raw_pointer_to_string(self.inner.allocator.ptr, profiling_name)
 	}
+
+	/// Returns a guard which ends profiling for this session when it goes out of scope, so the profiling file is
+	/// written even on early returns. Profiling must be enabled via [`SessionBuilder::with_profiling`].
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_profiling("profile")?.with_model_from_file("model.onnx")?;
+	/// {
+	/// 	let _profiling = session.profiling_scope();
+	/// 	// ... run the session ...
+	/// }
+	/// // the profiling file has now been written
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// Like [`Session::end_profiling`], profiling can only be ended once per session, so only one guard should be
+	/// created.
+	#[cfg(feature = "profiling")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+	pub fn profiling_scope(&self) -> ProfilingGuard<'_> {
+		ProfilingGuard::new(self)
+	}
+
+	/// Ends profiling for this session and returns the per-node timings parsed from the resulting profiling file,
+	/// aggregated across all runs since profiling was enabled.
+	///
+	/// Like [`Session::end_profiling`], this ends profiling, so it can only be called once per session.
+	///
+	/// ```no_run
+	/// # use ort::Session;
+	/// # fn main() -> ort::Result<()> {
+	/// let session = Session::builder()?.with_profiling("profile")?.with_model_from_file("model.onnx")?;
+	/// // ... run the session ...
+	/// for timing in session.profiling_summary()? {
+	/// 	println!("{} ({}, {}): {}us over {} runs", timing.node_name, timing.op_name, timing.ep, timing.duration_us, timing.count);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "profiling")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+	pub fn profiling_summary(&self) -> Result<Vec<OpTiming>> {
+		let profiling_file = self.end_profiling()?;
+		let trace = std::fs::read_to_string(&profiling_file).map_err(Error::ReadProfile)?;
+		profiling::summarize_trace(&trace)
+	}
 }
 
 // https://github.com/microsoft/onnxruntime/issues/114
 unsafe impl Send for Session {}
 unsafe impl Sync for Session {}
 
+/// Checks that as many input values as input names were given, since ONNX Runtime reads the same number of each.
+pub(crate) fn check_input_count(names: usize, values: usize) -> Result<()> {
+	if names != values {
+		return Err(Error::PositionalInputCount { expected: names, actual: values });
+	}
+	Ok(())
+}
+
+/// Copies the tensor `value` into an owned array of dimensionality `D`; used by the `Session::run_extract*` methods.
+#[cfg(feature = "ndarray")]
+fn extract_array<T, D>(value: &Value) -> Result<ndarray::Array<T, D>>
+where
+	T: crate::ExtractTensorData + Clone + fmt::Debug,
+	D: ndarray::Dimension
+{
+	let tensor = value.extract_tensor::<T>()?;
+	let array = tensor.view().to_owned();
+	let shape = array.shape().iter().map(|&d| d as i64).collect();
+	array.into_dimensionality::<D>().map_err(|e| Error::InvalidViewShape(shape, e))
+}
+
+/// Determines whether all nodes were placed on `ep` from the node placements ONNX Runtime logs when building a session
+/// with verbose logging, which look like:
+///
+/// ```text
+/// Node placements
+///  Node(s) placed on [CUDAExecutionProvider]. Number of nodes: 3
+///   ...
+///  Node(s) placed on [CPUExecutionProvider]. Number of nodes: 1
+///   ...
+/// ```
+///
+/// (or ` All nodes placed on [CPUExecutionProvider]. Number of nodes: 4` if they were all placed on one provider).
+/// Returns `None` if the placements were not logged.
+fn full_ep_coverage(messages: &[String], ep: &str) -> Option<bool> {
+	let start = messages.iter().position(|message| message.trim() == "Node placements")?;
+	let mut providers = messages[start + 1..]
+		.iter()
+		.filter_map(|message| message.split_once("placed on [")?.1.split_once(']'))
+		.map(|(provider, _)| provider)
+		.peekable();
+	// a header without any placements means the log was cut short, so we can't tell
+	providers.peek()?;
+	Some(providers.all(|provider| provider == ep))
+}
+
+/// A custom operator library registered with [`SessionBuilder::with_custom_ops_lib`]. Clones of a builder share its
+/// handles, so the library is only closed once the last builder or session using it is dropped.
+#[cfg(feature = "custom-ops")]
+#[derive(Debug)]
+struct CustomOpsLibHandle(*mut std::os::raw::c_void);
+
+// library handles are process-wide, and closing one is thread-safe
+#[cfg(feature = "custom-ops")]
+unsafe impl Send for CustomOpsLibHandle {}
+#[cfg(feature = "custom-ops")]
+unsafe impl Sync for CustomOpsLibHandle {}
+
+#[cfg(feature = "custom-ops")]
+impl Drop for CustomOpsLibHandle {
+	fn drop(&mut self) {
+		close_lib_handle(self.0);
+	}
+}
+
 #[cfg(all(unix, feature = "custom-ops"))]
 fn close_lib_handle(handle: *mut std::os::raw::c_void) {
 	unsafe { libc::dlclose(handle) };
@@ -805,12 +2056,9 @@ fn close_lib_handle(handle: *mut std::os::raw::c_void) {
 /// This module contains dangerous functions working on raw pointers.
 /// Those functions are only to be used from inside the
 /// `SessionBuilder::with_model_from_file()` method.
-mod dangerous {
+pub(crate) mod dangerous {
 	use super::*;
-	use crate::{
-		ortfree,
-		value::{extract_data_type_from_map_info, extract_data_type_from_sequence_info, extract_data_type_from_tensor_info}
-	};
+	use crate::{ortfree, value::extract_data_type_from_type_info};
 
 	pub(super) fn extract_inputs_count(session_ptr: *mut ort_sys::OrtSession) -> Result<usize> {
 		let f = api().SessionGetInputCount.unwrap();
@@ -907,29 +2155,35 @@ mod dangerous {
 		status_to_result(status).map_err(Error::GetTypeInfo)?;
 		assert_non_null_pointer(typeinfo_ptr, "TypeInfo")?;
 
-		let mut ty: ort_sys::ONNXType = ort_sys::ONNXType::ONNX_TYPE_UNKNOWN;
-		let status = ortsys![unsafe GetOnnxTypeFromTypeInfo(typeinfo_ptr, &mut ty)];
-		status_to_result(status).map_err(Error::GetOnnxTypeFromTypeInfo)?;
-		let io_type = match ty {
-			ort_sys::ONNXType::ONNX_TYPE_TENSOR | ort_sys::ONNXType::ONNX_TYPE_SPARSETENSOR => {
-				let mut info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
-				ortsys![unsafe CastTypeInfoToTensorInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToTensorInfo; nonNull(info_ptr)];
-				unsafe { extract_data_type_from_tensor_info(info_ptr)? }
-			}
-			ort_sys::ONNXType::ONNX_TYPE_SEQUENCE => {
-				let mut info_ptr: *const ort_sys::OrtSequenceTypeInfo = std::ptr::null_mut();
-				ortsys![unsafe CastTypeInfoToSequenceTypeInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToSequenceTypeInfo; nonNull(info_ptr)];
-				unsafe { extract_data_type_from_sequence_info(info_ptr)? }
-			}
-			ort_sys::ONNXType::ONNX_TYPE_MAP => {
-				let mut info_ptr: *const ort_sys::OrtMapTypeInfo = std::ptr::null_mut();
-				ortsys![unsafe CastTypeInfoToMapTypeInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToMapTypeInfo; nonNull(info_ptr)];
-				unsafe { extract_data_type_from_map_info(info_ptr)? }
-			}
-			_ => unreachable!()
-		};
-
+		let io_type = unsafe { extract_data_type_from_type_info(typeinfo_ptr) };
 		ortsys![unsafe ReleaseTypeInfo(typeinfo_ptr)];
-		Ok(io_type)
+		io_type
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_full_ep_coverage() {
+		let messages = |lines: &[&str]| lines.iter().map(|line| line.to_string()).collect::<Vec<_>>();
+
+		let all_cpu = messages(&["Node placements", " All nodes placed on [CPUExecutionProvider]. Number of nodes: 4"]);
+		assert_eq!(full_ep_coverage(&all_cpu, "CPUExecutionProvider"), Some(true));
+		assert_eq!(full_ep_coverage(&all_cpu, "CUDAExecutionProvider"), Some(false));
+
+		let partial = messages(&[
+			"Node placements",
+			" Node(s) placed on [CUDAExecutionProvider]. Number of nodes: 3",
+			"  MatMul (matmul)",
+			" Node(s) placed on [CPUExecutionProvider]. Number of nodes: 1",
+			"  Shape (shape)"
+		]);
+		assert_eq!(full_ep_coverage(&partial, "CUDAExecutionProvider"), Some(false));
+
+		// without the header or any placements following it, coverage can't be determined
+		assert_eq!(full_ep_coverage(&messages(&["Node placements"]), "CUDAExecutionProvider"), None);
+		assert_eq!(full_ep_coverage(&messages(&["Initializing session."]), "CPUExecutionProvider"), None);
 	}
 }
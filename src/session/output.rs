@@ -6,6 +6,21 @@ use std::{
 
 use crate::Value;
 
+/// The outputs of a session run, which can be indexed by name or by position (in the order of [`Session::outputs`]).
+///
+/// Outputs are not copied or converted when the run completes. Each [`Value`] wraps the tensor (or sequence/map) that
+/// ONNX Runtime allocated for it, and data is only read when it is accessed, e.g. via
+/// [`Value::extract_raw_tensor`] (which borrows the data in-place) or [`Value::to_bytes`] (which copies it). Outputs
+/// you never access therefore cost nothing beyond the allocation ONNX Runtime already made for them, and are freed
+/// when `SessionOutputs` is dropped.
+///
+/// `SessionOutputs` borrows the session that produced it (or the [`IoBinding`] whose outputs it holds), so it can't
+/// outlive the session. Outputs of [`Session::run`] (or of a `TrainingSession`'s training & evaluation steps) taken out
+/// with [`HashMap::remove`] keep the session's internals alive on their own, and so can.
+///
+/// [`Session::outputs`]: crate::Session::outputs
+/// [`Session::run`]: crate::Session::run
+/// [`IoBinding`]: crate::IoBinding
 pub struct SessionOutputs<'s> {
 	map: HashMap<&'s str, Value>,
 	idxs: Vec<&'s str>,
@@ -89,3 +104,36 @@ impl<'s> Index<usize> for SessionOutputs<'s> {
 		self.map.get(&self.idxs[index]).expect("no entry found for key")
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{inputs, ortsys, value::ValueInner, Result, Session};
+
+	/// Returns the `OrtValue` wrapped by `value`, along with the address of the tensor data ONNX Runtime allocated for it.
+	fn raw_parts(value: &Value) -> (*mut ort_sys::OrtValue, *mut c_void) {
+		let mut data_ptr: *mut c_void = std::ptr::null_mut();
+		ortsys![unsafe GetTensorMutableData(value.ptr(), &mut data_ptr)];
+		(value.ptr(), data_ptr)
+	}
+
+	#[test]
+	fn test_access_leaves_other_outputs_untouched() -> Result<()> {
+		let session = Session::builder()?.with_model_from_memory(include_bytes!("../../tests/data/multi_output.onnx"))?;
+		let outputs = session.run(inputs![(vec![4], &[-2.5_f32, -0.5, 0.5, 2.5][..])]?)?;
+		assert_eq!(outputs.idxs, ["relu", "neg", "truncated"]);
+
+		// the run hands out the values ONNX Runtime allocated as-is; nothing is extracted into Rust-owned memory
+		assert!(outputs.values().all(|value| matches!(value.inner, ValueInner::CppOwned { .. })));
+		let before: Vec<_> = outputs.idxs.iter().map(|name| raw_parts(&outputs[*name])).collect();
+
+		// copying one output out & borrowing another doesn't touch the third, or replace either of their buffers
+		assert_eq!(outputs["relu"].to_bytes()?.2.len(), 16);
+		assert_eq!(outputs["neg"].extract_raw_tensor::<f32>()?.1, [2.5, 0.5, -0.5, -2.5]);
+		let after: Vec<_> = outputs.idxs.iter().map(|name| raw_parts(&outputs[*name])).collect();
+		assert_eq!(before, after);
+		assert!(outputs.values().all(|value| matches!(value.inner, ValueInner::CppOwned { .. })));
+
+		Ok(())
+	}
+}
@@ -0,0 +1,131 @@
+//! Parsing of the Chrome trace files produced by ONNX Runtime's profiler.
+
+use std::collections::HashMap;
+
+use crate::{Error, Result, Session};
+
+/// Aggregated timing information for a single node in the graph, as reported by [`crate::Session::profiling_summary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpTiming {
+	/// The type of the operator, e.g. `Conv` or `Relu`.
+	pub op_name: String,
+	/// The name of the node in the graph.
+	pub node_name: String,
+	/// The execution provider the node was assigned to, e.g. `CPUExecutionProvider`.
+	pub ep: String,
+	/// Total time spent executing the node's kernel across all runs, in microseconds.
+	pub duration_us: u64,
+	/// Number of times the node's kernel was executed.
+	pub count: u64
+}
+
+/// A guard which ends profiling for a session when dropped, returned by [`Session::profiling_scope`].
+///
+/// Use [`ProfilingGuard::end`] to end profiling early and get the path of the profiling file.
+#[derive(Debug)]
+pub struct ProfilingGuard<'s> {
+	session: &'s Session,
+	ended: bool
+}
+
+impl<'s> ProfilingGuard<'s> {
+	pub(crate) fn new(session: &'s Session) -> Self {
+		Self { session, ended: false }
+	}
+
+	/// Ends profiling, returning the path of the profiling file.
+	pub fn end(mut self) -> Result<String> {
+		self.ended = true;
+		self.session.end_profiling()
+	}
+}
+
+impl<'s> Drop for ProfilingGuard<'s> {
+	fn drop(&mut self) {
+		if !self.ended {
+			if let Err(e) = self.session.end_profiling() {
+				tracing::warn!("Failed to end profiling: {e}");
+			}
+		}
+	}
+}
+
+/// Suffix ONNX Runtime appends to the names of node kernel execution events.
+const KERNEL_TIME_SUFFIX: &str = "_kernel_time";
+
+/// Parses a profiling trace and aggregates the kernel execution events by node. Nodes are returned in the order they
+/// first appear in the trace.
+pub(crate) fn summarize_trace(trace: &str) -> Result<Vec<OpTiming>> {
+	let events: Vec<serde_json::Value> = serde_json::from_str(trace).map_err(Error::ParseProfile)?;
+
+	let mut timings: Vec<OpTiming> = Vec::new();
+	let mut indices: HashMap<String, usize> = HashMap::new();
+	for event in &events {
+		if event["cat"].as_str() != Some("Node") {
+			continue;
+		}
+		let Some(node_name) = event["name"].as_str().and_then(|n| n.strip_suffix(KERNEL_TIME_SUFFIX)) else {
+			// `_fence_before`/`_fence_after` events only measure synchronization overhead
+			continue;
+		};
+		let duration_us = event["dur"].as_u64().unwrap_or(0);
+
+		match indices.get(node_name) {
+			Some(&i) => {
+				timings[i].duration_us += duration_us;
+				timings[i].count += 1;
+			}
+			None => {
+				indices.insert(node_name.to_string(), timings.len());
+				timings.push(OpTiming {
+					op_name: event["args"]["op_name"].as_str().unwrap_or_default().to_string(),
+					node_name: node_name.to_string(),
+					ep: event["args"]["provider"].as_str().unwrap_or_default().to_string(),
+					duration_us,
+					count: 1
+				});
+			}
+		}
+	}
+	Ok(timings)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_summarize_trace() -> Result<()> {
+		let trace = r#"[
+			{"cat":"Session","pid":1,"tid":1,"dur":120,"ts":1,"ph":"X","name":"model_run","args":{}},
+			{"cat":"Node","pid":1,"tid":1,"dur":0,"ts":2,"ph":"X","name":"relu_fence_before","args":{"op_name":"Relu"}},
+			{"cat":"Node","pid":1,"tid":1,"dur":7,"ts":2,"ph":"X","name":"relu_kernel_time","args":{"op_name":"Relu","provider":"CPUExecutionProvider"}},
+			{"cat":"Node","pid":1,"tid":1,"dur":3,"ts":9,"ph":"X","name":"neg_kernel_time","args":{"op_name":"Neg","provider":"CPUExecutionProvider"}},
+			{"cat":"Node","pid":1,"tid":1,"dur":5,"ts":20,"ph":"X","name":"relu_kernel_time","args":{"op_name":"Relu","provider":"CPUExecutionProvider"}}
+		]"#;
+
+		let summary = summarize_trace(trace)?;
+		assert_eq!(
+			summary,
+			vec![
+				OpTiming {
+					op_name: "Relu".to_string(),
+					node_name: "relu".to_string(),
+					ep: "CPUExecutionProvider".to_string(),
+					duration_us: 12,
+					count: 2
+				},
+				OpTiming {
+					op_name: "Neg".to_string(),
+					node_name: "neg".to_string(),
+					ep: "CPUExecutionProvider".to_string(),
+					duration_us: 3,
+					count: 1
+				}
+			]
+		);
+
+		assert!(matches!(summarize_trace("not json"), Err(Error::ParseProfile(_))));
+		Ok(())
+	}
+}
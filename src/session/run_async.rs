@@ -0,0 +1,185 @@
+//! Asynchronous inference via ONNX Runtime's `RunAsync`.
+
+use std::{
+	ffi::CString,
+	future::Future,
+	os::raw::{c_char, c_void},
+	pin::Pin,
+	sync::{Arc, Mutex},
+	task::{Context, Poll, Waker}
+};
+
+use super::{check_input_count, Session, SessionOutputs, SharedSessionInner};
+use crate::{error::status_to_result, extern_system_fn, ortsys, Error, Result, RunOptions, Value};
+
+/// Input values for an asynchronous run, which must be kept alive until the run completes.
+pub(crate) enum AsyncInputs {
+	Borrowed(&'static [Value]),
+	Owned(Vec<Value>)
+}
+
+impl AsyncInputs {
+	fn values(&self) -> &[Value] {
+		match self {
+			AsyncInputs::Borrowed(values) => values,
+			AsyncInputs::Owned(values) => values
+		}
+	}
+}
+
+#[derive(Default)]
+struct RunState {
+	/// The raw output values once the run completes, or the error it failed with.
+	result: Option<Result<Vec<*mut ort_sys::OrtValue>>>,
+	waker: Option<Waker>
+}
+
+/// Everything passed to `RunAsync`, shared between a [`RunFuture`] and ONNX Runtime's completion callback.
+///
+/// The callback holds its own reference to the context, so the session, inputs, and name/pointer arrays stay alive
+/// until ONNX Runtime is done with them, even if the [`RunFuture`] is leaked (e.g. via [`std::mem::forget`]).
+struct RunContext {
+	session: Arc<SharedSessionInner>,
+	run_options: Arc<RunOptions>,
+	state: Mutex<RunState>,
+	_inputs: AsyncInputs,
+	_input_names: Vec<CString>,
+	_input_ptrs: Vec<*const ort_sys::OrtValue>,
+	_output_names: Vec<CString>,
+	_output_ptrs: Vec<*mut ort_sys::OrtValue>,
+	_name_ptrs: (Vec<*const c_char>, Vec<*const c_char>)
+}
+
+// the raw pointers are only read by ONNX Runtime, and the raw output values are only handed from ONNX Runtime's thread
+// to the future
+unsafe impl Send for RunContext {}
+unsafe impl Sync for RunContext {}
+
+impl Drop for RunContext {
+	fn drop(&mut self) {
+		// release any outputs that were never retrieved
+		let state = self.state.get_mut().unwrap_or_else(|e| e.into_inner());
+		if let Some(Ok(output_ptrs)) = state.result.take() {
+			for ptr in output_ptrs {
+				drop(unsafe { Value::from_raw(ptr, Arc::clone(&self.session)) });
+			}
+		}
+	}
+}
+
+extern_system_fn! {
+	unsafe fn run_async_callback(user_data: *mut c_void, outputs: *mut *mut ort_sys::OrtValue, num_outputs: ort_sys::size_t, status: ort_sys::OrtStatusPtr) {
+		let context = Arc::from_raw(user_data as *const RunContext);
+		let result = match status_to_result(status) {
+			Ok(()) => Ok(std::slice::from_raw_parts(outputs, num_outputs as _).to_vec()),
+			Err(e) => Err(Error::SessionRun(e))
+		};
+
+		let mut state = context.state.lock().unwrap_or_else(|e| e.into_inner());
+		state.result = Some(result);
+		if let Some(waker) = state.waker.take() {
+			waker.wake();
+		}
+	}
+}
+
+/// A future which resolves to the outputs of an asynchronous run started with [`Session::run_async`].
+///
+/// The future does not need to be polled for inference to progress. If it is dropped before the run completes, the
+/// run is terminated; its inputs and any outputs are released once ONNX Runtime stops using them.
+pub struct RunFuture<'s> {
+	session: &'s Session,
+	context: Arc<RunContext>,
+	finished: bool
+}
+
+impl<'s> RunFuture<'s> {
+	pub(crate) fn new(session: &'s Session, input_names: Vec<CString>, inputs: AsyncInputs) -> Result<Self> {
+		check_input_count(input_names.len(), inputs.values().len())?;
+
+		let output_names: Vec<CString> = session.outputs.iter().map(|o| CString::new(o.name.as_str())).collect::<Result<_, _>>()?;
+		let input_name_ptrs: Vec<*const c_char> = input_names.iter().map(|n| n.as_ptr()).collect();
+		let output_name_ptrs: Vec<*const c_char> = output_names.iter().map(|n| n.as_ptr()).collect();
+		let input_ptrs: Vec<*const ort_sys::OrtValue> = inputs.values().iter().map(|v| v.ptr() as *const _).collect();
+		let mut output_ptrs: Vec<*mut ort_sys::OrtValue> = vec![std::ptr::null_mut(); output_names.len()];
+		// moving the `Vec`s into the context below doesn't move their heap buffers, so these pointers stay valid
+		let (input_name_ptrs_ptr, input_ptrs_ptr, output_name_ptrs_ptr, output_ptrs_ptr) =
+			(input_name_ptrs.as_ptr(), input_ptrs.as_ptr(), output_name_ptrs.as_ptr(), output_ptrs.as_mut_ptr());
+		let (input_count, output_count) = (input_ptrs.len(), output_name_ptrs.len());
+
+		let context = Arc::new(RunContext {
+			session: Arc::clone(&session.inner),
+			run_options: Arc::new(RunOptions::new()?),
+			state: Mutex::new(RunState::default()),
+			_inputs: inputs,
+			_input_names: input_names,
+			_input_ptrs: input_ptrs,
+			_output_names: output_names,
+			_output_ptrs: output_ptrs,
+			_name_ptrs: (input_name_ptrs, output_name_ptrs)
+		});
+		// the callback takes ownership of this reference
+		let user_data = Arc::into_raw(Arc::clone(&context)) as *mut c_void;
+		let status = ortsys![
+			unsafe RunAsync(
+				context.session.session_ptr,
+				context.run_options.run_options_ptr,
+				input_name_ptrs_ptr,
+				input_ptrs_ptr,
+				input_count as _,
+				output_name_ptrs_ptr,
+				output_count as _,
+				output_ptrs_ptr,
+				Some(run_async_callback),
+				user_data
+			)
+		];
+		if let Err(e) = status_to_result(status) {
+			// the callback is never called if the run couldn't be started
+			drop(unsafe { Arc::from_raw(user_data as *const RunContext) });
+			return Err(Error::SessionRun(e));
+		}
+
+		Ok(Self { session, context, finished: false })
+	}
+}
+
+impl<'s> Future for RunFuture<'s> {
+	type Output = Result<SessionOutputs<'s>>;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let this = self.get_mut();
+		let mut state = this.context.state.lock().unwrap_or_else(|e| e.into_inner());
+		match state.result.take() {
+			Some(result) => {
+				drop(state);
+				this.finished = true;
+				let outputs: Vec<Value> = result?
+					.into_iter()
+					.map(|ptr| unsafe { Value::from_raw(ptr, Arc::clone(&this.context.session)) })
+					.collect();
+				Poll::Ready(Ok(SessionOutputs::new(this.session.outputs.iter().map(|o| o.name.as_str()), outputs)))
+			}
+			None => {
+				state.waker = Some(cx.waker().clone());
+				Poll::Pending
+			}
+		}
+	}
+}
+
+impl<'s> Drop for RunFuture<'s> {
+	fn drop(&mut self) {
+		if self.finished {
+			return;
+		}
+
+		let state = self.context.state.lock().unwrap_or_else(|e| e.into_inner());
+		if state.result.is_none() {
+			// the context (and with it, the inputs & outputs) is released by whichever of us finishes last
+			if let Err(e) = self.context.run_options.set_terminate() {
+				tracing::warn!("Failed to terminate asynchronous run: {e}");
+			}
+		}
+	}
+}
@@ -0,0 +1,112 @@
+//! Approximate comparison of tensors against expected arrays, for use in tests.
+
+use std::{cmp::Ordering, fmt, ops::Sub};
+
+use ndarray::{ArrayView, Dimension};
+
+use crate::{Error, ExtractTensorData, Value};
+
+/// The reason a tensor did not match the expected array in [`Value::approx_eq`].
+#[derive(Debug)]
+pub enum TensorMismatch<T> {
+	/// The tensor's data could not be extracted as `T`, e.g. because its element type is different.
+	Extract(Error),
+	/// The tensor's shape differs from the expected array's.
+	Shape { actual: Vec<usize>, expected: Vec<usize> },
+	/// The first element that differs from the expected array by more than the tolerance.
+	Element { index: Vec<usize>, actual: T, expected: T }
+}
+
+impl<T: fmt::Debug> fmt::Display for TensorMismatch<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TensorMismatch::Extract(e) => write!(f, "failed to extract tensor: {e}"),
+			TensorMismatch::Shape { actual, expected } => write!(f, "tensor has shape {actual:?}, expected {expected:?}"),
+			TensorMismatch::Element { index, actual, expected } => write!(f, "tensor element at {index:?} is {actual:?}, expected {expected:?}")
+		}
+	}
+}
+
+impl<T: fmt::Debug> std::error::Error for TensorMismatch<T> {}
+
+impl Value {
+	/// Checks that this tensor has the same shape as `expected`, and that each of its elements is within `tolerance` of
+	/// the corresponding element of `expected`. On failure, the returned [`TensorMismatch`] describes the first
+	/// difference found, which makes for helpful assertion messages:
+	///
+	/// ```
+	/// # use ndarray::array;
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(array![[1.0_f32, 2.0], [3.0, 4.0]])?;
+	/// if let Err(mismatch) = value.approx_eq(&array![[1.0, 2.0], [3.0, 4.00001]].view(), 1e-4) {
+	/// 	panic!("{mismatch}");
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// `NaN`s are never considered equal. Use a `tolerance` of zero to check for exact equality, e.g. for integer
+	/// tensors.
+	#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+	pub fn approx_eq<T, D>(&self, expected: &ArrayView<'_, T, D>, tolerance: T) -> Result<(), TensorMismatch<T>>
+	where
+		T: ExtractTensorData + Copy + fmt::Debug + PartialOrd + Sub<Output = T>,
+		D: Dimension
+	{
+		let tensor = self.extract_tensor::<T>().map_err(TensorMismatch::Extract)?;
+		let actual = tensor.view();
+		if actual.shape() != expected.shape() {
+			return Err(TensorMismatch::Shape {
+				actual: actual.shape().to_vec(),
+				expected: expected.shape().to_vec()
+			});
+		}
+
+		for ((index, &a), &e) in actual.indexed_iter().zip(expected.iter()) {
+			let difference = if a > e { a - e } else { e - a };
+			// incomparable differences (i.e. `NaN`s) fail the check
+			if !matches!(difference.partial_cmp(&tolerance), Some(Ordering::Less | Ordering::Equal)) {
+				return Err(TensorMismatch::Element {
+					index: index.slice().to_vec(),
+					actual: a,
+					expected: e
+				});
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ndarray::{array, Array3};
+
+	use super::*;
+
+	#[test]
+	fn test_approx_eq() -> crate::Result<()> {
+		let expected = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as f32 * 0.1);
+		let value = Value::from_array(expected.clone())?;
+		assert!(value.approx_eq(&expected.view(), 0.0).is_ok());
+
+		let mut perturbed = expected.clone();
+		perturbed[[1, 2, 0]] += 0.01;
+		assert!(value.approx_eq(&perturbed.view(), 0.1).is_ok());
+		match value.approx_eq(&perturbed.view(), 1e-3) {
+			Err(TensorMismatch::Element { index, actual, expected }) => {
+				assert_eq!(index, [1, 2, 0]);
+				assert_eq!(actual, 2.0);
+				assert!((expected - 2.01).abs() < 1e-6);
+			}
+			r => panic!("expected an element mismatch, got {r:?}")
+		}
+
+		assert!(matches!(value.approx_eq(&array![1.0_f32, 2.0].view(), 0.1), Err(TensorMismatch::Shape { .. })));
+		assert!(matches!(value.approx_eq(&array![[[1_i64]]].view(), 0), Err(TensorMismatch::Extract(_))));
+
+		let nan = Value::from_array(array![f32::NAN])?;
+		assert!(nan.approx_eq(&array![f32::NAN].view(), f32::INFINITY).is_err());
+		Ok(())
+	}
+}
@@ -0,0 +1,54 @@
+//! 8-bit floating point types, as defined by the [ONNX specification](https://onnx.ai/onnx/technical/float8.html).
+//!
+//! These types are opaque wrappers around their raw bits; they can be passed to and extracted from ONNX Runtime, but
+//! `ort` does not implement any arithmetic or conversions to/from other floating point types for them.
+
+macro_rules! float8_type {
+	($(#[$meta:meta])* $name:ident) => {
+		$(#[$meta])*
+		#[repr(transparent)]
+		#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+		pub struct $name(pub u8);
+
+		impl $name {
+			/// Creates a value from its raw bit representation.
+			pub const fn from_bits(bits: u8) -> Self {
+				Self(bits)
+			}
+
+			/// Returns the raw bit representation of this value.
+			pub const fn to_bits(self) -> u8 {
+				self.0
+			}
+		}
+	};
+}
+
+float8_type!(
+	/// An 8-bit floating point number with 1 sign bit, 4 exponent bits (bias 7), and 3 mantissa bits, corresponding to
+	/// ONNX's `FLOAT8E4M3FN`.
+	///
+	/// There are no infinities; `S.1111.111` is NaN.
+	Float8E4M3FN
+);
+float8_type!(
+	/// An 8-bit floating point number with 1 sign bit, 4 exponent bits (bias 8), and 3 mantissa bits, corresponding to
+	/// ONNX's `FLOAT8E4M3FNUZ`.
+	///
+	/// There are no infinities or negative zero; `1.0000.000` (`0x80`) is NaN.
+	Float8E4M3FNUZ
+);
+float8_type!(
+	/// An 8-bit floating point number with 1 sign bit, 5 exponent bits (bias 15), and 2 mantissa bits, corresponding to
+	/// ONNX's `FLOAT8E5M2`.
+	///
+	/// Like IEEE 754 types, `S.11111.00` is infinity and `S.11111.{01, 10, 11}` is NaN.
+	Float8E5M2
+);
+float8_type!(
+	/// An 8-bit floating point number with 1 sign bit, 5 exponent bits (bias 16), and 2 mantissa bits, corresponding to
+	/// ONNX's `FLOAT8E5M2FNUZ`.
+	///
+	/// There are no infinities or negative zero; `1.00000.00` (`0x80`) is NaN.
+	Float8E5M2FNUZ
+);
@@ -0,0 +1,89 @@
+//! Conversion of image tensors output by a model into [`image::RgbImage`]s.
+
+use image::{Rgb, RgbImage};
+
+use crate::{Error, Result, TensorElementType, Value, ValueType};
+
+/// The layout of a tensor containing a single RGB image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageLayout {
+	/// Channels-first, i.e. a tensor of shape `[1, 3, H, W]`. This is the layout used by most PyTorch models.
+	Nchw,
+	/// Channels-last, i.e. a tensor of shape `[1, H, W, 3]`. This is the layout used by most TensorFlow models.
+	Nhwc
+}
+
+impl Value {
+	/// Converts a tensor containing a single RGB image, such as the output of a segmentation or image generation model,
+	/// into an [`RgbImage`]. Both `f32` and `u8` tensors are supported.
+	///
+	/// If `denormalize` is `Some((mean, std))`, each element is converted to a pixel value via `value * std + mean`;
+	/// e.g. use `Some((0.0, 255.0))` for outputs in the range `[0, 1]`, or `Some((127.5, 127.5))` for outputs in the
+	/// range `[-1, 1]`. Otherwise, elements are assumed to already be pixel values. Pixel values are rounded and clamped
+	/// to `[0, 255]`.
+	///
+	/// Returns an error if the tensor's shape doesn't match `layout`.
+	#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+	pub fn to_image(&self, layout: ImageLayout, denormalize: Option<(f32, f32)>) -> Result<RgbImage> {
+		let (ty, shape) = match self.dtype()? {
+			ValueType::Tensor { ty, dimensions } => (ty, dimensions),
+			t => return Err(Error::NotTensor(t))
+		};
+		let (height, width) = match (layout, &shape[..]) {
+			(ImageLayout::Nchw, &[1, 3, height, width]) | (ImageLayout::Nhwc, &[1, height, width, 3]) => (height as u32, width as u32),
+			_ => return Err(Error::InvalidImageShape { layout, shape })
+		};
+		match ty {
+			TensorElementType::Float32 => Ok(to_rgb_image(self.extract_raw_tensor::<f32>()?.1, width, height, layout, denormalize)),
+			TensorElementType::Uint8 => Ok(to_rgb_image(self.extract_raw_tensor::<u8>()?.1, width, height, layout, denormalize)),
+			actual => Err(Error::DataTypeMismatch {
+				actual,
+				requested: TensorElementType::Float32
+			})
+		}
+	}
+}
+
+fn to_rgb_image<T: Copy + Into<f32>>(data: &[T], width: u32, height: u32, layout: ImageLayout, denormalize: Option<(f32, f32)>) -> RgbImage {
+	let (mean, std) = denormalize.unwrap_or((0.0, 1.0));
+	let plane_len = width as usize * height as usize;
+	RgbImage::from_fn(width, height, |x, y| {
+		let pixel = y as usize * width as usize + x as usize;
+		Rgb(std::array::from_fn(|channel| {
+			let value = match layout {
+				ImageLayout::Nchw => data[channel * plane_len + pixel],
+				ImageLayout::Nhwc => data[pixel * 3 + channel]
+			};
+			// `as` saturates, and maps NaN to 0
+			(value.into() * std + mean).round() as u8
+		}))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_to_image() -> Result<()> {
+		// a 2x1 image; the first pixel is red, the second is white
+		let nhwc = Value::from_array((vec![1, 1, 2, 3], &[1.0_f32, 0.0, 0.0, 1.0, 1.0, 1.0][..]))?;
+		let image = nhwc.to_image(ImageLayout::Nhwc, Some((0.0, 255.0)))?;
+		assert_eq!(image.dimensions(), (2, 1));
+		assert_eq!(image.get_pixel(0, 0), &Rgb([255, 0, 0]));
+		assert_eq!(image.get_pixel(1, 0), &Rgb([255, 255, 255]));
+
+		let nchw = Value::from_array((vec![1, 3, 1, 2], &[1.0_f32, 1.0, 0.0, 1.0, 0.0, 1.0][..]))?;
+		assert_eq!(nchw.to_image(ImageLayout::Nchw, Some((0.0, 255.0)))?, image);
+
+		// out-of-range values are clamped
+		let unclamped = Value::from_array((vec![1, 1, 1, 3], &[-20.0_f32, 300.0, 127.6][..]))?;
+		assert_eq!(unclamped.to_image(ImageLayout::Nhwc, None)?.get_pixel(0, 0), &Rgb([0, 255, 128]));
+
+		let pixels = Value::from_array((vec![1, 1, 1, 3], &[1_u8, 2, 3][..]))?;
+		assert_eq!(pixels.to_image(ImageLayout::Nhwc, None)?.get_pixel(0, 0), &Rgb([1, 2, 3]));
+
+		assert!(matches!(nchw.to_image(ImageLayout::Nhwc, None), Err(Error::InvalidImageShape { layout: ImageLayout::Nhwc, .. })));
+		Ok(())
+	}
+}
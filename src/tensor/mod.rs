@@ -14,8 +14,15 @@
 //! convert it internally to an [`OrtTensor`]. After inference, a [`OrtOwnedTensor`] will be returned by the method
 //! which can be derefed into its internal [`ndarray::ArrayView`].
 
+#[cfg(feature = "test-utils")]
+mod approx_eq;
+#[cfg(feature = "float8")]
+mod float8;
+#[cfg(feature = "image")]
+mod image;
 #[cfg(feature = "ndarray")]
 mod ndarray;
+mod quantized;
 mod types;
 
 use std::{fmt::Debug, ptr};
@@ -23,12 +30,18 @@ use std::{fmt::Debug, ptr};
 #[cfg(feature = "ndarray")]
 use ::ndarray::{ArrayView, IxDyn};
 
+#[cfg(feature = "test-utils")]
+pub use self::approx_eq::TensorMismatch;
+#[cfg(feature = "float8")]
+pub use self::float8::{Float8E4M3FN, Float8E4M3FNUZ, Float8E5M2, Float8E5M2FNUZ};
+#[cfg(feature = "image")]
+pub use self::image::ImageLayout;
+pub use self::quantized::{QuantizedElement, QuantizedTensor};
 pub use self::types::{ExtractTensorData, IntoTensorElementType, TensorElementType, Utf8Data};
+pub(crate) use self::types::{dims_to_usize, element_count, validate_bool_data, validate_shape};
 #[cfg(feature = "ndarray")]
 pub use self::{ndarray::ArrayExtensions, types::TensorData};
 use crate::ortsys;
-#[cfg(feature = "ndarray")]
-use crate::{Error, Result};
 
 /// Tensor containing data owned by the ONNX Runtime C library, used to return values from inference.
 ///
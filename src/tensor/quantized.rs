@@ -0,0 +1,222 @@
+//! Linearly quantized `Int8` & `Uint8` tensors, bundled with the parameters needed to interpret them.
+
+use std::fmt::Debug;
+
+use super::{ExtractTensorData, IntoTensorElementType, TensorElementType};
+use crate::{Error, Result, Value, ValueType};
+
+/// The element type of a [`QuantizedTensor`]; implemented for `i8` and `u8`.
+pub trait QuantizedElement: IntoTensorElementType + ExtractTensorData + Copy + Debug + 'static {
+	/// The smallest value representable by this type.
+	const MIN: i32;
+	/// The largest value representable by this type.
+	const MAX: i32;
+
+	/// Converts a value in the range `MIN..=MAX` to this type.
+	fn from_i32(value: i32) -> Self;
+
+	/// Converts this value to an `i32`.
+	fn to_i32(self) -> i32;
+}
+
+macro_rules! impl_quantized_element {
+	($type:ty) => {
+		impl QuantizedElement for $type {
+			const MIN: i32 = <$type>::MIN as i32;
+			const MAX: i32 = <$type>::MAX as i32;
+
+			fn from_i32(value: i32) -> Self {
+				value as $type
+			}
+
+			fn to_i32(self) -> i32 {
+				self as i32
+			}
+		}
+	};
+}
+
+impl_quantized_element!(i8);
+impl_quantized_element!(u8);
+
+/// An `Int8` or `Uint8` tensor along with the scale & zero point used to quantize it, following the same linear
+/// quantization scheme as ONNX's `QuantizeLinear` & `DequantizeLinear` operators:
+///
+/// ```text
+/// quantized = saturate(round(real / scale) + zero_point)
+/// real = (quantized - zero_point) * scale
+/// ```
+///
+/// ONNX Runtime's tensors don't store quantization parameters, so this is purely a convenience for keeping them
+/// together with the tensor; [`QuantizedTensor::value`] is an ordinary tensor which can be passed to a session as-is.
+///
+/// ```
+/// # use ort::QuantizedTensor;
+/// # fn main() -> ort::Result<()> {
+/// let tensor = QuantizedTensor::quantize::<u8>(vec![4], &[-1.0, 0.0, 1.5, 100.0], 0.5, 128)?;
+/// // values outside the representable range saturate
+/// assert_eq!(tensor.value().extract_raw_tensor::<u8>()?.1, &[126, 128, 131, 255]);
+///
+/// let dequantized = tensor.dequantize()?;
+/// assert_eq!(dequantized.extract_raw_tensor::<f32>()?.1, &[-1.0, 0.0, 1.5, 63.5]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct QuantizedTensor {
+	value: Value,
+	scale: f32,
+	zero_point: i32
+}
+
+impl QuantizedTensor {
+	/// Wraps an existing `Int8` or `Uint8` tensor which was quantized with the given `scale` & `zero_point`.
+	///
+	/// Returns an error if `value` is not an `Int8` or `Uint8` tensor, if `scale` is not a positive, finite number, or
+	/// if `zero_point` is not representable in the tensor's element type.
+	pub fn new(value: Value, scale: f32, zero_point: i32) -> Result<Self> {
+		match value.dtype()? {
+			ValueType::Tensor { ty: TensorElementType::Int8, .. } => validate_parameters::<i8>(scale, zero_point)?,
+			ValueType::Tensor { ty: TensorElementType::Uint8, .. } => validate_parameters::<u8>(scale, zero_point)?,
+			dtype => return Err(Error::InvalidQuantizedTensorType(dtype))
+		}
+		Ok(Self { value, scale, zero_point })
+	}
+
+	/// Quantizes `data` to a new tensor of `T` (`i8` or `u8`) with the given `shape`, `scale`, and `zero_point`.
+	///
+	/// Values are rounded to the nearest integer (with ties rounding to even), and saturate to the range of `T`.
+	pub fn quantize<T: QuantizedElement>(shape: Vec<i64>, data: &[f32], scale: f32, zero_point: i32) -> Result<Self> {
+		validate_parameters::<T>(scale, zero_point)?;
+		let quantized: Vec<T> = data
+			.iter()
+			.map(|&x| T::from_i32((round_half_to_even(x / scale) + zero_point as f32).clamp(T::MIN as f32, T::MAX as f32) as i32))
+			.collect();
+		Ok(Self {
+			value: Value::from_array((shape, quantized))?,
+			scale,
+			zero_point
+		})
+	}
+
+	/// Returns the quantized tensor.
+	pub fn value(&self) -> &Value {
+		&self.value
+	}
+
+	/// Consumes this wrapper, returning the quantized tensor.
+	pub fn into_value(self) -> Value {
+		self.value
+	}
+
+	/// Returns the scale the tensor was quantized with.
+	pub fn scale(&self) -> f32 {
+		self.scale
+	}
+
+	/// Returns the zero point the tensor was quantized with.
+	pub fn zero_point(&self) -> i32 {
+		self.zero_point
+	}
+
+	/// Dequantizes the tensor into a new `Float32` tensor of the same shape.
+	pub fn dequantize(&self) -> Result<Value> {
+		match self.value.dtype()? {
+			ValueType::Tensor { ty: TensorElementType::Int8, .. } => self.dequantize_as::<i8>(),
+			ValueType::Tensor { ty: TensorElementType::Uint8, .. } => self.dequantize_as::<u8>(),
+			dtype => Err(Error::InvalidQuantizedTensorType(dtype))
+		}
+	}
+
+	fn dequantize_as<T: QuantizedElement>(&self) -> Result<Value> {
+		let (shape, data) = self.value.extract_raw_tensor::<T>()?;
+		let data: Vec<f32> = data.iter().map(|q| (q.to_i32() - self.zero_point) as f32 * self.scale).collect();
+		Value::from_array((shape, data))
+	}
+}
+
+fn validate_parameters<T: QuantizedElement>(scale: f32, zero_point: i32) -> Result<()> {
+	if !scale.is_finite() || scale <= 0.0 {
+		return Err(Error::InvalidQuantizationParameters(format!("scale must be a positive, finite number, got {scale}")));
+	}
+	if !(T::MIN..=T::MAX).contains(&zero_point) {
+		return Err(Error::InvalidQuantizationParameters(format!(
+			"zero point {zero_point} is out of range for {:?} tensors ({}..={})",
+			T::into_tensor_element_type(),
+			T::MIN,
+			T::MAX
+		)));
+	}
+	Ok(())
+}
+
+/// Rounds `x` to the nearest integer, rounding ties to even like `QuantizeLinear` does (`f32::round_ties_even` requires
+/// Rust 1.77).
+fn round_half_to_even(x: f32) -> f32 {
+	if (x - x.trunc()).abs() == 0.5 { 2.0 * (x / 2.0).round() } else { x.round() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_round_half_to_even() {
+		assert_eq!(round_half_to_even(0.5), 0.0);
+		assert_eq!(round_half_to_even(1.5), 2.0);
+		assert_eq!(round_half_to_even(2.5), 2.0);
+		assert_eq!(round_half_to_even(-2.5), -2.0);
+		assert_eq!(round_half_to_even(-3.5), -4.0);
+		assert_eq!(round_half_to_even(2.4), 2.0);
+		assert_eq!(round_half_to_even(-2.6), -3.0);
+	}
+
+	#[test]
+	fn test_round_trip() -> Result<()> {
+		let data: Vec<f32> = (0..=100).map(|i| i as f32 / 50.0 - 1.0).collect();
+
+		let scale = 2.0 / 255.0;
+		let tensor = QuantizedTensor::quantize::<u8>(vec![101], &data, scale, 128)?;
+		assert_eq!(tensor.value().extract_raw_tensor::<u8>()?.0, [101]);
+		let dequantized = tensor.dequantize()?;
+		let (shape, dequantized) = dequantized.extract_raw_tensor::<f32>()?;
+		assert_eq!(shape, [101]);
+		for (x, y) in data.iter().zip(dequantized) {
+			assert!((x - y).abs() <= scale / 2.0 + f32::EPSILON, "{x} was dequantized to {y}");
+		}
+
+		let scale = 1.0 / 127.0;
+		let tensor = QuantizedTensor::quantize::<i8>(vec![101], &data, scale, 0)?;
+		let (_, quantized) = tensor.value().extract_raw_tensor::<i8>()?;
+		assert_eq!((quantized[0], quantized[50], quantized[100]), (-127, 0, 127));
+		let dequantized = tensor.dequantize()?;
+		let (_, dequantized) = dequantized.extract_raw_tensor::<f32>()?;
+		for (x, y) in data.iter().zip(dequantized) {
+			assert!((x - y).abs() <= scale / 2.0 + f32::EPSILON, "{x} was dequantized to {y}");
+		}
+
+		// wrapping an existing tensor dequantizes the same way
+		let wrapped = QuantizedTensor::new(tensor.into_value(), scale, 0)?;
+		assert!((wrapped.dequantize()?.extract_raw_tensor::<f32>()?.1[100] - 1.0).abs() <= f32::EPSILON);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_invalid() -> Result<()> {
+		assert!(matches!(QuantizedTensor::quantize::<u8>(vec![1], &[0.0], 0.0, 0), Err(Error::InvalidQuantizationParameters(_))));
+		assert!(matches!(QuantizedTensor::quantize::<u8>(vec![1], &[0.0], f32::NAN, 0), Err(Error::InvalidQuantizationParameters(_))));
+		assert!(matches!(QuantizedTensor::quantize::<u8>(vec![1], &[0.0], 1.0, -1), Err(Error::InvalidQuantizationParameters(_))));
+		assert!(matches!(QuantizedTensor::quantize::<i8>(vec![1], &[0.0], 1.0, 128), Err(Error::InvalidQuantizationParameters(_))));
+
+		let value = Value::from_array((vec![1], vec![0.5_f32]))?;
+		match QuantizedTensor::new(value, 1.0, 0) {
+			Err(e @ Error::InvalidQuantizedTensorType(_)) => {
+				assert_eq!(e.to_string(), "Quantized tensors must be Int8 or Uint8 tensors, got Tensor<Float32>[1]")
+			}
+			r => panic!("expected a type error, got {r:?}")
+		}
+
+		Ok(())
+	}
+}
@@ -3,7 +3,8 @@ use std::fmt::{self, Debug};
 use std::{ffi, ptr, result, string};
 
 #[cfg(feature = "ndarray")]
-use super::{ortsys, Error, Result};
+use super::ortsys;
+use crate::{Error, Result};
 
 /// Enum mapping ONNX Runtime's supported tensor data types.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -44,7 +45,26 @@ pub enum TensorElementType {
 	/// Brain 16-bit floating point number, equivalent to [`half::bf16`] (requires the `half` feature).
 	#[cfg(feature = "half")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
-	Bfloat16
+	Bfloat16,
+	/// 8-bit floating point number, equivalent to [`Float8E4M3FN`](crate::Float8E4M3FN) (requires the `float8`
+	/// feature).
+	#[cfg(feature = "float8")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+	Float8E4M3FN,
+	/// 8-bit floating point number, equivalent to [`Float8E4M3FNUZ`](crate::Float8E4M3FNUZ) (requires the `float8`
+	/// feature).
+	#[cfg(feature = "float8")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+	Float8E4M3FNUZ,
+	/// 8-bit floating point number, equivalent to [`Float8E5M2`](crate::Float8E5M2) (requires the `float8` feature).
+	#[cfg(feature = "float8")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+	Float8E5M2,
+	/// 8-bit floating point number, equivalent to [`Float8E5M2FNUZ`](crate::Float8E5M2FNUZ) (requires the `float8`
+	/// feature).
+	#[cfg(feature = "float8")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+	Float8E5M2FNUZ
 }
 
 impl From<TensorElementType> for ort_sys::ONNXTensorElementDataType {
@@ -67,10 +87,36 @@ impl From<TensorElementType> for ort_sys::ONNXTensorElementDataType {
 			// TensorElementDataType::Complex64 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64,
 			// TensorElementDataType::Complex128 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128,
 			#[cfg(feature = "half")]
-			TensorElementType::Bfloat16 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16
+			TensorElementType::Bfloat16 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16,
+			#[cfg(feature = "float8")]
+			TensorElementType::Float8E4M3FN => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN,
+			#[cfg(feature = "float8")]
+			TensorElementType::Float8E4M3FNUZ => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FNUZ,
+			#[cfg(feature = "float8")]
+			TensorElementType::Float8E5M2 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2,
+			#[cfg(feature = "float8")]
+			TensorElementType::Float8E5M2FNUZ => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2FNUZ
 		}
 	}
 }
+impl TensorElementType {
+	/// Returns the size in bytes of a single element of this type, or `None` for [`TensorElementType::String`], which
+	/// does not have a fixed size.
+	pub(crate) fn byte_size(&self) -> Option<usize> {
+		match self {
+			TensorElementType::Uint8 | TensorElementType::Int8 | TensorElementType::Bool => Some(1),
+			TensorElementType::Uint16 | TensorElementType::Int16 => Some(2),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 | TensorElementType::Bfloat16 => Some(2),
+			#[cfg(feature = "float8")]
+			TensorElementType::Float8E4M3FN | TensorElementType::Float8E4M3FNUZ | TensorElementType::Float8E5M2 | TensorElementType::Float8E5M2FNUZ => Some(1),
+			TensorElementType::Float32 | TensorElementType::Int32 | TensorElementType::Uint32 => Some(4),
+			TensorElementType::Float64 | TensorElementType::Int64 | TensorElementType::Uint64 => Some(8),
+			TensorElementType::String => None
+		}
+	}
+}
+
 impl From<ort_sys::ONNXTensorElementDataType> for TensorElementType {
 	fn from(val: ort_sys::ONNXTensorElementDataType) -> Self {
 		match val {
@@ -92,6 +138,14 @@ impl From<ort_sys::ONNXTensorElementDataType> for TensorElementType {
 			// ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128 => TensorElementDataType::Complex128,
 			#[cfg(feature = "half")]
 			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 => TensorElementType::Bfloat16,
+			#[cfg(feature = "float8")]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN => TensorElementType::Float8E4M3FN,
+			#[cfg(feature = "float8")]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FNUZ => TensorElementType::Float8E4M3FNUZ,
+			#[cfg(feature = "float8")]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2 => TensorElementType::Float8E5M2,
+			#[cfg(feature = "float8")]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2FNUZ => TensorElementType::Float8E5M2FNUZ,
 			_ => panic!("Invalid ONNXTensorElementDataType value")
 		}
 	}
@@ -132,6 +186,18 @@ impl_type_trait!(u64, Uint64);
 #[cfg(feature = "half")]
 #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
 impl_type_trait!(half::bf16, Bfloat16);
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+impl_type_trait!(super::float8::Float8E4M3FN, Float8E4M3FN);
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+impl_type_trait!(super::float8::Float8E4M3FNUZ, Float8E4M3FNUZ);
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+impl_type_trait!(super::float8::Float8E5M2, Float8E5M2);
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+impl_type_trait!(super::float8::Float8E5M2FNUZ, Float8E5M2FNUZ);
 
 /// Adapter for common Rust string types to ONNX strings.
 ///
@@ -238,6 +304,18 @@ impl_prim_type_from_ort_trait!(half::f16, Float16);
 #[cfg(feature = "half")]
 #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
 impl_prim_type_from_ort_trait!(half::bf16, Bfloat16);
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+impl_prim_type_from_ort_trait!(super::float8::Float8E4M3FN, Float8E4M3FN);
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+impl_prim_type_from_ort_trait!(super::float8::Float8E4M3FNUZ, Float8E4M3FNUZ);
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+impl_prim_type_from_ort_trait!(super::float8::Float8E5M2, Float8E5M2);
+#[cfg(feature = "float8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float8")))]
+impl_prim_type_from_ort_trait!(super::float8::Float8E5M2FNUZ, Float8E5M2FNUZ);
 impl_prim_type_from_ort_trait!(f32, Float32);
 impl_prim_type_from_ort_trait!(f64, Float64);
 impl_prim_type_from_ort_trait!(u8, Uint8);
@@ -248,7 +326,68 @@ impl_prim_type_from_ort_trait!(i8, Int8);
 impl_prim_type_from_ort_trait!(i16, Int16);
 impl_prim_type_from_ort_trait!(i32, Int32);
 impl_prim_type_from_ort_trait!(i64, Int64);
-impl_prim_type_from_ort_trait!(bool, Bool);
+
+/// Converts tensor dimensions reported by ONNX Runtime (as `int64_t`) into `usize`s.
+///
+/// Unlike an `as` cast, this returns an error instead of silently truncating dimensions that don't fit in a `usize`
+/// on 32-bit targets, or wrapping negative (symbolic) dimensions.
+pub(crate) fn dims_to_usize(dims: &[i64]) -> Result<Vec<usize>> {
+	dims.iter().map(|&d| usize::try_from(d).map_err(|_| Error::InvalidDimension(d))).collect()
+}
+
+/// Returns the number of elements described by `shape`. An empty shape describes a 0-dimensional (scalar) tensor with
+/// exactly one element, and a shape containing a `0` dimension describes an empty tensor.
+///
+/// Returns an error if any dimension is negative, or if the element count overflows a `usize`.
+pub(crate) fn element_count(shape: &[i64]) -> Result<usize> {
+	dims_to_usize(shape)?
+		.into_iter()
+		.try_fold(1_usize, |acc, d| acc.checked_mul(d))
+		.ok_or_else(|| Error::ShapeTooLarge(shape.to_vec()))
+}
+
+/// Ensures the number of elements described by `shape` matches the length of the tensor's data, so that ONNX Runtime
+/// never reads past the end of it.
+pub(crate) fn validate_shape(shape: &[i64], len: usize) -> Result<()> {
+	let expected = element_count(shape)?;
+	if expected != len {
+		return Err(Error::ShapeDataMismatch {
+			shape: shape.to_vec(),
+			expected,
+			actual: len
+		});
+	}
+	Ok(())
+}
+
+/// Ensures every byte of a boolean tensor's data is a valid Rust `bool` (`0` or `1`).
+///
+/// ONNX Runtime stores booleans as single bytes, but does not guarantee that they only hold `0` or `1`; reinterpreting
+/// any other value as a `bool` would be undefined behavior.
+pub(crate) fn validate_bool_data(data: &[u8]) -> Result<()> {
+	match data.iter().find(|&&b| b > 1) {
+		Some(&b) => Err(Error::InvalidBoolValue(b)),
+		None => Ok(())
+	}
+}
+
+impl ExtractTensorData for bool {
+	fn tensor_element_type() -> TensorElementType {
+		TensorElementType::Bool
+	}
+
+	#[cfg(feature = "ndarray")]
+	fn extract_tensor_array<'t, D>(shape: D, _tensor_element_len: usize, tensor_ptr: *mut ort_sys::OrtValue) -> Result<TensorData<'t, Self>>
+	where
+		D: ndarray::Dimension
+	{
+		// read the data as bytes first so that no invalid `bool`s are ever constructed
+		let bytes: ndarray::ArrayView<'t, u8, ndarray::IxDyn> = extract_primitive_array(shape, tensor_ptr)?;
+		validate_bool_data(bytes.as_slice().expect("tensor should be contiguous"))?;
+		let array_view = unsafe { ndarray::ArrayView::from_shape_ptr(bytes.raw_dim(), bytes.as_ptr() as *const bool) };
+		Ok(TensorData::PrimitiveView { ptr: tensor_ptr, array_view })
+	}
+}
 
 impl ExtractTensorData for String {
 	fn tensor_element_type() -> TensorElementType {
@@ -0,0 +1,39 @@
+//! Helpers shared between unit tests.
+
+use std::{
+	io,
+	sync::{Arc, Mutex}
+};
+
+use tracing::Level;
+
+/// An in-memory writer for `tracing_subscriber`, collecting everything that gets logged.
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for LogBuffer {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.0.lock().unwrap().write(buf)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+/// Runs `f` with a `tracing` subscriber enabled for events up to `max_level`, returning the result of `f` along with
+/// the formatted log output.
+pub(crate) fn capture_logs<R>(max_level: Level, f: impl FnOnce() -> R) -> (R, String) {
+	let buffer = LogBuffer::default();
+	let subscriber = tracing_subscriber::fmt()
+		.with_max_level(max_level)
+		.with_ansi(false)
+		.with_writer({
+			let buffer = buffer.clone();
+			move || buffer.clone()
+		})
+		.finish();
+	let result = tracing::subscriber::with_default(subscriber, f);
+	let output = buffer.0.lock().unwrap().clone();
+	(result, String::from_utf8(output).unwrap())
+}
@@ -0,0 +1,415 @@
+//! Contains the [`TrainingSession`] and [`Checkpoint`] types for on-device training with ONNX Runtime's training API.
+
+#[cfg(not(target_family = "windows"))]
+use std::os::unix::ffi::OsStrExt;
+#[cfg(target_family = "windows")]
+use std::os::windows::ffi::OsStrExt;
+use std::{
+	ffi::CString,
+	os::raw::c_char,
+	path::Path,
+	ptr,
+	sync::{
+		atomic::{AtomicPtr, Ordering},
+		Arc, OnceLock
+	}
+};
+
+use compact_str::CompactString;
+
+use crate::{
+	environment::Environment,
+	error::{assert_non_null_pointer, status_to_result},
+	execution_providers::apply_execution_providers,
+	memory::Allocator,
+	ortsys,
+	session::{check_input_count, dangerous::raw_pointer_to_string, IntoInputs, RunOptions, SessionInputs, SessionOutputs},
+	value::Value,
+	Error, Result, SessionBuilder
+};
+
+static G_TRAINING_API: OnceLock<AtomicPtr<ort_sys::OrtTrainingApi>> = OnceLock::new();
+
+/// Attempts to acquire the global [`ort_sys::OrtTrainingApi`] object.
+///
+/// Returns [`Error::TrainingNotSupported`] if the loaded ONNX Runtime binary was not built with training support.
+pub(crate) fn training_api() -> Result<ort_sys::OrtTrainingApi> {
+	let api_ptr = G_TRAINING_API
+		.get_or_init(|| AtomicPtr::new(ortsys![unsafe GetTrainingApi(ort_sys::ORT_API_VERSION)] as *mut ort_sys::OrtTrainingApi))
+		.load(Ordering::Relaxed);
+	if api_ptr.is_null() {
+		return Err(Error::TrainingNotSupported);
+	}
+	Ok(unsafe { *api_ptr })
+}
+
+macro_rules! trainsys {
+	(unsafe $method:ident($($n:expr),+ $(,)?)) => {
+		unsafe { $crate::training::training_api()?.$method.unwrap()($($n),+) }
+	};
+	(unsafe $method:ident($($n:expr),+ $(,)?) -> $err:expr$(;)?) => {
+		$crate::error::status_to_result(unsafe { $crate::training::training_api()?.$method.unwrap()($($n),+) }).map_err($err)?;
+	};
+	(unsafe $method:ident($($n:expr),+ $(,)?) -> $err:expr; nonNull($($check:expr),+ $(,)?)$(;)?) => {{
+		$crate::error::status_to_result(unsafe { $crate::training::training_api()?.$method.unwrap()($($n),+) }).map_err($err)?;
+		$($crate::error::assert_non_null_pointer($check, stringify!($method))?;)+
+	}};
+}
+
+/// Converts a path into a null-terminated string of ONNX Runtime's platform-specific character type.
+fn path_to_ortchar(path: &Path) -> Vec<ort_sys::ortchar> {
+	let path = std::ffi::OsString::from(path);
+	#[cfg(target_family = "windows")]
+	let path: Vec<u16> = path
+		.encode_wide()
+		.chain(std::iter::once(0)) // Make sure we have a null terminated string
+		.collect();
+	#[cfg(not(target_family = "windows"))]
+	let path: Vec<std::os::raw::c_char> = path
+		.as_bytes()
+		.iter()
+		.chain(std::iter::once(&b'\0')) // Make sure we have a null terminated string
+		.map(|b| *b as std::os::raw::c_char)
+		.collect();
+	path
+}
+
+/// A training checkpoint, holding the state of the model's trainable parameters and, optionally, the optimizer state.
+///
+/// Checkpoints are produced offline alongside the training, evaluation & optimizer models via
+/// `onnxruntime.training.artifacts.generate_artifacts`, and can be saved again after training with
+/// [`TrainingSession::save_checkpoint`].
+#[derive(Debug)]
+pub struct Checkpoint {
+	pub(crate) ptr: *mut ort_sys::OrtCheckpointState
+}
+
+impl Checkpoint {
+	/// Loads a checkpoint from the given path.
+	pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		if !path.exists() {
+			return Err(Error::FileDoesNotExist { filename: path.to_path_buf() });
+		}
+
+		let path = path_to_ortchar(path);
+		let mut ptr: *mut ort_sys::OrtCheckpointState = ptr::null_mut();
+		trainsys![unsafe LoadCheckpoint(path.as_ptr(), &mut ptr) -> Error::LoadCheckpoint; nonNull(ptr)];
+		Ok(Self { ptr })
+	}
+
+	/// Saves this checkpoint to the given path. If `include_optimizer_state` is `true`, the optimizer's state will also
+	/// be saved so that training can be resumed later.
+	pub fn save(&self, path: impl AsRef<Path>, include_optimizer_state: bool) -> Result<()> {
+		let path = path_to_ortchar(path.as_ref());
+		trainsys![unsafe SaveCheckpoint(self.ptr, path.as_ptr(), include_optimizer_state) -> Error::SaveCheckpoint];
+		Ok(())
+	}
+}
+
+impl Drop for Checkpoint {
+	#[tracing::instrument]
+	fn drop(&mut self) {
+		if !self.ptr.is_null() {
+			if let Ok(api) = training_api() {
+				unsafe { api.ReleaseCheckpointState.unwrap()(self.ptr) };
+			}
+		}
+		self.ptr = ptr::null_mut();
+	}
+}
+
+/// A session used to train (or fine-tune) a model with ONNX Runtime's on-device training API.
+///
+/// Training sessions are created from a [`Checkpoint`] and a set of training artifacts via
+/// [`SessionBuilder::with_training_artifacts`].
+///
+/// A typical training loop looks like this:
+///
+/// ```no_run
+/// # use ort::{Checkpoint, Session};
+/// # fn main() -> ort::Result<()> {
+/// # let batches: Vec<[ort::Value; 2]> = vec![];
+/// let checkpoint = Checkpoint::load("checkpoint")?;
+/// let trainer = Session::builder()?.with_training_artifacts(checkpoint, "training_model.onnx", Some("eval_model.onnx"), Some("optimizer_model.onnx"))?;
+/// for batch in batches {
+/// 	let _loss = trainer.train_step(batch)?;
+/// 	trainer.optimizer_step()?;
+/// 	trainer.lazy_reset_grad()?;
+/// }
+/// trainer.save_checkpoint("checkpoint", false)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TrainingSession {
+	inner: Arc<SharedTrainingSessionInner>,
+	allocator: Allocator,
+	train_input_names: Vec<String>,
+	train_output_names: Vec<String>,
+	eval_input_names: Vec<String>,
+	eval_output_names: Vec<String>
+}
+
+unsafe impl Send for TrainingSession {}
+
+/// Holds onto the training session's pointer and its [`Checkpoint`], so that outputs of
+/// [`TrainingSession::train_step`] & [`TrainingSession::eval_step`] can stay alive after the session is dropped.
+#[derive(Debug)]
+pub(crate) struct SharedTrainingSessionInner {
+	ptr: *mut ort_sys::OrtTrainingSession,
+	checkpoint: Checkpoint,
+	_environment: Arc<Environment>
+}
+
+unsafe impl Send for SharedTrainingSessionInner {}
+unsafe impl Sync for SharedTrainingSessionInner {}
+
+impl Drop for SharedTrainingSessionInner {
+	#[tracing::instrument]
+	fn drop(&mut self) {
+		// the training session must be released before the checkpoint, which is dropped automatically after this
+		if !self.ptr.is_null() {
+			if let Ok(api) = training_api() {
+				unsafe { api.ReleaseTrainingSession.unwrap()(self.ptr) };
+			}
+		}
+		self.ptr = ptr::null_mut();
+	}
+}
+
+impl SessionBuilder {
+	/// Loads a set of training artifacts and commits the builder's configuration into a [`TrainingSession`].
+	///
+	/// The evaluation & optimizer models are optional; [`TrainingSession::eval_step`] and
+	/// [`TrainingSession::optimizer_step`] will fail if their respective model was not provided.
+	#[cfg_attr(docsrs, doc(cfg(feature = "training")))]
+	pub fn with_training_artifacts<P>(
+		self,
+		checkpoint: Checkpoint,
+		training_model: P,
+		eval_model: Option<P>,
+		optimizer_model: Option<P>
+	) -> Result<TrainingSession>
+	where
+		P: AsRef<Path>
+	{
+		for model in std::iter::once(training_model.as_ref())
+			.chain(eval_model.as_ref().map(|p| p.as_ref()))
+			.chain(optimizer_model.as_ref().map(|p| p.as_ref()))
+		{
+			if !model.exists() {
+				return Err(Error::FileDoesNotExist { filename: model.to_path_buf() });
+			}
+		}
+
+		let training_model = path_to_ortchar(training_model.as_ref());
+		let eval_model = eval_model.map(|p| path_to_ortchar(p.as_ref()));
+		let optimizer_model = optimizer_model.map(|p| path_to_ortchar(p.as_ref()));
+
+		let env = self.environment()?;
+		apply_execution_providers(&self, self.execution_providers.iter().chain(&env.execution_providers).cloned())?;
+
+		let env_ptr = env.env_ptr.load(Ordering::Relaxed);
+
+		let mut ptr: *mut ort_sys::OrtTrainingSession = ptr::null_mut();
+		trainsys![
+			unsafe CreateTrainingSession(
+				env_ptr,
+				self.session_options_ptr,
+				checkpoint.ptr,
+				training_model.as_ptr(),
+				eval_model.as_ref().map_or_else(ptr::null, |p| p.as_ptr()),
+				optimizer_model.as_ref().map_or_else(ptr::null, |p| p.as_ptr()),
+				&mut ptr
+			) -> Error::CreateTrainingSession;
+			nonNull(ptr)
+		];
+
+		let allocator = Allocator::default();
+		let mut session = TrainingSession {
+			inner: Arc::new(SharedTrainingSessionInner {
+				ptr,
+				checkpoint,
+				_environment: Arc::clone(&env)
+			}),
+			allocator,
+			train_input_names: Vec::new(),
+			train_output_names: Vec::new(),
+			eval_input_names: Vec::new(),
+			eval_output_names: Vec::new()
+		};
+
+		let api = training_api()?;
+		session.train_input_names =
+			session.extract_names(api.TrainingSessionGetTrainingModelInputCount.unwrap(), api.TrainingSessionGetTrainingModelInputName.unwrap())?;
+		session.train_output_names =
+			session.extract_names(api.TrainingSessionGetTrainingModelOutputCount.unwrap(), api.TrainingSessionGetTrainingModelOutputName.unwrap())?;
+		if eval_model.is_some() {
+			session.eval_input_names =
+				session.extract_names(api.TrainingSessionGetEvalModelInputCount.unwrap(), api.TrainingSessionGetEvalModelInputName.unwrap())?;
+			session.eval_output_names =
+				session.extract_names(api.TrainingSessionGetEvalModelOutputCount.unwrap(), api.TrainingSessionGetEvalModelOutputName.unwrap())?;
+		}
+
+		Ok(session)
+	}
+}
+
+impl TrainingSession {
+	fn extract_names(
+		&self,
+		count_fn: crate::extern_system_fn! { unsafe fn(*const ort_sys::OrtTrainingSession, *mut ort_sys::size_t) -> ort_sys::OrtStatusPtr },
+		name_fn: crate::extern_system_fn! {
+			unsafe fn(*const ort_sys::OrtTrainingSession, ort_sys::size_t, *mut ort_sys::OrtAllocator, *mut *mut c_char) -> ort_sys::OrtStatusPtr
+		}
+	) -> Result<Vec<String>> {
+		let mut count: ort_sys::size_t = 0;
+		status_to_result(unsafe { count_fn(self.inner.ptr, &mut count) }).map_err(Error::CreateTrainingSession)?;
+		(0..count)
+			.map(|i| {
+				let mut name: *mut c_char = ptr::null_mut();
+				status_to_result(unsafe { name_fn(self.inner.ptr, i, self.allocator.ptr, &mut name) }).map_err(Error::GetInputName)?;
+				assert_non_null_pointer(name, "InputName")?;
+				raw_pointer_to_string(self.allocator.ptr, name)
+			})
+			.collect()
+	}
+
+	/// Returns the [`Checkpoint`] this session is training.
+	pub fn checkpoint(&self) -> &Checkpoint {
+		&self.inner.checkpoint
+	}
+
+	/// Orders the given inputs positionally according to `input_names`.
+	fn order_inputs<'v, const N: usize>(input_names: &[String], inputs: &'v SessionInputs<'_, N>) -> Result<Vec<&'v Value>> {
+		match inputs {
+			SessionInputs::ValueSlice(values) => {
+				check_input_count(input_names.len(), values.len())?;
+				Ok(values.iter().collect())
+			}
+			SessionInputs::ValueArray(values) => {
+				check_input_count(input_names.len(), values.len())?;
+				Ok(values.iter().collect())
+			}
+			SessionInputs::ValueMap(values) => input_names
+				.iter()
+				.map(|name| {
+					values
+						.get(&CompactString::new(name))
+						.ok_or_else(|| Error::TrainingInputMissing(name.to_owned()))
+				})
+				.collect()
+		}
+	}
+
+	/// Performs a single training step: computes the model's outputs (including the loss) for the given inputs, and
+	/// accumulates the gradients of the trainable parameters.
+	///
+	/// Gradients accumulate across calls until [`TrainingSession::lazy_reset_grad`] is called.
+	pub fn train_step<'s, 'i, const N: usize>(&'s self, inputs: impl IntoInputs<'i, N>) -> Result<SessionOutputs<'s>> {
+		self.train_step_inner(inputs.into_inputs()?, None)
+	}
+
+	/// Performs a single training step with the given [`RunOptions`]. See [`TrainingSession::train_step`].
+	pub fn train_step_with_options<'s, 'i, const N: usize>(
+		&'s self,
+		inputs: impl IntoInputs<'i, N>,
+		run_options: Arc<RunOptions>
+	) -> Result<SessionOutputs<'s>> {
+		self.train_step_inner(inputs.into_inputs()?, Some(run_options))
+	}
+
+	fn train_step_inner<const N: usize>(&self, inputs: SessionInputs<'_, N>, run_options: Option<Arc<RunOptions>>) -> Result<SessionOutputs<'_>> {
+		let inputs = Self::order_inputs(&self.train_input_names, &inputs)?;
+		let input_ptrs: Vec<*const ort_sys::OrtValue> = inputs.iter().map(|v| v.ptr() as *const _).collect();
+		let mut output_ptrs: Vec<*mut ort_sys::OrtValue> = vec![ptr::null_mut(); self.train_output_names.len()];
+		let run_options_ptr = run_options.as_ref().map_or_else(ptr::null_mut, |o| o.run_options_ptr);
+
+		trainsys![
+			unsafe TrainStep(
+				self.inner.ptr,
+				run_options_ptr,
+				input_ptrs.len() as _,
+				input_ptrs.as_ptr(),
+				output_ptrs.len() as _,
+				output_ptrs.as_mut_ptr()
+			) -> Error::TrainStep
+		];
+
+		let outputs = output_ptrs.into_iter().map(|ptr| unsafe { Value::from_raw_training(ptr, Arc::clone(&self.inner)) });
+		Ok(SessionOutputs::new(self.train_output_names.iter().map(|n| n.as_str()), outputs))
+	}
+
+	/// Computes the outputs of the evaluation model for the given inputs. This does not affect the gradients of the
+	/// trainable parameters.
+	pub fn eval_step<'s, 'i, const N: usize>(&'s self, inputs: impl IntoInputs<'i, N>) -> Result<SessionOutputs<'s>> {
+		let inputs = inputs.into_inputs()?;
+		let inputs = Self::order_inputs(&self.eval_input_names, &inputs)?;
+		let input_ptrs: Vec<*const ort_sys::OrtValue> = inputs.iter().map(|v| v.ptr() as *const _).collect();
+		let mut output_ptrs: Vec<*mut ort_sys::OrtValue> = vec![ptr::null_mut(); self.eval_output_names.len()];
+
+		trainsys![
+			unsafe EvalStep(
+				self.inner.ptr,
+				ptr::null(),
+				input_ptrs.len() as _,
+				input_ptrs.as_ptr(),
+				output_ptrs.len() as _,
+				output_ptrs.as_mut_ptr()
+			) -> Error::EvalStep
+		];
+
+		let outputs = output_ptrs.into_iter().map(|ptr| unsafe { Value::from_raw_training(ptr, Arc::clone(&self.inner)) });
+		Ok(SessionOutputs::new(self.eval_output_names.iter().map(|n| n.as_str()), outputs))
+	}
+
+	/// Updates the trainable parameters using the gradients accumulated by [`TrainingSession::train_step`], according
+	/// to the optimizer model.
+	pub fn optimizer_step(&self) -> Result<()> {
+		trainsys![unsafe OptimizerStep(self.inner.ptr, ptr::null()) -> Error::OptimizerStep];
+		Ok(())
+	}
+
+	/// Resets the gradients of all trainable parameters to zero. The reset is performed lazily, at the start of the next
+	/// [`TrainingSession::train_step`].
+	pub fn lazy_reset_grad(&self) -> Result<()> {
+		trainsys![unsafe LazyResetGrad(self.inner.ptr) -> Error::LazyResetGrad];
+		Ok(())
+	}
+
+	/// Sets the learning rate used by the optimizer.
+	pub fn set_learning_rate(&self, learning_rate: f32) -> Result<()> {
+		trainsys![unsafe SetLearningRate(self.inner.ptr, learning_rate) -> Error::SetLearningRate];
+		Ok(())
+	}
+
+	/// Returns the learning rate currently used by the optimizer.
+	pub fn learning_rate(&self) -> Result<f32> {
+		let mut learning_rate = 0.0;
+		trainsys![unsafe GetLearningRate(self.inner.ptr, &mut learning_rate) -> Error::GetLearningRate];
+		Ok(learning_rate)
+	}
+
+	/// Saves the current state of the training session's [`Checkpoint`] to the given path.
+	pub fn save_checkpoint(&self, path: impl AsRef<Path>, include_optimizer_state: bool) -> Result<()> {
+		self.inner.checkpoint.save(path, include_optimizer_state)
+	}
+
+	/// Exports the trained model as an inference-only model, keeping only the graph outputs named in `output_names`.
+	///
+	/// The evaluation model must have been provided to create this session.
+	pub fn export<S: AsRef<str>>(&self, path: impl AsRef<Path>, output_names: impl AsRef<[S]>) -> Result<()> {
+		let path = path_to_ortchar(path.as_ref());
+		let output_names = output_names
+			.as_ref()
+			.iter()
+			.map(|n| CString::new(n.as_ref()))
+			.collect::<Result<Vec<_>, _>>()?;
+		let output_name_ptrs: Vec<*const c_char> = output_names.iter().map(|n| n.as_ptr()).collect();
+		trainsys![
+			unsafe ExportModelForInferencing(self.inner.ptr, path.as_ptr(), output_name_ptrs.len() as _, output_name_ptrs.as_ptr()) -> Error::ExportModel
+		];
+		Ok(())
+	}
+}
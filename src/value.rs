@@ -1,7 +1,17 @@
-use std::{any::Any, collections::HashMap, ffi, fmt::Debug, hash::Hash, marker::PhantomData, ops::Deref, ptr, sync::Arc};
+use std::{
+	any::Any,
+	collections::HashMap,
+	ffi,
+	fmt::{self, Debug},
+	hash::Hash,
+	marker::PhantomData,
+	ops::Deref,
+	ptr,
+	sync::Arc
+};
 
 #[cfg(feature = "ndarray")]
-use ndarray::{ArcArray, Array, ArrayView, CowArray, Dimension, IxDyn};
+use ndarray::{ArcArray, Array, ArrayView, ArrayViewMut, CowArray, Dimension, IxDyn};
 
 #[cfg(feature = "ndarray")]
 use crate::tensor::Tensor;
@@ -10,7 +20,7 @@ use crate::{
 	memory::{Allocator, MemoryInfo},
 	ortsys,
 	session::SharedSessionInner,
-	tensor::{ExtractTensorData, IntoTensorElementType, TensorElementType, Utf8Data},
+	tensor::{dims_to_usize, element_count, validate_bool_data, validate_shape, ExtractTensorData, IntoTensorElementType, TensorElementType, Utf8Data},
 	AllocatorType, Error, MemoryType, Result
 };
 
@@ -31,6 +41,17 @@ impl ValueType {
 	}
 }
 
+/// Formats the type as e.g. `Tensor<Float32>[1, 3]`, `Sequence<Tensor<Int64>[-1]>`, or `Map<String, Float32>`.
+impl fmt::Display for ValueType {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ValueType::Tensor { ty, dimensions } => write!(f, "Tensor<{ty:?}>{dimensions:?}"),
+			ValueType::Sequence(element) => write!(f, "Sequence<{element}>"),
+			ValueType::Map { key, value } => write!(f, "Map<{key:?}, {value:?}>")
+		}
+	}
+}
+
 #[doc(hidden)]
 #[derive(Debug)]
 #[cfg(feature = "ndarray")]
@@ -106,15 +127,26 @@ pub(crate) enum ValueInner {
 	RustOwned {
 		ptr: *mut ort_sys::OrtValue,
 		_array: Box<dyn Any>,
-		_memory_info: MemoryInfo
+		_memory_info: MemoryInfo,
+		/// Whether the value exclusively owns its data, and thus may be mutated via [`Value::try_view_tensor_mut`].
+		/// This is `false` for values which borrow or share their data with something else.
+		#[cfg_attr(not(feature = "ndarray"), allow(dead_code))]
+		exclusive: bool
 	},
 	CppOwned {
 		ptr: *mut ort_sys::OrtValue,
 		/// Hold [`SharedSessionInner`] to ensure that the value can stay alive after the main session is dropped.
 		_session: Arc<SharedSessionInner>
 	},
-	/// A version of `CppOwned` that does not belong to a session. Used exclusively in [`ValueRef`]s which are returned
-	/// by `extract_sequence` and used temporarily in `extract_map`.
+	/// An output of a [`crate::TrainingSession`]; like `CppOwned`, this holds onto the training session so the value can
+	/// outlive it.
+	#[cfg(feature = "training")]
+	CppOwnedTraining {
+		ptr: *mut ort_sys::OrtValue,
+		_session: Arc<crate::training::SharedTrainingSessionInner>
+	},
+	/// A version of `CppOwned` that does not belong to a session. Used in [`ValueRef`]s which are returned by
+	/// `extract_sequence` and used temporarily in `extract_map`.
 	///
 	/// We forego holding onto an `Arc<SharedSessionInner>` here because:
 	/// - a map value can be created independently of a session, and thus we wouldn't have anything to hold on to;
@@ -124,7 +156,8 @@ pub(crate) enum ValueInner {
 
 /// A temporary version of [`Value`] with a lifetime specifier.
 ///
-/// This is used exclusively by [`Value::extract_sequence`] to ensure the sequence value outlives its child elements.
+/// This is used by [`Value::extract_sequence`] to ensure the sequence value outlives its child elements, and by
+/// `Value::from_mmap` to ensure the memory mapping outlives the tensor.
 #[derive(Debug)]
 pub struct ValueRef<'v> {
 	inner: Value,
@@ -141,9 +174,11 @@ impl<'v> Deref for ValueRef<'v> {
 
 /// A [`Value`] contains data for inputs/outputs in ONNX Runtime graphs. [`Value`]s can hold a tensor, sequence (array),
 /// or map.
-#[derive(Debug)]
+///
+/// Its [`Debug`] implementation shows the value's type & shape, along with the first few elements of numeric tensors
+/// in CPU memory; its [`Display`](fmt::Display) implementation only shows the type (see [`ValueType`]).
 pub struct Value {
-	inner: ValueInner
+	pub(crate) inner: ValueInner
 }
 
 unsafe impl Send for Value {}
@@ -160,7 +195,14 @@ impl Value {
 		}
 	}
 
-	unsafe fn from_raw_ref(ptr: *mut ort_sys::OrtValue) -> Value {
+	#[cfg(feature = "training")]
+	pub(crate) unsafe fn from_raw_training(ptr: *mut ort_sys::OrtValue, session: Arc<crate::training::SharedTrainingSessionInner>) -> Value {
+		Value {
+			inner: ValueInner::CppOwnedTraining { ptr, _session: session }
+		}
+	}
+
+	pub(crate) unsafe fn from_raw_ref(ptr: *mut ort_sys::OrtValue) -> Value {
 		Value {
 			inner: ValueInner::CppOwnedRef { ptr }
 		}
@@ -181,30 +223,283 @@ impl Value {
 		let mut typeinfo_ptr: *mut ort_sys::OrtTypeInfo = std::ptr::null_mut();
 		ortsys![unsafe GetTypeInfo(self.ptr(), &mut typeinfo_ptr) -> Error::GetTypeInfo; nonNull(typeinfo_ptr)];
 
-		let mut ty: ort_sys::ONNXType = ort_sys::ONNXType::ONNX_TYPE_UNKNOWN;
-		let status = ortsys![unsafe GetOnnxTypeFromTypeInfo(typeinfo_ptr, &mut ty)];
-		status_to_result(status).map_err(Error::GetOnnxTypeFromTypeInfo)?;
-		let io_type = match ty {
-			ort_sys::ONNXType::ONNX_TYPE_TENSOR | ort_sys::ONNXType::ONNX_TYPE_SPARSETENSOR => {
-				let mut info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
-				ortsys![unsafe CastTypeInfoToTensorInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToTensorInfo; nonNull(info_ptr)];
-				unsafe { extract_data_type_from_tensor_info(info_ptr)? }
-			}
-			ort_sys::ONNXType::ONNX_TYPE_SEQUENCE => {
-				let mut info_ptr: *const ort_sys::OrtSequenceTypeInfo = std::ptr::null_mut();
-				ortsys![unsafe CastTypeInfoToSequenceTypeInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToSequenceTypeInfo; nonNull(info_ptr)];
-				unsafe { extract_data_type_from_sequence_info(info_ptr)? }
+		let io_type = unsafe { extract_data_type_from_type_info(typeinfo_ptr) };
+		ortsys![unsafe ReleaseTypeInfo(typeinfo_ptr)];
+		io_type
+	}
+
+	/// Borrows the underlying tensor data as an [`ndarray::ArrayView`] with dimensionality `D`, without copying.
+	///
+	/// The view points directly into the tensor's memory and is bound to the lifetime of this `Value`. Returns an error
+	/// if the tensor's element type is not `T`, if its shape is not compatible with `D`, if it is a string tensor, or if
+	/// its data is not in CPU memory (e.g. an output bound to a CUDA device).
+	///
+	/// ```compile_fail
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let view = {
+	/// 	let value = Value::from_array(ndarray::Array1::<f32>::zeros(4))?;
+	/// 	value.try_view_tensor::<f32, ndarray::Ix1>()?
+	/// }; // `value` is dropped here while still borrowed by `view`
+	/// println!("{view:?}");
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_view_tensor<T, D>(&self) -> Result<ArrayView<'_, T, D>>
+	where
+		T: ExtractTensorData,
+		D: Dimension
+	{
+		let (shape, dimensions, data_ptr) = self.tensor_view_parts::<T>()?;
+		let view = unsafe { ArrayView::from_shape_ptr(shape, data_ptr as *const T) };
+		view.into_dimensionality::<D>().map_err(|e| Error::InvalidViewShape(dimensions, e))
+	}
+
+	/// Mutably borrows the underlying tensor data as an [`ndarray::ArrayViewMut`] with dimensionality `D`, without
+	/// copying. This is useful for post-processing session outputs in place (e.g. applying a softmax) without allocating
+	/// a new buffer.
+	///
+	/// Since this requires `&mut self`, the tensor can't be aliased by any other view while the mutable view is alive.
+	/// Mutations only affect this host copy of the tensor; they are not propagated back to the session, nor to any copy
+	/// of the output which may still exist on a device.
+	///
+	/// Only values which exclusively own their data can be mutated: those allocated by ONNX Runtime (such as session
+	/// outputs), or created from owned data (e.g. an `ndarray::Array`). Values which share or borrow their data - for
+	/// example, ones created with [`Value::from_array_view`], [`Value::from_shared`], or from a `&mut ArcArray` - return
+	/// [`Error::TensorNotMutable`]. Otherwise, this returns the same errors as [`Value::try_view_tensor`].
+	///
+	/// ```
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let mut value = Value::from_array(ndarray::Array1::<f32>::from_vec(vec![1.0, 2.0, 3.0]))?;
+	/// value.try_view_tensor_mut::<f32, ndarray::Ix1>()?.mapv_inplace(|x| x * 2.0);
+	/// assert_eq!(value.extract_raw_tensor::<f32>()?.1, &[2.0, 4.0, 6.0]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_view_tensor_mut<T, D>(&mut self) -> Result<ArrayViewMut<'_, T, D>>
+	where
+		T: ExtractTensorData,
+		D: Dimension
+	{
+		let mutable = match &self.inner {
+			ValueInner::RustOwned { exclusive, .. } => *exclusive,
+			ValueInner::CppOwned { .. } => true,
+			#[cfg(feature = "training")]
+			ValueInner::CppOwnedTraining { .. } => true,
+			ValueInner::CppOwnedRef { .. } => false
+		};
+		if !mutable {
+			return Err(Error::TensorNotMutable);
+		}
+		let (shape, dimensions, data_ptr) = self.tensor_view_parts::<T>()?;
+		let view = unsafe { ArrayViewMut::from_shape_ptr(shape, data_ptr as *mut T) };
+		view.into_dimensionality::<D>().map_err(|e| Error::InvalidViewShape(dimensions, e))
+	}
+
+	/// Validates that this value is a non-string tensor of `T`, returning its shape & a pointer to its data.
+	#[cfg(feature = "ndarray")]
+	fn tensor_view_parts<T: ExtractTensorData>(&self) -> Result<(IxDyn, Vec<i64>, *mut std::ffi::c_void)> {
+		let (ty, dimensions) = match self.dtype()? {
+			ValueType::Tensor { ty, dimensions } => (ty, dimensions),
+			t => return Err(Error::NotTensor(t))
+		};
+		if ty != T::tensor_element_type() {
+			return Err(Error::DataTypeMismatch {
+				actual: ty,
+				requested: T::tensor_element_type()
+			});
+		}
+		if ty == TensorElementType::String {
+			return Err(Error::StringTensorView);
+		}
+		if !self.is_cpu_tensor() {
+			return Err(Error::TensorNotInCpuMemory);
+		}
+
+		let shape = IxDyn(&dims_to_usize(&dimensions)?);
+		let len = shape.size();
+
+		let mut data_ptr: *mut std::ffi::c_void = ptr::null_mut();
+		ortsys![unsafe GetTensorMutableData(self.ptr(), &mut data_ptr) -> Error::GetTensorMutableData; nonNull(data_ptr)];
+		if ty == TensorElementType::Bool {
+			validate_bool_data(unsafe { std::slice::from_raw_parts(data_ptr as *const u8, len) })?;
+		}
+		Ok((shape, dimensions, data_ptr))
+	}
+
+	/// Copies the tensor's data out as raw bytes, along with its element type and shape. This allows tensors to be
+	/// serialized generically, without knowing their element type statically.
+	///
+	/// For numeric and boolean tensors, the bytes are the tensor's elements in row-major order, each encoded as
+	/// little-endian. For string tensors, each string is encoded as its length in bytes (as a little-endian `u64`),
+	/// followed by its UTF-8 bytes.
+	///
+	/// Returns [`Error::TensorNotInCpuMemory`] if the tensor's data lives on another device, e.g. a CUDA output bound
+	/// with [`crate::IoBinding`].
+	///
+	/// ```
+	/// # use ort::{TensorElementType, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array((vec![2], &[1_i32, -2][..]))?;
+	/// let (ty, shape, bytes) = value.to_bytes()?;
+	/// assert_eq!(ty, TensorElementType::Int32);
+	/// assert_eq!(shape, vec![2]);
+	/// assert_eq!(bytes, [1, 0, 0, 0, 0xfe, 0xff, 0xff, 0xff]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn to_bytes(&self) -> Result<(TensorElementType, Vec<i64>, Vec<u8>)> {
+		let (ty, dimensions) = match self.dtype()? {
+			ValueType::Tensor { ty, dimensions } => (ty, dimensions),
+			t => return Err(Error::NotTensor(t))
+		};
+		if !self.is_cpu_tensor() {
+			return Err(Error::TensorNotInCpuMemory);
+		}
+		let len = dims_to_usize(&dimensions)?.into_iter().product::<usize>();
+		if len == 0 {
+			return Ok((ty, dimensions, Vec::new()));
+		}
+
+		let bytes = match ty.byte_size() {
+			Some(element_size) => {
+				let mut data_ptr: *mut std::ffi::c_void = ptr::null_mut();
+				ortsys![unsafe GetTensorMutableData(self.ptr(), &mut data_ptr) -> Error::GetTensorMutableData; nonNull(data_ptr)];
+				let mut bytes = unsafe { std::slice::from_raw_parts(data_ptr as *const u8, len * element_size) }.to_vec();
+				if cfg!(target_endian = "big") {
+					for element in bytes.chunks_exact_mut(element_size) {
+						element.reverse();
+					}
+				}
+				bytes
 			}
-			ort_sys::ONNXType::ONNX_TYPE_MAP => {
-				let mut info_ptr: *const ort_sys::OrtMapTypeInfo = std::ptr::null_mut();
-				ortsys![unsafe CastTypeInfoToMapTypeInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToMapTypeInfo; nonNull(info_ptr)];
-				unsafe { extract_data_type_from_map_info(info_ptr)? }
+			None => {
+				let strings = self.string_tensor_contents(len)?;
+				let mut bytes = Vec::with_capacity(strings.iter().map(|s| s.len() + std::mem::size_of::<u64>()).sum());
+				for string in strings {
+					bytes.extend_from_slice(&(string.len() as u64).to_le_bytes());
+					bytes.extend_from_slice(&string);
+				}
+				bytes
 			}
-			_ => unreachable!()
 		};
+		Ok((ty, dimensions, bytes))
+	}
 
-		ortsys![unsafe ReleaseTypeInfo(typeinfo_ptr)];
-		Ok(io_type)
+	/// Reads the raw bytes of each of the `len` strings in a string tensor.
+	fn string_tensor_contents(&self, len: usize) -> Result<Vec<Vec<u8>>> {
+		let mut total_length: ort_sys::size_t = 0;
+		ortsys![unsafe GetStringTensorDataLength(self.ptr(), &mut total_length) -> Error::GetStringTensorDataLength];
+		let mut contents = vec![0u8; total_length as _];
+		let mut offsets: Vec<ort_sys::size_t> = vec![0; len + 1];
+		ortsys![
+			unsafe GetStringTensorContent(self.ptr(), contents.as_mut_ptr() as *mut std::ffi::c_void, total_length, offsets.as_mut_ptr(), len as _)
+				-> Error::GetStringTensorContent
+		];
+		// the last offset is the end of the last string
+		offsets[len] = total_length;
+		Ok(offsets.windows(2).map(|window| contents[window[0] as _..window[1] as _].to_vec()).collect())
+	}
+
+	/// Construct a tensor of type `element_type` with the given `shape` from its raw bytes. This is the inverse of
+	/// [`Value::to_bytes`], and uses the same encoding: for numeric and boolean tensors, `bytes` must contain exactly
+	/// `element_count * element_size` bytes, with each element encoded as little-endian. For string tensors, each
+	/// string is encoded as its length in bytes (as a little-endian `u64`), followed by its UTF-8 bytes.
+	///
+	/// The data is copied into a new tensor allocated by ONNX Runtime, so `bytes` need not be aligned.
+	///
+	/// ```
+	/// # use ort::{TensorElementType, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_bytes(TensorElementType::Int32, &[2], &[1, 0, 0, 0, 0xfe, 0xff, 0xff, 0xff])?;
+	/// assert_eq!(value.extract_raw_tensor::<i32>()?.1, [1, -2]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn from_bytes(element_type: TensorElementType, shape: &[i64], bytes: &[u8]) -> Result<Value> {
+		let len = element_count(shape)?;
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Arena, MemoryType::Default)?;
+		let allocator = Allocator::default();
+
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		match element_type.byte_size() {
+			Some(element_size) => {
+				let expected = len.checked_mul(element_size).ok_or_else(|| Error::ShapeTooLarge(shape.to_vec()))?;
+				if bytes.len() != expected {
+					return Err(Error::TensorBytesLengthMismatch {
+						element_type,
+						expected,
+						actual: bytes.len()
+					});
+				}
+				if element_type == TensorElementType::Bool {
+					validate_bool_data(bytes)?;
+				}
+
+				ortsys![
+					unsafe CreateTensorAsOrtValue(allocator.ptr, shape.as_ptr(), shape.len() as _, element_type.into(), &mut value_ptr)
+						-> Error::CreateTensor;
+					nonNull(value_ptr)
+				];
+				// take ownership now so the tensor is released if anything below fails
+				let value = Value {
+					inner: ValueInner::RustOwned {
+						ptr: value_ptr,
+						_array: Box::new(()),
+						_memory_info: memory_info,
+						exclusive: true
+					}
+				};
+				if len > 0 {
+					let mut data_ptr: *mut std::ffi::c_void = ptr::null_mut();
+					ortsys![unsafe GetTensorMutableData(value_ptr, &mut data_ptr) -> Error::GetTensorMutableData; nonNull(data_ptr)];
+					let data = unsafe { std::slice::from_raw_parts_mut(data_ptr as *mut u8, expected) };
+					data.copy_from_slice(bytes);
+					if cfg!(target_endian = "big") {
+						for element in data.chunks_exact_mut(element_size) {
+							element.reverse();
+						}
+					}
+				}
+				Ok(value)
+			}
+			None => {
+				let mut strings = Vec::with_capacity(len);
+				let mut rest = bytes;
+				for _ in 0..len {
+					let consumed = bytes.len() - rest.len();
+					let (length, tail) = rest.split_at(rest.len().min(std::mem::size_of::<u64>()));
+					let length = u64::from_le_bytes(length.try_into().map_err(|_| Error::TensorBytesLengthMismatch {
+						element_type,
+						expected: consumed + std::mem::size_of::<u64>(),
+						actual: bytes.len()
+					})?) as usize;
+					if tail.len() < length {
+						return Err(Error::TensorBytesLengthMismatch {
+							element_type,
+							expected: consumed + std::mem::size_of::<u64>() + length,
+							actual: bytes.len()
+						});
+					}
+					let (string, tail) = tail.split_at(length);
+					strings.push(String::from_utf8(string.to_vec())?);
+					rest = tail;
+				}
+				if !rest.is_empty() {
+					return Err(Error::TensorBytesLengthMismatch {
+						element_type,
+						expected: bytes.len() - rest.len(),
+						actual: bytes.len()
+					});
+				}
+				Value::from_string_array(&allocator, (shape.to_vec(), &strings[..]))
+			}
+		}
 	}
 
 	/// Attempt to extract the underlying data into a Rust `ndarray`.
@@ -225,7 +520,7 @@ impl Value {
 
 			let mut node_dims: Vec<i64> = vec![0; num_dims as _];
 			ortsys![unsafe GetDimensions(tensor_info_ptr, node_dims.as_mut_ptr(), num_dims as _) -> Error::GetDimensions];
-			let shape = IxDyn(&node_dims.iter().map(|&n| n as usize).collect::<Vec<_>>());
+			let shape = IxDyn(&dims_to_usize(&node_dims)?);
 
 			let mut type_sys = ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
 			ortsys![unsafe GetTensorElementType(tensor_info_ptr, &mut type_sys) -> Error::GetTensorElementType];
@@ -256,6 +551,35 @@ impl Value {
 		res
 	}
 
+	/// Extracts the single element of a tensor with exactly one element, such as a 0-dimensional (scalar) tensor or a
+	/// tensor of shape `[1]`. This is useful for models which output a single value, like a loss or score.
+	///
+	/// Returns an error if the tensor has more or less than one element, or its element type isn't `T`. String tensors
+	/// are not supported; use [`Value::extract_tensor`] for those instead.
+	///
+	/// ```
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let score = Value::from_array((vec![], &[0.93_f32][..]))?;
+	/// assert_eq!(score.try_extract_scalar::<f32>()?, 0.93);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn try_extract_scalar<T>(&self) -> Result<T>
+	where
+		T: ExtractTensorData + Clone + Debug
+	{
+		if T::tensor_element_type() == TensorElementType::String {
+			return Err(Error::StringTensorView);
+		}
+
+		let (shape, data) = self.extract_raw_tensor::<T>()?;
+		match data {
+			[value] => Ok(value.clone()),
+			_ => Err(Error::NotScalar(shape))
+		}
+	}
+
 	pub fn extract_raw_tensor<T>(&self) -> Result<(Vec<i64>, &[T])>
 	where
 		T: ExtractTensorData + Clone + Debug
@@ -296,6 +620,11 @@ impl Value {
 				let mut len = 0;
 				ortsys![unsafe GetTensorShapeElementCount(tensor_info_ptr, &mut len) -> Error::GetTensorShapeElementCount];
 
+				if data_type == TensorElementType::Bool {
+					// make sure the bytes are valid `bool`s before reinterpreting them
+					validate_bool_data(unsafe { std::slice::from_raw_parts(output_array_ptr as *const u8, len as _) })?;
+				}
+
 				Ok((node_dims, unsafe { std::slice::from_raw_parts(output_array_ptr, len as _) }))
 			}
 		};
@@ -325,7 +654,28 @@ impl Value {
 		}
 	}
 
-	pub fn extract_map<K: ExtractTensorData + Clone + Hash + Eq, V: ExtractTensorData + Clone>(&self, allocator: &Allocator) -> Result<HashMap<K, V>> {
+	/// Extracts the contents of a map, like those produced by classifiers in the `ai.onnx.ml` domain (e.g. the
+	/// `map(string, float)` label-to-probability outputs of `ZipMap`).
+	///
+	/// Both numeric and string keys & values are supported. ONNX Runtime doesn't require map keys to be unique; if a
+	/// key appears more than once, the value that appears last wins.
+	///
+	/// ```no_run
+	/// # use std::collections::HashMap;
+	/// # use ort::{inputs, Session};
+	/// # fn main() -> ort::Result<()> {
+	/// # let session = Session::builder()?.with_model_from_file("classifier.onnx")?;
+	/// # let features = [0.0_f32; 4];
+	/// let outputs = session.run(inputs![(vec![1, 4], &features[..])]?)?;
+	/// let probabilities = outputs["output_probability"].extract_sequence(session.allocator())?;
+	/// let probabilities: HashMap<String, f32> = probabilities[0].extract_map(session.allocator())?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn extract_map<K: ExtractTensorData + Clone + Hash + Eq + 'static, V: ExtractTensorData + Clone + 'static>(
+		&self,
+		allocator: &Allocator
+	) -> Result<HashMap<K, V>> {
 		match self.dtype()? {
 			ValueType::Map { key, value } => {
 				let k_type = K::tensor_element_type();
@@ -340,26 +690,45 @@ impl Value {
 				let mut key_tensor_ptr = ptr::null_mut();
 				ortsys![unsafe GetValue(self.ptr(), 0, allocator.ptr, &mut key_tensor_ptr) -> Error::ExtractMap; nonNull(key_tensor_ptr)];
 				let key_value = unsafe { Value::from_raw_ref(key_tensor_ptr) };
-				let (key_tensor_shape, key_tensor) = key_value.extract_raw_tensor::<K>()?;
+				let keys = key_value.extract_map_elements::<K>()?;
 
 				let mut value_tensor_ptr = ptr::null_mut();
 				ortsys![unsafe GetValue(self.ptr(), 1, allocator.ptr, &mut value_tensor_ptr) -> Error::ExtractMap; nonNull(value_tensor_ptr)];
 				let value_value = unsafe { Value::from_raw_ref(value_tensor_ptr) };
-				let (value_tensor_shape, value_tensor) = value_value.extract_raw_tensor::<V>()?;
+				let values = value_value.extract_map_elements::<V>()?;
 
-				assert_eq!(key_tensor_shape.len(), 1);
-				assert_eq!(value_tensor_shape.len(), 1);
-				assert_eq!(key_tensor_shape[0], value_tensor_shape[0]);
-
-				let mut vec = Vec::with_capacity(key_tensor_shape[0] as _);
-				for i in 0..key_tensor_shape[0] as usize {
-					vec.push((key_tensor[i].clone(), value_tensor[i].clone()));
-				}
-				Ok(vec.into_iter().collect())
+				assert_eq!(keys.len(), values.len());
+				Ok(keys.into_iter().zip(values).collect())
 			}
 			t => Err(Error::NotMap(t))
 		}
 	}
+
+	/// Extracts the elements of the 1-dimensional key or value tensor of a map.
+	fn extract_map_elements<T: ExtractTensorData + Clone + 'static>(&self) -> Result<Vec<T>> {
+		let dimensions = match self.dtype()? {
+			ValueType::Tensor { dimensions, .. } => dimensions,
+			t => return Err(Error::NotTensor(t))
+		};
+		assert_eq!(dimensions.len(), 1);
+		let len = dims_to_usize(&dimensions)?[0];
+		if len == 0 {
+			return Ok(Vec::new());
+		}
+
+		if T::tensor_element_type() == TensorElementType::String {
+			let strings = self
+				.string_tensor_contents(len)?
+				.into_iter()
+				.map(String::from_utf8)
+				.collect::<Result<Vec<String>, _>>()?;
+			// `T` can only be `String` here
+			let strings: Box<dyn Any> = Box::new(strings);
+			Ok(*strings.downcast::<Vec<T>>().expect("string tensor elements should be `String`s"))
+		} else {
+			Ok(self.extract_raw_tensor::<T>()?.1.to_vec())
+		}
+	}
 }
 
 pub trait OrtInput {
@@ -367,17 +736,36 @@ pub trait OrtInput {
 
 	fn ref_parts(&self) -> (Vec<i64>, &[Self::Item]);
 	fn into_parts(self) -> (Vec<i64>, *mut Self::Item, usize, Box<dyn Any>);
+
+	/// Returns whether the data returned by [`OrtInput::into_parts`] is exclusively owned by its guard, rather than
+	/// shared with something the caller can still access. Only tensors created from exclusively owned data can be
+	/// mutated via [`Value::try_view_tensor_mut`]. Defaults to `false`.
+	fn owns_data_exclusively(&self) -> bool {
+		false
+	}
 }
 
 impl Value {
 	/// Construct a [`Value`] from a Rust-owned array.
 	///
+	/// An empty shape creates a 0-dimensional (scalar) tensor, which must contain exactly one element:
+	///
+	/// ```
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let temperature = Value::from_array((vec![], &[0.7_f32][..]))?;
+	/// assert_eq!(temperature.extract_raw_tensor::<f32>()?, (vec![], &[0.7_f32][..]));
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
 	/// `allocator` is required to be `Some` when converting a String tensor. See [`crate::Session::allocator`].
 	pub fn from_array<T: IntoTensorElementType + Debug + Clone + 'static>(input: impl OrtInput<Item = T>) -> Result<Value> {
 		let memory_info = MemoryInfo::new_cpu(AllocatorType::Arena, MemoryType::Default)?;
 
 		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
 
+		let exclusive = input.owns_data_exclusively();
 		let guard = match T::into_tensor_element_type() {
 			TensorElementType::Float32
 			| TensorElementType::Uint8
@@ -393,6 +781,7 @@ impl Value {
 				// primitive data is already suitably laid out in memory; provide it to
 				// onnxruntime as is
 				let (shape, ptr, ptr_len, guard) = input.into_parts();
+				validate_shape(&shape, ptr_len)?;
 				let shape_ptr: *const i64 = shape.as_ptr();
 				let shape_len = shape.len();
 
@@ -421,6 +810,36 @@ impl Value {
 			TensorElementType::Bfloat16 | TensorElementType::Float16 => {
 				// f16 and bf16 are repr(transparent) to u16, so memory layout should be identical to onnxruntime
 				let (shape, ptr, ptr_len, guard) = input.into_parts();
+				validate_shape(&shape, ptr_len)?;
+				let shape_ptr: *const i64 = shape.as_ptr();
+				let shape_len = shape.len();
+
+				let tensor_values_ptr: *mut std::ffi::c_void = ptr as *mut std::ffi::c_void;
+				assert_non_null_pointer(tensor_values_ptr, "TensorValues")?;
+
+				ortsys![
+					unsafe CreateTensorWithDataAsOrtValue(
+						memory_info.ptr,
+						tensor_values_ptr,
+						(ptr_len * std::mem::size_of::<T>()) as _,
+						shape_ptr,
+						shape_len as _,
+						T::into_tensor_element_type().into(),
+						&mut value_ptr
+					) -> Error::CreateTensorWithData;
+					nonNull(value_ptr)
+				];
+
+				let mut is_tensor = 0;
+				ortsys![unsafe IsTensor(value_ptr, &mut is_tensor) -> Error::FailedTensorCheck];
+				assert_eq!(is_tensor, 1);
+				guard
+			}
+			#[cfg(feature = "float8")]
+			TensorElementType::Float8E4M3FN | TensorElementType::Float8E4M3FNUZ | TensorElementType::Float8E5M2 | TensorElementType::Float8E5M2FNUZ => {
+				// the float8 types are repr(transparent) to u8, so memory layout should be identical to onnxruntime
+				let (shape, ptr, ptr_len, guard) = input.into_parts();
+				validate_shape(&shape, ptr_len)?;
 				let shape_ptr: *const i64 = shape.as_ptr();
 				let shape_len = shape.len();
 
@@ -454,11 +873,29 @@ impl Value {
 			inner: ValueInner::RustOwned {
 				ptr: value_ptr,
 				_array: guard,
-				_memory_info: memory_info
+				_memory_info: memory_info,
+				exclusive
 			}
 		})
 	}
 
+	/// Construct a tensor with the given `shape` which takes ownership of `data`, without copying it. The `Vec` is kept
+	/// alive by the returned [`Value`] and freed when it is dropped.
+	///
+	/// ```
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let data = vec![1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+	/// let data_ptr = data.as_ptr();
+	/// let value = Value::from_vec(data, &[2, 3])?;
+	/// assert_eq!(value.extract_raw_tensor::<f32>()?.1.as_ptr(), data_ptr);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn from_vec<T: IntoTensorElementType + Debug + Clone + 'static>(data: Vec<T>, shape: &[i64]) -> Result<Value> {
+		Value::from_array((shape.to_vec(), data))
+	}
+
 	/// Construct a [`Value`] from a Rust-owned array.
 	pub fn from_string_array<T: Utf8Data + Debug + Clone + 'static>(allocator: &Allocator, input: impl OrtInput<Item = T>) -> Result<Value> {
 		let memory_info = MemoryInfo::new_cpu(AllocatorType::Arena, MemoryType::Default)?;
@@ -466,6 +903,7 @@ impl Value {
 		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
 
 		let (shape, data) = input.ref_parts();
+		validate_shape(&shape, data.len())?;
 		let shape_ptr: *const i64 = shape.as_ptr();
 		let shape_len = shape.len();
 
@@ -496,15 +934,327 @@ impl Value {
 			inner: ValueInner::RustOwned {
 				ptr: value_ptr,
 				_array: Box::new(()),
-				_memory_info: memory_info
+				_memory_info: memory_info,
+				exclusive: true
+			}
+		})
+	}
+
+	/// Construct a tensor that references data the caller has already placed on a device, such as a CUDA buffer from
+	/// your own allocation, so that ONNX Runtime reads directly from it without any copies.
+	///
+	/// `memory_info` describes the device `ptr` lives on, i.e.
+	/// `MemoryInfo::new(AllocationDevice::CUDA, 0, AllocatorType::Device, MemoryType::Default)` for memory on the first
+	/// CUDA device. The buffer is interpreted as a tensor of type `element_type` with the given `shape`; ONNX Runtime
+	/// will return an error if `len_bytes` is too small to hold the whole tensor. String tensors are not supported.
+	///
+	/// # Safety
+	///
+	/// - `ptr` must point to at least `len_bytes` bytes of memory on the device described by `memory_info`.
+	/// - The buffer must remain valid, and must not be freed or written to by anything else, for as long as the
+	///   returned [`Value`] is alive.
+	pub unsafe fn from_raw_device_ptr(
+		ptr: *mut std::ffi::c_void,
+		len_bytes: usize,
+		shape: Vec<i64>,
+		element_type: TensorElementType,
+		memory_info: &MemoryInfo
+	) -> Result<Value> {
+		if element_type.byte_size().is_none() {
+			return Err(Error::UnsupportedBufferElementType(element_type));
+		}
+		assert_non_null_pointer(ptr, "TensorValues")?;
+
+		// the value holds onto its own copy of the memory info, so the caller's may be dropped
+		let memory_info = memory_info.try_clone()?;
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr,
+				ptr,
+				len_bytes as _,
+				shape.as_ptr(),
+				shape.len() as _,
+				element_type.into(),
+				&mut value_ptr
+			) -> Error::CreateTensorWithData;
+			nonNull(value_ptr)
+		];
+
+		Ok(Value {
+			inner: ValueInner::RustOwned {
+				ptr: value_ptr,
+				_array: Box::new(()),
+				_memory_info: memory_info,
+				exclusive: false
+			}
+		})
+	}
+
+	/// Construct a tensor over a region of a memory-mapped file, without copying or reading the data into memory
+	/// up front. This is useful for very large inputs stored on disk, such as precomputed embeddings.
+	///
+	/// The tensor is `element_type` with the given `shape`, and its data starts `offset` bytes into the mapping. The
+	/// data is expected to be in the native byte order, and `offset` must be aligned to the size of `element_type`.
+	/// String tensors are not supported.
+	///
+	/// The returned [`ValueRef`] borrows the mapping, so it cannot outlive it. The mapping is read-only, so the value
+	/// must only be used as an input to a session.
+	///
+	/// ```no_run
+	/// # use ort::{inputs, Session, TensorElementType, Value};
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// # let session = Session::builder()?.with_model_from_file("model.onnx")?;
+	/// let file = std::fs::File::open("embeddings.bin")?;
+	/// let map = unsafe { memmap2::Mmap::map(&file)? };
+	/// let embeddings = Value::from_mmap(&map, 0, vec![100_000, 768], TensorElementType::Float32)?;
+	/// let outputs = session.run(std::slice::from_ref(&*embeddings))?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "memmap")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "memmap")))]
+	pub fn from_mmap(map: &memmap2::Mmap, offset: usize, shape: Vec<i64>, element_type: TensorElementType) -> Result<ValueRef<'_>> {
+		let element_size = element_type.byte_size().ok_or(Error::UnsupportedBufferElementType(element_type))?;
+		let len = dims_to_usize(&shape)?
+			.into_iter()
+			.try_fold(element_size, |acc, d| acc.checked_mul(d))
+			.ok_or(Error::MmapOutOfBounds { offset, len: usize::MAX, map_len: map.len() })?;
+		if offset.checked_add(len).map_or(true, |end| end > map.len()) {
+			return Err(Error::MmapOutOfBounds { offset, len, map_len: map.len() });
+		}
+		let data = map[offset..].as_ptr();
+		if data as usize % element_size != 0 {
+			return Err(Error::MmapMisaligned { offset, alignment: element_size });
+		}
+
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default)?;
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr,
+				// ONNX Runtime never writes to input tensors, so handing it a pointer to read-only memory is fine
+				data as *mut std::ffi::c_void,
+				len as _,
+				shape.as_ptr(),
+				shape.len() as _,
+				element_type.into(),
+				&mut value_ptr
+			) -> Error::CreateTensorWithData;
+			nonNull(value_ptr)
+		];
+
+		Ok(ValueRef {
+			inner: Value {
+				inner: ValueInner::RustOwned {
+					ptr: value_ptr,
+					_array: Box::new(()),
+					_memory_info: memory_info,
+					exclusive: false
+				}
+			},
+			lifetime: PhantomData
+		})
+	}
+
+	/// Construct a tensor that borrows the data of an [`ndarray::ArrayView`] without copying it.
+	///
+	/// ONNX Runtime tensors must be contiguous, so this returns [`Error::NonContiguousArray`] if the view is not in
+	/// standard (row-major, C-contiguous) layout, i.e. because it was transposed or sliced with a step. Use
+	/// [`Value::from_array_contiguous`] to explicitly copy such views instead. String tensors are not supported.
+	///
+	/// The returned [`ValueRef`] borrows the view's data, which is read-only, so the value must only be used as an input
+	/// to a session.
+	///
+	/// ```
+	/// # use ndarray::Array2;
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let array = Array2::<f32>::zeros((2, 3));
+	/// let value = Value::from_array_view(array.view())?;
+	/// assert!(Value::from_array_view(array.t()).is_err());
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn from_array_view<'v, T: IntoTensorElementType + Debug + Clone + 'static, D: Dimension + 'static>(view: ArrayView<'v, T, D>) -> Result<ValueRef<'v>> {
+		let element_type = T::into_tensor_element_type();
+		let element_size = element_type.byte_size().ok_or(Error::UnsupportedBufferElementType(element_type))?;
+		if !view.is_standard_layout() {
+			return Err(Error::NonContiguousArray);
+		}
+
+		let shape: Vec<i64> = view.shape().iter().map(|d| *d as i64).collect();
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default)?;
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr,
+				// ONNX Runtime never writes to input tensors, so handing it a pointer to borrowed data is fine
+				view.as_ptr() as *mut std::ffi::c_void,
+				(view.len() * element_size) as _,
+				shape.as_ptr(),
+				shape.len() as _,
+				element_type.into(),
+				&mut value_ptr
+			) -> Error::CreateTensorWithData;
+			nonNull(value_ptr)
+		];
+
+		Ok(ValueRef {
+			inner: Value {
+				inner: ValueInner::RustOwned {
+					ptr: value_ptr,
+					_array: Box::new(()),
+					_memory_info: memory_info,
+					exclusive: false
+				}
+			},
+			lifetime: PhantomData
+		})
+	}
+
+	/// Construct a tensor with the given `shape` over the data of an [`Arc`]'d buffer, without copying it. The returned
+	/// value holds a reference to the buffer, so it is only freed once the value (and any other owners of the `Arc`) are
+	/// dropped. This allows multiple values, i.e. for different sessions, to share one large input. String tensors are
+	/// not supported.
+	///
+	/// Since the buffer is shared, the value is read-only: [`Value::try_view_tensor_mut`] returns
+	/// [`Error::TensorNotMutable`] for it.
+	///
+	/// ```
+	/// # use std::sync::Arc;
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let data: Arc<[f32]> = Arc::from(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+	/// let a = Value::from_shared(Arc::clone(&data), &[2, 3])?;
+	/// let b = Value::from_shared(Arc::clone(&data), &[3, 2])?;
+	/// assert_eq!(a.extract_raw_tensor::<f32>()?.1.as_ptr(), b.extract_raw_tensor::<f32>()?.1.as_ptr());
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn from_shared<T: IntoTensorElementType + Debug + Clone + Send + Sync + 'static>(data: Arc<[T]>, shape: &[i64]) -> Result<Value> {
+		let element_type = T::into_tensor_element_type();
+		let element_size = element_type.byte_size().ok_or(Error::UnsupportedBufferElementType(element_type))?;
+		validate_shape(shape, data.len())?;
+
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default)?;
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr,
+				// ONNX Runtime never writes to input tensors, so the data can be shared
+				data.as_ptr() as *mut std::ffi::c_void,
+				(data.len() * element_size) as _,
+				shape.as_ptr(),
+				shape.len() as _,
+				element_type.into(),
+				&mut value_ptr
+			) -> Error::CreateTensorWithData;
+			nonNull(value_ptr)
+		];
+
+		Ok(Value {
+			inner: ValueInner::RustOwned {
+				ptr: value_ptr,
+				_array: Box::new(data),
+				_memory_info: memory_info,
+				exclusive: false
 			}
 		})
 	}
 
+	/// Construct a tensor with the given `shape` which borrows `data` without copying it, described by `memory_info`
+	/// rather than the default CPU memory info. This allows tagging the data with e.g. the NUMA node it was allocated on
+	/// (see [`MemoryInfo::new_cpu_on_numa_node`]). String tensors are not supported.
+	///
+	/// `memory_info` must describe host memory, i.e. its [`MemoryInfo::device_type`] must be
+	/// [`MemoryDeviceType::CPU`](crate::MemoryDeviceType::CPU); otherwise, [`Error::NonCpuMemoryInfo`] is returned.
+	/// The returned [`ValueRef`] borrows `data`, which is read-only, so the value must only be used as an input to a
+	/// session.
+	///
+	/// ```
+	/// # use ort::{AllocatorType, MemoryInfo, MemoryType, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let data = vec![1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+	/// let memory_info = MemoryInfo::new_cpu_on_numa_node(1, AllocatorType::Device, MemoryType::Default)?;
+	/// let value = Value::from_slice_with_memory_info(memory_info, &data, &[2, 3])?;
+	/// assert_eq!(value.extract_raw_tensor::<f32>()?.1.as_ptr(), data.as_ptr());
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn from_slice_with_memory_info<'v, T: IntoTensorElementType + Debug + Clone + 'static>(
+		memory_info: MemoryInfo,
+		data: &'v [T],
+		shape: &[i64]
+	) -> Result<ValueRef<'v>> {
+		let element_type = T::into_tensor_element_type();
+		let element_size = element_type.byte_size().ok_or(Error::UnsupportedBufferElementType(element_type))?;
+		validate_shape(shape, data.len())?;
+		let device_type = memory_info.device_type();
+		if device_type != crate::MemoryDeviceType::CPU {
+			return Err(Error::NonCpuMemoryInfo(device_type));
+		}
+
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr,
+				// ONNX Runtime never writes to input tensors, so handing it a pointer to read-only data is fine
+				data.as_ptr() as *mut std::ffi::c_void,
+				(data.len() * element_size) as _,
+				shape.as_ptr(),
+				shape.len() as _,
+				element_type.into(),
+				&mut value_ptr
+			) -> Error::CreateTensorWithData;
+			nonNull(value_ptr)
+		];
+
+		Ok(ValueRef {
+			inner: Value {
+				inner: ValueInner::RustOwned {
+					ptr: value_ptr,
+					_array: Box::new(()),
+					_memory_info: memory_info,
+					exclusive: false
+				}
+			},
+			lifetime: PhantomData
+		})
+	}
+
+	/// Construct a tensor from an [`ndarray::ArrayView`] by copying its data into a new contiguous buffer owned by the
+	/// returned [`Value`]. This always copies, even if the view is already contiguous; see [`Value::from_array_view`]
+	/// to borrow contiguous views without copying.
+	///
+	/// ```
+	/// # use ndarray::array;
+	/// # use ort::Value;
+	/// # fn main() -> ort::Result<()> {
+	/// let array = array![[1_i64, 2, 3], [4, 5, 6]];
+	/// let transposed = Value::from_array_contiguous(array.t())?;
+	/// assert_eq!(transposed.extract_raw_tensor::<i64>()?, (vec![3, 2], &[1, 4, 2, 5, 3, 6][..]));
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn from_array_contiguous<T: IntoTensorElementType + Debug + Clone + 'static, D: Dimension + 'static>(view: ArrayView<'_, T, D>) -> Result<Value> {
+		// `as_standard_layout` would borrow a contiguous view rather than copy it, so copy explicitly
+		let mut data = Vec::with_capacity(view.len());
+		data.extend(view.iter().cloned());
+		let shape: Vec<i64> = view.shape().iter().map(|d| *d as i64).collect();
+		Value::from_array((shape, Arc::new(data.into_boxed_slice())))
+	}
+
 	pub(crate) fn ptr(&self) -> *mut ort_sys::OrtValue {
 		match &self.inner {
 			ValueInner::CppOwnedRef { ptr } => *ptr,
 			ValueInner::CppOwned { ptr, .. } => *ptr,
+			#[cfg(feature = "training")]
+			ValueInner::CppOwnedTraining { ptr, .. } => *ptr,
 			ValueInner::RustOwned { ptr, .. } => *ptr
 		}
 	}
@@ -533,6 +1283,9 @@ where
 
 	fn into_parts(self) -> (Vec<i64>, *mut Self::Item, usize, Box<dyn Any>) {
 		// This will result in a copy in either form of the CowArray
+		if !self.is_standard_layout() {
+			tracing::debug!("array is not in standard layout; copying into a contiguous buffer");
+		}
 		let mut contiguous_array = self.as_standard_layout().into_owned();
 		let shape: Vec<i64> = contiguous_array.shape().iter().map(|d| *d as i64).collect();
 		let ptr = contiguous_array.as_mut_ptr();
@@ -540,6 +1293,11 @@ where
 		let guard = Box::new(contiguous_array);
 		(shape, ptr, ptr_len, guard)
 	}
+
+	fn owns_data_exclusively(&self) -> bool {
+		// the data is always copied
+		true
+	}
 }
 
 #[cfg(feature = "ndarray")]
@@ -563,6 +1321,7 @@ impl<T: Clone + 'static, D: Dimension + 'static> OrtInput for &mut ArcArray<T, D
 			(shape, ptr, ptr_len, guard)
 		} else {
 			// Need to do a copy here to get data in to standard layout
+			tracing::debug!("array is not in standard layout; copying into a contiguous buffer");
 			let mut contiguous_array = self.as_standard_layout().into_owned();
 			let shape: Vec<i64> = contiguous_array.shape().iter().map(|d| *d as i64).collect();
 			let ptr = contiguous_array.as_mut_ptr();
@@ -571,6 +1330,11 @@ impl<T: Clone + 'static, D: Dimension + 'static> OrtInput for &mut ArcArray<T, D
 			(shape, ptr, ptr_len, guard)
 		}
 	}
+
+	fn owns_data_exclusively(&self) -> bool {
+		// arrays in standard layout share their data with the caller's `ArcArray`; others are copied
+		!self.is_standard_layout()
+	}
 }
 
 #[cfg(feature = "ndarray")]
@@ -594,6 +1358,7 @@ impl<T: Clone + 'static, D: Dimension + 'static> OrtInput for Array<T, D> {
 			(shape, ptr, ptr_len, guard)
 		} else {
 			// Need to do a copy here to get data in to standard layout
+			tracing::debug!("array is not in standard layout; copying into a contiguous buffer");
 			let mut contiguous_array = self.as_standard_layout().into_owned();
 			let shape: Vec<i64> = contiguous_array.shape().iter().map(|d| *d as i64).collect();
 			let ptr = contiguous_array.as_mut_ptr();
@@ -602,8 +1367,15 @@ impl<T: Clone + 'static, D: Dimension + 'static> OrtInput for Array<T, D> {
 			(shape, ptr, ptr_len, guard)
 		}
 	}
+
+	fn owns_data_exclusively(&self) -> bool {
+		true
+	}
 }
 
+/// Views are always copied into a new contiguous buffer, since the view's data is borrowed and may not be in standard
+/// layout. To control this copy explicitly, use [`Value::from_array_view`] (which never copies, and errors for
+/// non-contiguous views) or [`Value::from_array_contiguous`] (which always copies).
 #[cfg(feature = "ndarray")]
 impl<'v, T: Clone + 'static, D: Dimension + 'static> OrtInput for ArrayView<'v, T, D> {
 	type Item = T;
@@ -616,6 +1388,9 @@ impl<'v, T: Clone + 'static, D: Dimension + 'static> OrtInput for ArrayView<'v,
 
 	fn into_parts(self) -> (Vec<i64>, *mut Self::Item, usize, Box<dyn Any>) {
 		// This will result in a copy in either form of the ArrayView
+		if !self.is_standard_layout() {
+			tracing::debug!("array view is not in standard layout; copying into a contiguous buffer");
+		}
 		let mut contiguous_array = self.as_standard_layout().into_owned();
 		let shape: Vec<i64> = contiguous_array.shape().iter().map(|d| *d as i64).collect();
 		let ptr = contiguous_array.as_mut_ptr();
@@ -623,6 +1398,51 @@ impl<'v, T: Clone + 'static, D: Dimension + 'static> OrtInput for ArrayView<'v,
 		let guard = Box::new(contiguous_array);
 		(shape, ptr, ptr_len, guard)
 	}
+
+	fn owns_data_exclusively(&self) -> bool {
+		// the data is always copied
+		true
+	}
+}
+
+impl<T: Clone + Debug + 'static> OrtInput for (Vec<i64>, &[T]) {
+	type Item = T;
+
+	fn ref_parts(&self) -> (Vec<i64>, &[Self::Item]) {
+		(self.0.clone(), self.1)
+	}
+
+	fn into_parts(self) -> (Vec<i64>, *mut Self::Item, usize, Box<dyn Any>) {
+		// This will always result in a copy, since ONNX Runtime may write to the data
+		let mut data: Box<[T]> = self.1.into();
+		let ptr = data.as_mut_ptr();
+		let ptr_len = data.len();
+		(self.0, ptr, ptr_len, Box::new(data))
+	}
+
+	fn owns_data_exclusively(&self) -> bool {
+		true
+	}
+}
+
+impl<T: Clone + Debug + 'static> OrtInput for (Vec<i64>, Vec<T>) {
+	type Item = T;
+
+	fn ref_parts(&self) -> (Vec<i64>, &[Self::Item]) {
+		(self.0.clone(), &self.1)
+	}
+
+	fn into_parts(self) -> (Vec<i64>, *mut Self::Item, usize, Box<dyn Any>) {
+		// the `Vec` is owned, so its buffer can be used as is
+		let mut guard = Box::new(self.1);
+		let ptr = guard.as_mut_ptr();
+		let ptr_len = guard.len();
+		(self.0, ptr, ptr_len, guard)
+	}
+
+	fn owns_data_exclusively(&self) -> bool {
+		true
+	}
 }
 
 impl<T: Clone + Debug + 'static> OrtInput for (Vec<i64>, Arc<Box<[T]>>) {
@@ -641,6 +1461,11 @@ impl<T: Clone + Debug + 'static> OrtInput for (Vec<i64>, Arc<Box<[T]>>) {
 		let guard = Box::new(Arc::clone(&self.1));
 		(shape, ptr, ptr_len, guard)
 	}
+
+	fn owns_data_exclusively(&self) -> bool {
+		// `Arc::make_mut` in `into_parts` copies the data if it is shared
+		true
+	}
 }
 
 #[cfg(feature = "ndarray")]
@@ -675,6 +1500,8 @@ impl<T: IntoTensorElementType + Debug + Clone + 'static, D: Dimension + 'static>
 
 #[cfg(feature = "ndarray")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+/// Copies the view's data into a new contiguous buffer owned by the [`Value`]. See [`Value::from_array_view`] for a
+/// zero-copy alternative.
 impl<'v, T: IntoTensorElementType + Debug + Clone + 'static, D: Dimension + 'static> TryFrom<ArrayView<'v, T, D>> for Value {
 	type Error = Error;
 	fn try_from(arr: ArrayView<'v, T, D>) -> Result<Self, Self::Error> {
@@ -682,6 +1509,13 @@ impl<'v, T: IntoTensorElementType + Debug + Clone + 'static, D: Dimension + 'sta
 	}
 }
 
+impl<T: IntoTensorElementType + Debug + Clone + 'static> TryFrom<(Vec<i64>, Vec<T>)> for Value {
+	type Error = Error;
+	fn try_from(d: (Vec<i64>, Vec<T>)) -> Result<Self, Self::Error> {
+		Value::from_array(d)
+	}
+}
+
 impl<T: IntoTensorElementType + Debug + Clone + 'static> TryFrom<(Vec<i64>, Arc<Box<[T]>>)> for Value {
 	type Error = Error;
 	fn try_from(d: (Vec<i64>, Arc<Box<[T]>>)) -> Result<Self, Self::Error> {
@@ -689,21 +1523,139 @@ impl<T: IntoTensorElementType + Debug + Clone + 'static> TryFrom<(Vec<i64>, Arc<
 	}
 }
 
+impl<T: IntoTensorElementType + Debug + Clone + 'static> TryFrom<(Vec<i64>, &[T])> for Value {
+	type Error = Error;
+	fn try_from(d: (Vec<i64>, &[T])) -> Result<Self, Self::Error> {
+		Value::from_array(d)
+	}
+}
+
+/// The maximum number of elements shown by [`Value`]'s [`Debug`] implementation.
+const DEBUG_PREVIEW_ELEMENTS: usize = 8;
+
+impl Value {
+	/// Returns whether this value is a tensor whose data is in CPU memory, and is thus safe to read.
+	fn is_cpu_tensor(&self) -> bool {
+		let mut memory_info_ptr: *const ort_sys::OrtMemoryInfo = ptr::null();
+		if status_to_result(ortsys![unsafe GetTensorMemoryInfo(self.ptr(), &mut memory_info_ptr)]).is_err() || memory_info_ptr.is_null() {
+			return false;
+		}
+		let mut name_ptr: *const std::os::raw::c_char = ptr::null();
+		if status_to_result(ortsys![unsafe MemoryInfoGetName(memory_info_ptr, &mut name_ptr)]).is_err() || name_ptr.is_null() {
+			return false;
+		}
+		unsafe { ffi::CStr::from_ptr(name_ptr) }.to_bytes() == b"Cpu"
+	}
+
+	/// Formats the first few elements of this tensor, or returns `None` if they can't be previewed.
+	fn debug_preview(&self, ty: TensorElementType) -> Option<String> {
+		fn preview<T: ExtractTensorData + Clone + Debug>(value: &Value) -> Option<String> {
+			// only borrows the data, so this is cheap even for large tensors
+			let (_, data) = value.extract_raw_tensor::<T>().ok()?;
+			let mut preview = format!("{:?}", &data[..data.len().min(DEBUG_PREVIEW_ELEMENTS)]);
+			if data.len() > DEBUG_PREVIEW_ELEMENTS {
+				preview.insert_str(preview.len() - 1, ", ...");
+			}
+			Some(preview)
+		}
+
+		if !self.is_cpu_tensor() {
+			return None;
+		}
+		match ty {
+			TensorElementType::Float32 => preview::<f32>(self),
+			TensorElementType::Float64 => preview::<f64>(self),
+			TensorElementType::Uint8 => preview::<u8>(self),
+			TensorElementType::Int8 => preview::<i8>(self),
+			TensorElementType::Uint16 => preview::<u16>(self),
+			TensorElementType::Int16 => preview::<i16>(self),
+			TensorElementType::Uint32 => preview::<u32>(self),
+			TensorElementType::Int32 => preview::<i32>(self),
+			TensorElementType::Uint64 => preview::<u64>(self),
+			TensorElementType::Int64 => preview::<i64>(self),
+			TensorElementType::Bool => preview::<bool>(self),
+			_ => None
+		}
+	}
+}
+
+impl Debug for Value {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut s = f.debug_struct("Value");
+		match self.dtype() {
+			Ok(ValueType::Tensor { ty, dimensions }) => {
+				s.field("type", &format_args!("Tensor")).field("dtype", &ty).field("shape", &dimensions);
+				if let Some(preview) = self.debug_preview(ty) {
+					s.field("data", &format_args!("{preview}"));
+				}
+			}
+			Ok(ValueType::Sequence(element)) => {
+				s.field("type", &format_args!("Sequence")).field("element_type", &format_args!("{element}"));
+			}
+			Ok(ValueType::Map { key, value }) => {
+				s.field("type", &format_args!("Map")).field("key_type", &key).field("value_type", &value);
+			}
+			Err(e) => {
+				s.field("error", &format_args!("{e}"));
+			}
+		}
+		s.finish()
+	}
+}
+
+impl fmt::Display for Value {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.dtype() {
+			Ok(ty) => fmt::Display::fmt(&ty, f),
+			Err(_) => f.write_str("<unknown value type>")
+		}
+	}
+}
+
 impl Drop for Value {
-	#[tracing::instrument]
+	// don't record `self`; formatting it calls into ONNX Runtime
+	#[tracing::instrument(skip(self))]
 	fn drop(&mut self) {
 		let ptr = self.ptr();
 		tracing::trace!(
 			"dropping {} value at {ptr:p}",
 			match &self.inner {
 				ValueInner::RustOwned { .. } => "rust-owned",
-				ValueInner::CppOwned { .. } | ValueInner::CppOwnedRef { .. } => "cpp-owned"
+				ValueInner::CppOwned { .. } | ValueInner::CppOwnedRef { .. } => "cpp-owned",
+				#[cfg(feature = "training")]
+				ValueInner::CppOwnedTraining { .. } => "cpp-owned"
 			}
 		);
 		ortsys![unsafe ReleaseValue(ptr)];
 	}
 }
 
+/// Parses an [`ort_sys::OrtTypeInfo`] into a [`ValueType`], recursing into the element types of sequences. The type info
+/// is not released.
+pub(crate) unsafe fn extract_data_type_from_type_info(typeinfo_ptr: *const ort_sys::OrtTypeInfo) -> Result<ValueType> {
+	let mut ty: ort_sys::ONNXType = ort_sys::ONNXType::ONNX_TYPE_UNKNOWN;
+	let status = ortsys![unsafe GetOnnxTypeFromTypeInfo(typeinfo_ptr, &mut ty)];
+	status_to_result(status).map_err(Error::GetOnnxTypeFromTypeInfo)?;
+	match ty {
+		ort_sys::ONNXType::ONNX_TYPE_TENSOR | ort_sys::ONNXType::ONNX_TYPE_SPARSETENSOR => {
+			let mut info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+			ortsys![unsafe CastTypeInfoToTensorInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToTensorInfo; nonNull(info_ptr)];
+			extract_data_type_from_tensor_info(info_ptr)
+		}
+		ort_sys::ONNXType::ONNX_TYPE_SEQUENCE => {
+			let mut info_ptr: *const ort_sys::OrtSequenceTypeInfo = std::ptr::null_mut();
+			ortsys![unsafe CastTypeInfoToSequenceTypeInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToSequenceTypeInfo; nonNull(info_ptr)];
+			extract_data_type_from_sequence_info(info_ptr)
+		}
+		ort_sys::ONNXType::ONNX_TYPE_MAP => {
+			let mut info_ptr: *const ort_sys::OrtMapTypeInfo = std::ptr::null_mut();
+			ortsys![unsafe CastTypeInfoToMapTypeInfo(typeinfo_ptr, &mut info_ptr) -> Error::CastTypeInfoToMapTypeInfo; nonNull(info_ptr)];
+			extract_data_type_from_map_info(info_ptr)
+		}
+		ty => Err(Error::UnsupportedValueType(ty))
+	}
+}
+
 pub(crate) unsafe fn extract_data_type_from_tensor_info(info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo) -> Result<ValueType> {
 	let mut type_sys = ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
 	ortsys![GetTensorElementType(info_ptr, &mut type_sys) -> Error::GetTensorElementType];
@@ -723,27 +1675,12 @@ pub(crate) unsafe fn extract_data_type_from_tensor_info(info_ptr: *const ort_sys
 
 pub(crate) unsafe fn extract_data_type_from_sequence_info(info_ptr: *const ort_sys::OrtSequenceTypeInfo) -> Result<ValueType> {
 	let mut element_type_info: *mut ort_sys::OrtTypeInfo = std::ptr::null_mut();
-	ortsys![GetSequenceElementType(info_ptr, &mut element_type_info) -> Error::GetSequenceElementType];
-
-	let mut ty: ort_sys::ONNXType = ort_sys::ONNXType::ONNX_TYPE_UNKNOWN;
-	let status = ortsys![unsafe GetOnnxTypeFromTypeInfo(element_type_info, &mut ty)];
-	status_to_result(status).map_err(Error::GetOnnxTypeFromTypeInfo)?;
+	ortsys![GetSequenceElementType(info_ptr, &mut element_type_info) -> Error::GetSequenceElementType; nonNull(element_type_info)];
 
-	match ty {
-		ort_sys::ONNXType::ONNX_TYPE_TENSOR => {
-			let mut info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
-			ortsys![unsafe CastTypeInfoToTensorInfo(element_type_info, &mut info_ptr) -> Error::CastTypeInfoToTensorInfo; nonNull(info_ptr)];
-			let ty = unsafe { extract_data_type_from_tensor_info(info_ptr)? };
-			Ok(ValueType::Sequence(Box::new(ty)))
-		}
-		ort_sys::ONNXType::ONNX_TYPE_MAP => {
-			let mut info_ptr: *const ort_sys::OrtMapTypeInfo = std::ptr::null_mut();
-			ortsys![unsafe CastTypeInfoToMapTypeInfo(element_type_info, &mut info_ptr) -> Error::CastTypeInfoToMapTypeInfo; nonNull(info_ptr)];
-			let ty = unsafe { extract_data_type_from_map_info(info_ptr)? };
-			Ok(ValueType::Sequence(Box::new(ty)))
-		}
-		_ => unreachable!()
-	}
+	// sequences may contain tensors, maps, or other sequences
+	let element_type = extract_data_type_from_type_info(element_type_info);
+	ortsys![unsafe ReleaseTypeInfo(element_type_info)];
+	Ok(ValueType::Sequence(Box::new(element_type?)))
 }
 
 pub(crate) unsafe fn extract_data_type_from_map_info(info_ptr: *const ort_sys::OrtMapTypeInfo) -> Result<ValueType> {
@@ -752,17 +1689,16 @@ pub(crate) unsafe fn extract_data_type_from_map_info(info_ptr: *const ort_sys::O
 	assert_ne!(key_type_sys, ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED);
 
 	let mut value_type_info: *mut ort_sys::OrtTypeInfo = std::ptr::null_mut();
-	ortsys![GetMapValueType(info_ptr, &mut value_type_info) -> Error::GetMapValueType];
-	let mut value_info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
-	ortsys![unsafe CastTypeInfoToTensorInfo(value_type_info, &mut value_info_ptr) -> Error::CastTypeInfoToTensorInfo; nonNull(value_info_ptr)];
-	let mut value_type_sys = ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
-	ortsys![GetTensorElementType(value_info_ptr, &mut value_type_sys) -> Error::GetTensorElementType];
-	assert_ne!(value_type_sys, ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED);
-
-	Ok(ValueType::Map {
-		key: key_type_sys.into(),
-		value: value_type_sys.into()
-	})
+	ortsys![GetMapValueType(info_ptr, &mut value_type_info) -> Error::GetMapValueType; nonNull(value_type_info)];
+	// ONNX Runtime only supports maps of primitive values, which are represented as (scalar) tensors
+	let value_type = extract_data_type_from_type_info(value_type_info);
+	ortsys![unsafe ReleaseTypeInfo(value_type_info)];
+	let value = match value_type? {
+		ValueType::Tensor { ty, .. } => ty,
+		_ => return Err(Error::UnsupportedValueType(ort_sys::ONNXType::ONNX_TYPE_MAP))
+	};
+
+	Ok(ValueType::Map { key: key_type_sys.into(), value })
 }
 
 #[cfg(test)]
@@ -771,6 +1707,87 @@ mod tests {
 
 	use crate::*;
 
+	#[test]
+	fn test_from_shared() -> crate::Result<()> {
+		use std::sync::Arc;
+
+		let data: Arc<[i64]> = Arc::from(vec![1, 2, 3, 4, 5, 6]);
+		let weak = Arc::downgrade(&data);
+
+		let a = Value::from_shared(Arc::clone(&data), &[2, 3])?;
+		let b = Value::from_shared(Arc::clone(&data), &[6])?;
+		assert!(matches!(Value::from_shared(Arc::clone(&data), &[4]), Err(Error::ShapeDataMismatch { expected: 4, actual: 6, .. })));
+		// the buffer is shared, so it can't be mutated through a value
+		#[cfg(feature = "ndarray")]
+		assert!(matches!(Value::from_shared(Arc::clone(&data), &[6])?.try_view_tensor_mut::<i64, ndarray::Ix1>(), Err(Error::TensorNotMutable)));
+		drop(data);
+
+		let (a_shape, a_data) = a.extract_raw_tensor::<i64>()?;
+		let (b_shape, b_data) = b.extract_raw_tensor::<i64>()?;
+		assert_eq!((a_shape, a_data), (vec![2, 3], &[1, 2, 3, 4, 5, 6][..]));
+		assert_eq!((b_shape, b_data), (vec![6], &[1, 2, 3, 4, 5, 6][..]));
+		// both values read from the same buffer...
+		assert_eq!(a_data.as_ptr(), b_data.as_ptr());
+
+		// ...which is only freed once both are dropped
+		drop(a);
+		assert!(weak.upgrade().is_some());
+		drop(b);
+		assert!(weak.upgrade().is_none());
+		Ok(())
+	}
+
+	#[test]
+	fn test_from_slice_with_memory_info() -> crate::Result<()> {
+		use crate::{AllocationDevice, MemoryDeviceType};
+
+		let data = [1.0_f32, 2.0, 3.0, 4.0];
+		let memory_info = MemoryInfo::new_cpu_on_numa_node(1, AllocatorType::Device, MemoryType::Default)?;
+		let value = Value::from_slice_with_memory_info(memory_info, &data, &[2, 2])?;
+		let (shape, extracted) = value.extract_raw_tensor::<f32>()?;
+		assert_eq!((shape, extracted), (vec![2, 2], &data[..]));
+		assert_eq!(extracted.as_ptr(), data.as_ptr());
+
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default)?;
+		assert!(matches!(
+			Value::from_slice_with_memory_info(memory_info, &data, &[3]),
+			Err(Error::ShapeDataMismatch { expected: 3, actual: 4, .. })
+		));
+		let memory_info = MemoryInfo::new(AllocationDevice::CUDA, 0, AllocatorType::Device, MemoryType::Default)?;
+		assert!(matches!(Value::from_slice_with_memory_info(memory_info, &data, &[4]), Err(Error::NonCpuMemoryInfo(MemoryDeviceType::GPU))));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_debug() -> crate::Result<()> {
+		let value = Value::from_array((vec![2, 2], &[1_i32, -2, 3, 4][..]))?;
+		assert_eq!(format!("{value:?}"), "Value { type: Tensor, dtype: Int32, shape: [2, 2], data: [1, -2, 3, 4] }");
+		assert_eq!(value.to_string(), "Tensor<Int32>[2, 2]");
+
+		// large tensors are truncated
+		let data: Vec<f32> = (0..1000).map(|i| i as f32).collect();
+		let value = Value::from_array((vec![10, 100], &data[..]))?;
+		assert_eq!(
+			format!("{value:?}"),
+			"Value { type: Tensor, dtype: Float32, shape: [10, 100], data: [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, ...] }"
+		);
+
+		// string tensors don't show their data
+		let value = Value::from_string_array(&Allocator::default(), (vec![1], &["hello".to_string()][..]))?;
+		assert_eq!(format!("{value:?}"), "Value { type: Tensor, dtype: String, shape: [1] }");
+
+		assert_eq!(
+			ValueType::Sequence(Box::new(ValueType::Map {
+				key: TensorElementType::Int64,
+				value: TensorElementType::Float32
+			}))
+			.to_string(),
+			"Sequence<Map<Int64, Float32>>"
+		);
+		Ok(())
+	}
+
 	#[test]
 	#[cfg(feature = "ndarray")]
 	fn test_tensor_value() -> crate::Result<()> {
@@ -817,6 +1834,344 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_array_conversion() -> crate::Result<()> {
+		// contiguous owned arrays are moved into the value without copying
+		let array = ndarray::Array2::from_shape_vec((2, 3), vec![1., 2., 3., 4., 5., 6.]).unwrap();
+		let data_ptr = array.as_ptr();
+		let value = Value::try_from(array)?;
+		let (shape, data) = value.extract_raw_tensor::<f32>()?;
+		assert_eq!(shape, vec![2, 3]);
+		assert_eq!(data.as_ptr(), data_ptr);
+
+		// non-contiguous views are copied into standard layout
+		let array = ndarray::Array2::from_shape_vec((2, 3), vec![1., 2., 3., 4., 5., 6.]).unwrap();
+		let value = Value::try_from(array.t())?;
+		let (shape, data) = value.extract_raw_tensor::<f32>()?;
+		assert_eq!(shape, vec![3, 2]);
+		assert_eq!(data, &[1., 4., 2., 5., 3., 6.]);
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_try_view_tensor() -> crate::Result<()> {
+		let v: Vec<f32> = vec![1., 2., 3., 4., 5., 6.];
+		let value = Value::from_array(ndarray::Array2::from_shape_vec((2, 3), v.clone()).unwrap())?;
+
+		let view = value.try_view_tensor::<f32, ndarray::Ix2>()?;
+		assert_eq!(view.shape(), [2, 3]);
+		assert_eq!(view.iter().copied().collect::<Vec<_>>(), v);
+		assert_eq!(value.try_view_tensor::<f32, ndarray::IxDyn>()?.shape(), [2, 3]);
+
+		assert!(matches!(value.try_view_tensor::<f32, ndarray::Ix1>(), Err(Error::InvalidViewShape(..))));
+		assert!(matches!(value.try_view_tensor::<i32, ndarray::Ix2>(), Err(Error::DataTypeMismatch { .. })));
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_try_view_tensor_mut() -> crate::Result<()> {
+		let mut value = Value::from_array(ndarray::Array2::from_shape_vec((2, 2), vec![1_i64, 2, 3, 4]).unwrap())?;
+		let data_ptr = value.extract_raw_tensor::<i64>()?.1.as_ptr();
+
+		let mut view = value.try_view_tensor_mut::<i64, ndarray::Ix2>()?;
+		assert_eq!(view.as_ptr(), data_ptr);
+		view.row_mut(1).fill(0);
+		assert_eq!(value.extract_raw_tensor::<i64>()?.1, &[1, 2, 0, 0]);
+
+		assert!(matches!(value.try_view_tensor_mut::<i64, ndarray::Ix3>(), Err(Error::InvalidViewShape(..))));
+		assert!(matches!(value.try_view_tensor_mut::<f32, ndarray::Ix2>(), Err(Error::DataTypeMismatch { .. })));
+
+		// a standard-layout `ArcArray` is used in place, so the value must not hand out a mutable view of it
+		let mut arc = ndarray::ArcArray::from_shape_vec((2, 2), vec![1_i64, 2, 3, 4]).unwrap();
+		let mut value = Value::from_array(&mut arc)?;
+		assert!(matches!(value.try_view_tensor_mut::<i64, ndarray::Ix2>(), Err(Error::TensorNotMutable)));
+		assert!(value.try_view_tensor::<i64, ndarray::Ix2>().is_ok());
+
+		// ...but a non-standard layout `ArcArray` is copied, so the copy can be mutated
+		let mut arc = arc.reversed_axes();
+		let mut value = Value::from_array(&mut arc)?;
+		value.try_view_tensor_mut::<i64, ndarray::Ix2>()?.fill(0);
+		assert_eq!(arc, ndarray::arr2(&[[1, 3], [2, 4]]));
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_from_array_view() -> crate::Result<()> {
+		let array = ndarray::Array2::from_shape_vec((2, 3), vec![1_i32, 2, 3, 4, 5, 6]).unwrap();
+
+		// contiguous views are borrowed as-is
+		let value = Value::from_array_view(array.view())?;
+		let (shape, data) = value.extract_raw_tensor::<i32>()?;
+		assert_eq!(shape, [2, 3]);
+		assert_eq!(data.as_ptr(), array.as_ptr());
+
+		// transposed views are rejected by the strict constructor...
+		assert!(matches!(Value::from_array_view(array.t()), Err(Error::NonContiguousArray)));
+		// ...and copied by the lenient one
+		let value = Value::from_array_contiguous(array.t())?;
+		let (shape, data) = value.extract_raw_tensor::<i32>()?;
+		assert_eq!(shape, [3, 2]);
+		assert_eq!(data, [1, 4, 2, 5, 3, 6]);
+
+		// contiguous views are copied too
+		let value = Value::from_array_contiguous(array.view())?;
+		assert_ne!(value.extract_raw_tensor::<i32>()?.1.as_ptr(), array.as_ptr());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_from_raw_device_ptr() -> crate::Result<()> {
+		let memory_info = MemoryInfo::new(AllocationDevice::CPU, 0, AllocatorType::Device, MemoryType::Default)?;
+		let mut data = vec![1.0_f32, -2.0, 3.5, 0.0, 4.0, -1.0];
+		let len_bytes = data.len() * std::mem::size_of::<f32>();
+		let value = unsafe { Value::from_raw_device_ptr(data.as_mut_ptr().cast(), len_bytes, vec![2, 3], TensorElementType::Float32, &memory_info)? };
+		// the value keeps its own memory info
+		drop(memory_info);
+
+		let (shape, extracted) = value.extract_raw_tensor::<f32>()?;
+		assert_eq!(shape, vec![2, 3]);
+		assert_eq!(extracted, &data[..]);
+		assert_eq!(extracted.as_ptr(), data.as_ptr(), "value should reference the buffer without copying");
+		drop(value);
+
+		let memory_info = MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default)?;
+		assert!(unsafe { Value::from_raw_device_ptr(data.as_mut_ptr().cast(), 8, vec![2, 3], TensorElementType::Float32, &memory_info) }.is_err());
+		assert!(matches!(
+			unsafe { Value::from_raw_device_ptr(data.as_mut_ptr().cast(), len_bytes, vec![1], TensorElementType::String, &memory_info) },
+			Err(Error::UnsupportedBufferElementType(TensorElementType::String))
+		));
+		Ok(())
+	}
+
+	/// Runs `branches.onnx` (`y = Relu(x) + Neg(x)`) once with its output on the GPU, then feeds that output's device
+	/// pointer back in as the input of a second run via [`Value::from_raw_device_ptr`].
+	#[test]
+	#[cfg(feature = "cuda")]
+	#[ignore = "requires a CUDA device"]
+	fn test_from_raw_device_ptr_cuda() -> crate::Result<()> {
+		let session = Session::builder()?
+			.with_execution_providers([CUDAExecutionProvider::default().build()])?
+			.with_model_from_file(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+		let cuda = || MemoryInfo::new(AllocationDevice::CUDA, 0, AllocatorType::Device, MemoryType::Default);
+
+		let x = [-2.0_f32, -0.5, 0.5, 2.0];
+		let mut first = session.create_binding()?;
+		first.bind_input("x", Value::from_array((vec![4], &x[..]))?)?;
+		first.bind_output_to_device("y", cuda()?)?;
+		let first_outputs = first.run()?;
+
+		let mut device_ptr: *mut std::ffi::c_void = ptr::null_mut();
+		ortsys![unsafe GetTensorMutableData(first_outputs["y"].ptr(), &mut device_ptr) -> Error::GetTensorMutableData; nonNull(device_ptr)];
+		let input = unsafe { Value::from_raw_device_ptr(device_ptr, x.len() * 4, vec![4], TensorElementType::Float32, &cuda()?)? };
+
+		let mut second = session.create_binding()?;
+		second.bind_input("x", input)?;
+		second.bind_output_to_device("y", MemoryInfo::new_cpu(AllocatorType::Device, MemoryType::Default)?)?;
+		let second_outputs = second.run()?;
+
+		let f = |x: f32| x.max(0.0) - x;
+		let expected: Vec<f32> = x.iter().map(|&x| f(f(x))).collect();
+		assert_eq!(second_outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_bytes() -> crate::Result<()> {
+		let v = [1.5_f32, -2.0, 0.0, f32::MAX];
+		let (ty, shape, bytes) = Value::from_array((vec![2, 2], &v[..]))?.to_bytes()?;
+		assert_eq!(ty, TensorElementType::Float32);
+		assert_eq!(shape, vec![2, 2]);
+		assert_eq!(bytes, v.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>());
+
+		let v = [i64::MIN, -1, 1 << 40];
+		let (ty, shape, bytes) = Value::from_array((vec![3], &v[..]))?.to_bytes()?;
+		assert_eq!(ty, TensorElementType::Int64);
+		assert_eq!(shape, vec![3]);
+		assert_eq!(bytes, v.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>());
+
+		let (ty, _, bytes) = Value::from_array((vec![3], &[true, false, true][..]))?.to_bytes()?;
+		assert_eq!(ty, TensorElementType::Bool);
+		assert_eq!(bytes, [1, 0, 1]);
+
+		let (_, shape, bytes) = Value::from_array((vec![0], &[] as &[u16]))?.to_bytes()?;
+		assert_eq!(shape, vec![0]);
+		assert!(bytes.is_empty());
+
+		let allocator = Allocator::default();
+		let (ty, shape, bytes) = Value::from_string_array(&allocator, (vec![3], &["ab", "", "ü"][..]))?.to_bytes()?;
+		assert_eq!(ty, TensorElementType::String);
+		assert_eq!(shape, vec![3]);
+		let mut expected = Vec::new();
+		for s in ["ab", "", "ü"] {
+			expected.extend_from_slice(&(s.len() as u64).to_le_bytes());
+			expected.extend_from_slice(s.as_bytes());
+		}
+		assert_eq!(bytes, expected);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_from_bytes() -> crate::Result<()> {
+		let v = [1.5_f32, -2.0, 0.0, f32::MAX, f32::MIN_POSITIVE, -0.25];
+		let bytes: Vec<u8> = v.iter().flat_map(|x| x.to_le_bytes()).collect();
+		let value = Value::from_bytes(TensorElementType::Float32, &[2, 3], &bytes)?;
+		let (shape, data) = value.extract_raw_tensor::<f32>()?;
+		assert_eq!(shape, vec![2, 3]);
+		assert_eq!(data, v);
+
+		let v = [i64::MIN, -1, 1 << 40];
+		// pass unaligned bytes to ensure they are copied into an aligned buffer
+		let mut bytes = vec![0];
+		bytes.extend(v.iter().flat_map(|x| x.to_le_bytes()));
+		let value = Value::from_bytes(TensorElementType::Int64, &[3], &bytes[1..])?;
+		assert_eq!(value.extract_raw_tensor::<i64>()?.1, v);
+		assert_eq!(value.to_bytes()?, (TensorElementType::Int64, vec![3], bytes[1..].to_vec()));
+
+		assert!(matches!(
+			Value::from_bytes(TensorElementType::Int64, &[2], &bytes[1..]),
+			Err(Error::TensorBytesLengthMismatch { expected: 16, actual: 24, .. })
+		));
+		assert!(matches!(Value::from_bytes(TensorElementType::Bool, &[2], &[1, 2]), Err(Error::InvalidBoolValue(2))));
+
+		let (_, _, bytes) = Value::from_string_array(&Allocator::default(), (vec![3], &["ab", "", "ü"][..]))?.to_bytes()?;
+		let value = Value::from_bytes(TensorElementType::String, &[3], &bytes)?;
+		assert_eq!(value.to_bytes()?, (TensorElementType::String, vec![3], bytes.clone()));
+		assert!(matches!(
+			Value::from_bytes(TensorElementType::String, &[3], &bytes[..bytes.len() - 1]),
+			Err(Error::TensorBytesLengthMismatch { element_type: TensorElementType::String, .. })
+		));
+		assert!(matches!(Value::from_bytes(TensorElementType::String, &[2], &bytes), Err(Error::TensorBytesLengthMismatch { .. })));
+		Ok(())
+	}
+
+	#[test]
+	fn test_from_vec() -> crate::Result<()> {
+		let x = vec![-2.0_f32, -0.5, 0.5, 2.0];
+		let x_ptr = x.as_ptr();
+		let input = Value::from_vec(x, &[4])?;
+		// the tensor should use the `Vec`'s buffer directly
+		let (shape, data) = input.extract_raw_tensor::<f32>()?;
+		assert_eq!(shape, vec![4]);
+		assert_eq!(data.as_ptr(), x_ptr);
+
+		assert!(matches!(Value::from_vec(vec![1_i64, 2, 3], &[2, 2]), Err(Error::ShapeDataMismatch { expected: 4, actual: 3, .. })));
+
+		// `branches.onnx` computes `y = Relu(x) + Neg(x)`
+		let session = Session::builder()?
+			.with_model_from_file(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+		let outputs = session.run([input])?;
+		assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, [2.0, 0.5, 0.0, 0.0]);
+		Ok(())
+	}
+
+	#[test]
+	fn test_try_extract_scalar() -> crate::Result<()> {
+		// both 0-d and `[1]` tensors hold a single element
+		let score = Value::from_array((vec![], &[0.25_f32][..]))?;
+		assert_eq!(score.try_extract_scalar::<f32>()?, 0.25);
+		let score = Value::from_array((vec![1], &[0.25_f32][..]))?;
+		assert_eq!(score.try_extract_scalar::<f32>()?, 0.25);
+
+		let scores = Value::from_array((vec![2], &[0.25_f32, 0.75][..]))?;
+		assert!(matches!(scores.try_extract_scalar::<f32>(), Err(Error::NotScalar(shape)) if shape == [2]));
+		let empty = Value::from_array((vec![0], &[] as &[f32]))?;
+		assert!(matches!(empty.try_extract_scalar::<f32>(), Err(Error::NotScalar(_))));
+		assert!(matches!(score.try_extract_scalar::<i64>(), Err(Error::DataTypeMismatch { .. })));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_scalar_tensor() -> crate::Result<()> {
+		let value = Value::from_array((vec![], &[4.2_f32][..]))?;
+		let (shape, data) = value.extract_raw_tensor::<f32>()?;
+		assert!(shape.is_empty());
+		assert_eq!(data, &[4.2]);
+
+		// a 0-d tensor is distinct from a 1-element 1-d tensor
+		let value = Value::from_array((vec![1], &[4.2_f32][..]))?;
+		assert_eq!(value.extract_raw_tensor::<f32>()?.0, vec![1]);
+
+		assert!(matches!(
+			Value::from_array((vec![], &[1.0_f32, 2.0][..])),
+			Err(Error::ShapeDataMismatch { expected: 1, actual: 2, .. })
+		));
+		assert!(matches!(Value::from_array((vec![3], &[1.0_f32][..])), Err(Error::ShapeDataMismatch { expected: 3, actual: 1, .. })));
+		match Value::from_vec(vec![1_i32, 2, 3, 4, 5], &[2, 3]) {
+			Err(e @ Error::ShapeDataMismatch { .. }) => assert_eq!(e.to_string(), "Shape [2, 3] describes 6 elements, but 5 were provided"),
+			r => panic!("expected a shape mismatch, got {r:?}")
+		}
+
+		// a shape containing a `0` dimension describes an empty tensor
+		let value = Value::from_array((vec![2, 0, 3], &[] as &[f32]))?;
+		assert_eq!(value.extract_raw_tensor::<f32>()?, (vec![2, 0, 3], &[][..]));
+		assert!(matches!(Value::from_array((vec![2, 0], &[1.0_f32][..])), Err(Error::ShapeDataMismatch { expected: 0, actual: 1, .. })));
+
+		// an element count that overflows must not wrap around to match the data
+		#[cfg(target_pointer_width = "64")]
+		assert!(matches!(Value::from_array((vec![1 << 32, 1 << 32], &[1.0_f32][..])), Err(Error::ShapeTooLarge(_))));
+		#[cfg(target_pointer_width = "64")]
+		assert!(matches!(Value::from_bytes(TensorElementType::Float32, &[i64::MAX], &[]), Err(Error::ShapeTooLarge(_))));
+
+		#[cfg(feature = "ndarray")]
+		{
+			let value = Value::from_array(ndarray::arr0(4.2_f32))?;
+			let tensor = value.extract_tensor::<f32>()?;
+			let view = tensor.view();
+			assert_eq!(view.ndim(), 0);
+			assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![4.2]);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_i64_tensor_no_truncation() -> crate::Result<()> {
+		let v = [i64::MAX, i64::MIN, 1 << 40, -(1 << 33), u32::MAX as i64 + 1];
+		let value = Value::from_array((vec![v.len() as i64], &v[..]))?;
+		let (shape, data) = value.extract_raw_tensor::<i64>()?;
+		assert_eq!(shape, vec![v.len() as i64]);
+		assert_eq!(data, &v);
+
+		let v = [u64::MAX, u32::MAX as u64 + 1];
+		let value = Value::from_array((vec![v.len() as i64], &v[..]))?;
+		assert_eq!(value.extract_raw_tensor::<u64>()?.1, &v);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_dims_to_usize() {
+		assert_eq!(crate::tensor::dims_to_usize(&[1, 3, 224, 224]).unwrap(), vec![1, 3, 224, 224]);
+		assert!(matches!(crate::tensor::dims_to_usize(&[1, -1]), Err(Error::InvalidDimension(-1))));
+		#[cfg(target_pointer_width = "32")]
+		assert!(matches!(crate::tensor::dims_to_usize(&[u32::MAX as i64 + 1]), Err(Error::InvalidDimension(_))));
+		#[cfg(target_pointer_width = "64")]
+		assert_eq!(crate::tensor::dims_to_usize(&[u32::MAX as i64 + 1]).unwrap(), vec![u32::MAX as usize + 1]);
+	}
+
+	#[test]
+	fn test_bool_tensor() -> crate::Result<()> {
+		let v = [true, false, false, true];
+		let value = Value::from_array((vec![4], &v[..]))?;
+		assert_eq!(value.tensor_element_type()?, TensorElementType::Bool);
+
+		let (shape, data) = value.extract_raw_tensor::<bool>()?;
+		assert_eq!(shape, vec![4]);
+		assert_eq!(data, &v);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_tensor_raw_lifetimes() -> crate::Result<()> {
 		let v: Vec<f32> = vec![1., 2., 3., 4., 5.];
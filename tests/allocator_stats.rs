@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use ort::{inputs, Error, Session};
+use test_log::test;
+
+/// This test verifies that `Session::allocator_stats` either reports allocations made during a run, or clearly
+/// reports that statistics are unsupported by the linked version of ONNX Runtime.
+#[test]
+fn allocator_stats() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	// `branches_dynamic.onnx` computes `y = Relu(x) + Neg(x)` for a `float[N]` input `x`; see `tests/profiling.rs`
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx"))?;
+	let input = vec![1.0_f32; 1024];
+	let _outputs = session.run(inputs![(vec![input.len() as i64], &input[..])]?)?;
+
+	match session.allocator_stats() {
+		Ok(stats) => assert!(stats.get("TotalAllocated").copied().unwrap_or_default() > 0, "expected nonzero `TotalAllocated` in {stats:?}"),
+		Err(Error::Unsupported(message)) => assert!(message.contains("AllocatorGetStats")),
+		Err(e) => return Err(e)
+	}
+
+	Ok(())
+}
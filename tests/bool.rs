@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use ndarray::Array1;
+use ort::{inputs, Session, TensorElementType};
+use test_log::test;
+
+/// Round-trips a boolean tensor through `identity_bool.onnx`, a single `Identity` node with a `bool[4]` input `x` and
+/// output `y`, which was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Identity', ['x'], ['y'], name='identity')],
+/// 	'identity_bool',
+/// 	[helper.make_tensor_value_info('x', TensorProto.BOOL, [4])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.BOOL, [4])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'identity_bool.onnx')
+/// ```
+#[test]
+fn bool_identity() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_bool.onnx"))?;
+
+	let input = [true, false, true, true];
+	let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+	assert_eq!(outputs["y"].tensor_element_type()?, TensorElementType::Bool);
+
+	let (shape, data) = outputs["y"].extract_raw_tensor::<bool>()?;
+	assert_eq!(shape, vec![4]);
+	assert_eq!(data.to_vec(), input.to_vec());
+
+	let output = outputs["y"].extract_tensor::<bool>()?;
+	assert_eq!(output.view().iter().copied().collect::<Vec<bool>>(), input.to_vec());
+
+	// ndarray inputs should work too
+	let outputs = session.run(inputs![Array1::from_vec(input.to_vec())]?)?;
+	assert_eq!(outputs["y"].extract_raw_tensor::<bool>()?.1, &input);
+
+	Ok(())
+}
@@ -0,0 +1,49 @@
+use ort::{CPUExecutionProvider, ExecutionMode, GraphOptimizationLevel, LogSeverity, Session, SessionConfigSummary};
+use test_log::test;
+
+/// Checks that `SessionBuilder::describe` reflects the options configured on the builder.
+#[test]
+fn config_summary() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	assert_eq!(Session::builder()?.describe(), SessionConfigSummary::default());
+
+	let builder = Session::builder()?
+		.with_optimization_level(GraphOptimizationLevel::Level1)?
+		.with_execution_mode(ExecutionMode::Parallel)?
+		.with_intra_threads(2)?
+		.with_inter_threads(3)?
+		.with_memory_pattern(false)?
+		.with_log_severity_level(LogSeverity::Info)?
+		.with_log_verbosity_level(1)?
+		.with_execution_providers([CPUExecutionProvider::default().build()])?
+		.with_denormal_as_zero(true)?
+		.with_disable_quant_qdq(false)?
+		.with_denormal_as_zero(false)?;
+
+	let summary = builder.describe();
+	assert_eq!(
+		summary,
+		SessionConfigSummary {
+			optimization_level: GraphOptimizationLevel::Level1,
+			execution_mode: ExecutionMode::Parallel,
+			intra_threads: Some(2),
+			inter_threads: Some(3),
+			per_session_threads: true,
+			execution_providers: vec!["CPUExecutionProvider".to_string()],
+			cpu_mem_arena: false,
+			memory_pattern: false,
+			log_severity_level: Some(LogSeverity::Info),
+			log_verbosity_level: 1,
+			config_entries: vec![
+				("session.set_denormal_as_zero".to_string(), "0".to_string()),
+				("session.disable_quant_qdq".to_string(), "0".to_string())
+			]
+		}
+	);
+
+	// the summary is carried over when a builder is cloned
+	assert_eq!(builder.clone().describe(), summary);
+
+	Ok(())
+}
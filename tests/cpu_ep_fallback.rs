@@ -0,0 +1,46 @@
+use ort::Session;
+use test_log::test;
+
+/// Checks that `SessionBuilder::with_cpu_ep_fallback` sets the corresponding session configuration entry.
+#[test]
+fn cpu_ep_fallback_config() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let builder = Session::builder()?.with_cpu_ep_fallback(false)?;
+	assert_eq!(builder.describe().config_entries, [("session.disable_cpu_ep_fallback".to_string(), "1".to_string())]);
+
+	let builder = Session::builder()?.with_cpu_ep_fallback(true)?;
+	assert_eq!(builder.describe().config_entries, [("session.disable_cpu_ep_fallback".to_string(), "0".to_string())]);
+
+	Ok(())
+}
+
+/// Checks that a session with a CUDA-incompatible node fails to build when CPU fallback is disabled, instead of running
+/// that node on the CPU. `ZipMap` is only implemented by the CPU execution provider; see `tests/zipmap.rs` for how
+/// `zipmap.onnx` was produced.
+#[test]
+#[cfg(feature = "cuda")]
+#[ignore = "requires a CUDA-capable GPU"]
+fn cpu_ep_fallback_disabled() -> ort::Result<()> {
+	use std::path::Path;
+
+	use ort::CUDAExecutionProvider;
+
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("zipmap.onnx");
+
+	// by default, `ZipMap` silently falls back to the CPU
+	Session::builder()?
+		.with_execution_providers([CUDAExecutionProvider::default().build().error_on_failure()])?
+		.with_model_from_file(&model_path)?;
+
+	let err = Session::builder()?
+		.with_execution_providers([CUDAExecutionProvider::default().build().error_on_failure()])?
+		.with_cpu_ep_fallback(false)?
+		.with_model_from_file(&model_path)
+		.expect_err("building a session with CPU-only nodes should fail when CPU fallback is disabled");
+	assert!(err.to_string().contains("fallback to CPU EP has been explicitly disabled"), "unexpected error: {err}");
+
+	Ok(())
+}
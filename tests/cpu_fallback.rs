@@ -0,0 +1,28 @@
+use std::path::Path;
+
+use ort::{inputs, CPUExecutionProvider, CUDAExecutionProvider, ExecutionProvider, Session};
+use test_log::test;
+
+/// Verifies that an explicitly listed, configured CPU execution provider is registered after an accelerator EP, so that
+/// nodes falling back to the CPU use its configuration. See `tests/execution_mode.rs` for how `branches.onnx` was
+/// produced.
+#[test]
+fn explicit_cpu_fallback() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let cpu = CPUExecutionProvider::default().with_arena_allocator().with_intra_threads(1);
+	let session_builder = Session::builder()?;
+	// the arena flag is applied directly to the session options on registration
+	cpu.register(&session_builder)?;
+
+	let session = session_builder
+		.with_execution_providers([CUDAExecutionProvider::default().build(), cpu.build()])?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+
+	let input = [-1.0_f32, 0.0, 1.0, 2.0];
+	let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+	let (_, output) = outputs["y"].extract_raw_tensor::<f32>()?;
+	assert_eq!(output, &[1.0, 0.0, 0.0, 0.0]);
+
+	Ok(())
+}
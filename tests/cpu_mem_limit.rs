@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use ort::{inputs, AllocatorType, ArenaConfig, Error, MemoryInfo, MemoryType, Session};
+use test_log::test;
+
+/// Runs `branches_dynamic.onnx` with the CPU arena capped via `SessionBuilder::with_cpu_mem_limit`. See
+/// `tests/profiling.rs` for how the model was produced.
+#[test]
+fn cpu_mem_limit() -> ort::Result<()> {
+	const LIMIT: usize = 8 * 1024 * 1024;
+
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx");
+	let session = Session::builder()?.with_cpu_mem_limit(LIMIT)?.with_model_from_file(&model_path)?;
+
+	// a small input easily fits within the limit
+	let input = [-1.0_f32, 0.0, 2.0];
+	let outputs = session.run(inputs![(vec![3], &input[..])]?)?;
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, [1.0, 0.0, 0.0]);
+	drop(outputs);
+
+	// the intermediate `Relu` & `Neg` outputs alone need 16 MiB for this input, so allocating them fails with an error
+	let input = vec![1.0_f32; 2 * 1024 * 1024];
+	assert!(session.run(inputs![(vec![input.len() as i64], &input[..])]?).is_err());
+
+	// sessions in the same environment share the arena, so they can't use a different limit
+	match Session::builder()?.with_cpu_mem_limit(LIMIT * 2)?.with_model_from_file(&model_path) {
+		Err(Error::CpuMemLimitConflict { requested, registered }) => assert_eq!((requested, registered), (LIMIT * 2, Some(LIMIT))),
+		r => panic!("expected a conflicting limit error, got {:?}", r.map(|_| ()))
+	}
+	Session::builder()?.with_cpu_mem_limit(LIMIT)?.with_model_from_file(&model_path)?;
+
+	Ok(())
+}
+
+/// Checks that a limit can't be combined with a CPU allocator shared via `EnvironmentBuilder::with_shared_allocator`,
+/// unless the shared arena has the same limit.
+#[test]
+fn cpu_mem_limit_shared_allocator() -> ort::Result<()> {
+	const LIMIT: usize = 8 * 1024 * 1024;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx");
+	let environment = ort::init()
+		.with_name("cpu_mem_limit_shared_allocator")
+		.with_shared_allocator(MemoryInfo::new_cpu(AllocatorType::Arena, MemoryType::Default)?, ArenaConfig::default())
+		.build()?;
+	match Session::builder()?.with_environment(&environment)?.with_cpu_mem_limit(LIMIT)?.with_model_from_file(&model_path) {
+		Err(Error::CpuMemLimitConflict { requested, registered }) => assert_eq!((requested, registered), (LIMIT, None)),
+		r => panic!("expected a conflicting limit error, got {:?}", r.map(|_| ()))
+	}
+
+	let environment = ort::init()
+		.with_name("cpu_mem_limit_shared_allocator")
+		.with_shared_allocator(
+			MemoryInfo::new_cpu(AllocatorType::Arena, MemoryType::Default)?,
+			ArenaConfig {
+				max_mem: Some(LIMIT),
+				..ArenaConfig::default()
+			}
+		)
+		.build()?;
+	Session::builder()?.with_environment(&environment)?.with_cpu_mem_limit(LIMIT)?.with_model_from_file(&model_path)?;
+
+	Ok(())
+}
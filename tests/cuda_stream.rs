@@ -0,0 +1,46 @@
+#![cfg(feature = "cuda")]
+
+use std::{os::raw::c_void, path::Path, ptr};
+
+use ort::{CUDAExecutionProvider, ExecutionProvider, Session};
+use test_log::test;
+
+#[link(name = "cudart")]
+extern "C" {
+	fn cudaStreamCreate(stream: *mut *mut c_void) -> i32;
+	fn cudaStreamSynchronize(stream: *mut c_void) -> i32;
+	fn cudaStreamDestroy(stream: *mut c_void) -> i32;
+}
+
+/// This test runs `branches.onnx` (`y = Relu(x) + Neg(x)`) with the CUDA execution provider configured to use a
+/// caller-created stream via [`CUDAExecutionProvider::with_compute_stream`].
+#[test]
+#[ignore = "requires a CUDA device"]
+fn cuda_user_compute_stream() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let mut stream = ptr::null_mut();
+	assert_eq!(unsafe { cudaStreamCreate(&mut stream) }, 0);
+
+	{
+		let ep = unsafe { CUDAExecutionProvider::default().with_compute_stream(stream) };
+		assert!(ep.is_available()?);
+
+		let session = Session::builder()?
+			.with_execution_providers([ep.build()])?
+			.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+		assert_eq!(session.registered_execution_providers(), ["CUDAExecutionProvider"]);
+
+		let x = [-2.0_f32, -0.5, 0.5, 2.0];
+		let outputs = session.run(ort::inputs![(vec![4], &x[..])]?)?;
+		assert_eq!(unsafe { cudaStreamSynchronize(stream) }, 0);
+
+		let expected: Vec<f32> = x.iter().map(|&x| x.max(0.0) - x).collect();
+		assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+	}
+
+	// the stream must outlive the session, so it can only be destroyed now
+	assert_eq!(unsafe { cudaStreamDestroy(stream) }, 0);
+
+	Ok(())
+}
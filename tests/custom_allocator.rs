@@ -0,0 +1,77 @@
+use std::{
+	alloc::{self, Layout},
+	ffi::c_void,
+	path::Path,
+	sync::atomic::{AtomicUsize, Ordering}
+};
+
+use ort::{inputs, CustomAllocator, Session};
+use test_log::test;
+
+/// Alignment ONNX Runtime's own CPU allocator uses.
+const ALIGNMENT: usize = 64;
+
+/// A CPU allocator which counts how many allocations are made through it. The size of each block is stored in a header
+/// before the returned pointer so it can be freed without ONNX Runtime passing the size.
+struct TrackingAllocator {
+	allocations: &'static AtomicUsize,
+	live: &'static AtomicUsize
+}
+
+impl CustomAllocator for TrackingAllocator {
+	fn alloc(&self, size: usize) -> *mut c_void {
+		let Ok(layout) = Layout::from_size_align(size + ALIGNMENT, ALIGNMENT) else {
+			return std::ptr::null_mut();
+		};
+		let block = unsafe { alloc::alloc(layout) };
+		if block.is_null() {
+			return std::ptr::null_mut();
+		}
+		unsafe { block.cast::<usize>().write(size) };
+		self.allocations.fetch_add(1, Ordering::Relaxed);
+		self.live.fetch_add(1, Ordering::Relaxed);
+		unsafe { block.add(ALIGNMENT) }.cast()
+	}
+
+	fn free(&self, ptr: *mut c_void) {
+		if ptr.is_null() {
+			return;
+		}
+		let block = unsafe { ptr.cast::<u8>().sub(ALIGNMENT) };
+		let size = unsafe { block.cast::<usize>().read() };
+		unsafe { alloc::dealloc(block, Layout::from_size_align_unchecked(size + ALIGNMENT, ALIGNMENT)) };
+		self.live.fetch_sub(1, Ordering::Relaxed);
+	}
+}
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+/// Registers a [`TrackingAllocator`] for CPU memory and checks that ONNX Runtime allocates through it when running a
+/// session. See `tests/execution_mode.rs` for how `branches.onnx` was produced.
+#[test]
+fn custom_allocator() -> ort::Result<()> {
+	ort::init()
+		.with_name("integration_test")
+		.with_custom_allocator(TrackingAllocator { allocations: &ALLOCATIONS, live: &LIVE })
+		.commit()?;
+
+	let session = Session::builder()?
+		.with_use_env_allocators()?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+
+	let allocations_before_run = ALLOCATIONS.load(Ordering::Relaxed);
+	let input = [-2.0_f32, -0.5, 0.5, 2.0];
+	let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+	let expected: Vec<f32> = input.iter().map(|&x| x.max(0.0) - x).collect();
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+
+	let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+	assert!(allocations > 0);
+	assert!(allocations > allocations_before_run, "expected the session's run to allocate through the custom allocator");
+
+	// the output tensor is still alive, so at least one block shouldn't have been freed yet
+	assert!(LIVE.load(Ordering::Relaxed) > 0);
+
+	Ok(())
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "custom-ops")]
+
+use ort::Session;
+use test_log::test;
+
+#[test]
+fn custom_op_domains() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let builder = Session::builder()?;
+	assert!(builder.custom_op_domains().is_empty());
+
+	// SAFETY: no ops are registered, so there are no pointers to keep alive
+	let builder = unsafe { builder.with_custom_op_domain("com.mydomain", &[])?.with_custom_op_domain("com.otherdomain", &[])? };
+	assert_eq!(builder.custom_op_domains(), ["com.mydomain", "com.otherdomain"]);
+
+	// cloned builders keep their registered domains
+	assert_eq!(builder.clone().custom_op_domains(), builder.custom_op_domains());
+
+	Ok(())
+}
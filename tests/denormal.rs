@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Runs `mul_denormal.onnx` with denormals flushed to zero. The model multiplies a `float[4]` input `x` by a scale of
+/// 1e-30, so small inputs produce denormal outputs; it was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Mul', ['x', 'scale'], ['y'], name='mul')],
+/// 	'mul_denormal',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, [4])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, [4])],
+/// 	initializer=[helper.make_tensor('scale', TensorProto.FLOAT, [], [1e-30])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'mul_denormal.onnx')
+/// ```
+#[test]
+fn denormal_as_zero() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?
+		.with_denormal_as_zero(true)?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("mul_denormal.onnx"))?;
+
+	let input = [1e-10_f32, -1e-10, 2.0, 0.0];
+	let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+	let (_, y) = outputs["y"].extract_raw_tensor::<f32>()?;
+
+	// whether the denormal results are actually flushed depends on the CPU & which thread ran the kernel, so only
+	// check that they are either flushed or correct to within a few ULPs of the smallest denormal
+	for (&x, &y) in input.iter().zip(y) {
+		let expected = x * 1e-30;
+		assert!(y == 0.0 || (y - expected).abs() <= 1e-44, "{x} * 1e-30 = {y}");
+	}
+	// normal results must be unaffected
+	assert!((y[2] - 2e-30).abs() < 1e-36);
+
+	Ok(())
+}
@@ -0,0 +1,27 @@
+use std::path::Path;
+
+use ort::{CPUExecutionProvider, Session};
+use test_log::test;
+
+/// Checks that execution providers configured on the environment are registered for sessions built against it, after
+/// any configured on the session itself. See `tests/execution_mode.rs` for how `branches.onnx` was produced.
+#[test]
+fn environment_execution_providers() -> ort::Result<()> {
+	ort::init()
+		.with_name("integration_test")
+		.with_execution_providers([CPUExecutionProvider::default().with_arena_allocator().build()])
+		.commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx");
+
+	let session = Session::builder()?.with_model_from_file(&model_path)?;
+	assert_eq!(session.registered_execution_providers(), ["CPUExecutionProvider"]);
+
+	// the session's own execution providers are registered first, followed by the environment's
+	let session = Session::builder()?
+		.with_execution_providers([CPUExecutionProvider::default().with_intra_threads(1).build()])?
+		.with_model_from_file(&model_path)?;
+	assert_eq!(session.registered_execution_providers(), ["CPUExecutionProvider", "CPUExecutionProvider"]);
+
+	Ok(())
+}
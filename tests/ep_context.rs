@@ -0,0 +1,56 @@
+use ort::Session;
+use test_log::test;
+
+/// Checks that `SessionBuilder::with_ep_context` sets the corresponding session configuration entries.
+#[test]
+fn ep_context_config() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let context_path = std::env::temp_dir().join("upsample_ctx.onnx");
+	let builder = Session::builder()?.with_ep_context(true, Some(&context_path), false)?;
+	assert_eq!(
+		builder.describe().config_entries,
+		[
+			("ep.context_enable".to_string(), "1".to_string()),
+			("ep.context_file_path".to_string(), context_path.to_str().unwrap().to_string()),
+			("ep.context_embed_mode".to_string(), "0".to_string())
+		]
+	);
+
+	// without a path, ONNX Runtime picks one next to the model
+	let builder = Session::builder()?.with_ep_context(true, None, true)?;
+	assert!(!builder.describe().config_entries.iter().any(|(k, _)| k == "ep.context_file_path"));
+
+	Ok(())
+}
+
+/// Checks that building a session with EP context generation enabled writes the context model.
+#[test]
+#[cfg(feature = "tensorrt")]
+#[ignore = "requires a TensorRT-capable GPU"]
+fn ep_context_model_written() -> ort::Result<()> {
+	use std::path::Path;
+
+	use ort::{ExecutionProvider, TensorRTExecutionProvider};
+
+	ort::init().with_name("integration_test").commit()?;
+
+	let context_path = std::env::temp_dir().join(format!("ort-ep-context-{}.onnx", std::process::id()));
+	let ep = TensorRTExecutionProvider::default();
+	assert!(ep.is_available()?);
+
+	let _session = Session::builder()?
+		.with_execution_providers([ep.build().error_on_failure()])?
+		.with_ep_context(true, Some(&context_path), true)?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.onnx"))?;
+	assert!(context_path.exists(), "context model should be written when the session is built");
+
+	// the context model can be loaded directly
+	let _session = Session::builder()?
+		.with_execution_providers([TensorRTExecutionProvider::default().build().error_on_failure()])?
+		.with_model_from_file(&context_path)?;
+
+	std::fs::remove_file(&context_path).unwrap();
+
+	Ok(())
+}
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use ort::{CPUExecutionProvider, Session};
+use test_log::test;
+
+/// Checks `SessionBuilder::check_full_ep_coverage` with a model that runs entirely on the CPU. See
+/// `tests/metadata_overrides.rs` for how `identity_dynamic_batch.onnx` was produced.
+#[test]
+fn ep_coverage() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_dynamic_batch.onnx");
+	let builder = Session::builder()?.with_execution_providers([CPUExecutionProvider::default().build()])?;
+	assert!(builder.check_full_ep_coverage(&model_path, "CPUExecutionProvider")?);
+	assert!(!builder.check_full_ep_coverage(&model_path, "CUDAExecutionProvider")?);
+
+	// the builder is left as-is, and can still build the session
+	let session = builder.with_model_from_file(&model_path)?;
+	assert_eq!(session.registered_execution_providers(), ["CPUExecutionProvider"]);
+
+	Ok(())
+}
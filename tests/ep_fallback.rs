@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use ort::{inputs, CPUExecutionProvider, CUDAExecutionProvider, Session};
+use test_log::test;
+
+/// Builds a session with a primary execution provider that fails to register (CUDA, which is unavailable in tests)
+/// falling back to the CPU EP via `ExecutionProviderDispatch::or_else`, and checks that the session is usable. See
+/// `tests/execution_mode.rs` for how `branches.onnx` was produced.
+#[test]
+fn ep_or_else_fallback() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?
+		.with_execution_providers([CUDAExecutionProvider::default()
+			.build()
+			.or_else(CPUExecutionProvider::default().with_arena_allocator())])?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+
+	let outputs = session.run(inputs![(vec![4], &[-2.0_f32, -0.5, 0.5, 2.0][..])]?)?;
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.0, vec![4]);
+
+	Ok(())
+}
@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use ort::{inputs, ExecutionMode, Session};
+use test_log::test;
+
+/// Runs `branches.onnx` in parallel execution mode. The model computes `y = Relu(x) + Neg(x)` for a `float[4]` input
+/// `x`, with `Relu` and `Neg` in independent branches, and was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[
+/// 		helper.make_node('Relu', ['x'], ['a'], name='relu'),
+/// 		helper.make_node('Neg', ['x'], ['b'], name='neg'),
+/// 		helper.make_node('Add', ['a', 'b'], ['y'], name='add')
+/// 	],
+/// 	'branches',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, [4])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, [4])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'branches.onnx')
+/// ```
+#[test]
+fn parallel_execution_mode() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?
+		.with_execution_mode(ExecutionMode::Parallel)?
+		.with_inter_threads(2)?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+
+	let input = [-2.0_f32, -0.5, 0.5, 2.0];
+	let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+	let (_, output) = outputs["y"].extract_raw_tensor::<f32>()?;
+	let expected: Vec<f32> = input.iter().map(|&x| x.max(0.0) - x).collect();
+	assert_eq!(output, &expected[..]);
+
+	Ok(())
+}
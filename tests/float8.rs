@@ -0,0 +1,53 @@
+#![cfg(feature = "float8")]
+
+use std::path::Path;
+
+use ort::{inputs, Float8E4M3FN, Float8E5M2, Session, TensorElementType};
+use test_log::test;
+
+/// Round-trips FP8 tensors through `identity_float8.onnx`, which has two `Identity` nodes: one mapping a
+/// `float8e4m3fn[4]` input `x` to `y`, and one mapping a `float8e5m2[4]` input `z` to `w`. It was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[
+/// 		helper.make_node('Identity', ['x'], ['y'], name='identity_e4m3fn'),
+/// 		helper.make_node('Identity', ['z'], ['w'], name='identity_e5m2')
+/// 	],
+/// 	'identity_float8',
+/// 	[
+/// 		helper.make_tensor_value_info('x', TensorProto.FLOAT8E4M3FN, [4]),
+/// 		helper.make_tensor_value_info('z', TensorProto.FLOAT8E5M2, [4])
+/// 	],
+/// 	[
+/// 		helper.make_tensor_value_info('y', TensorProto.FLOAT8E4M3FN, [4]),
+/// 		helper.make_tensor_value_info('w', TensorProto.FLOAT8E5M2, [4])
+/// 	]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 19)], ir_version=9), 'identity_float8.onnx')
+/// ```
+#[test]
+fn float8_identity() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_float8.onnx"))?;
+
+	// 1.0, -0.5, NaN, smallest subnormal
+	let e4m3fn = [0x38, 0xb0, 0x7f, 0x01].map(Float8E4M3FN::from_bits);
+	// 1.0, -0.5, +inf, NaN
+	let e5m2 = [0x3c, 0xb8, 0x7c, 0x7e].map(Float8E5M2::from_bits);
+
+	let outputs = session.run(inputs![(vec![4], &e4m3fn[..]), (vec![4], &e5m2[..])]?)?;
+
+	assert_eq!(outputs["y"].tensor_element_type()?, TensorElementType::Float8E4M3FN);
+	assert_eq!(outputs["y"].extract_raw_tensor::<Float8E4M3FN>()?.1, &e4m3fn);
+
+	assert_eq!(outputs["w"].tensor_element_type()?, TensorElementType::Float8E5M2);
+	let output = outputs["w"].extract_tensor::<Float8E5M2>()?;
+	assert_eq!(output.view().iter().map(|x| x.to_bits()).collect::<Vec<_>>(), vec![0x3c, 0xb8, 0x7c, 0x7e]);
+
+	Ok(())
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use ndarray::Array4;
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Wraps the raw pointer of an existing session without taking ownership of it, and runs inference through the
+/// wrapper. See `tests/upsample.rs` for how `upsample.onnx` was produced.
+#[test]
+fn session_from_raw() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?
+		.with_intra_threads(1)?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.onnx"))?;
+
+	let wrapper = unsafe { Session::from_raw(session.ptr(), false) }?;
+	assert_eq!(wrapper.inputs.len(), session.inputs.len());
+	assert_eq!(wrapper.inputs[0].name, session.inputs[0].name);
+	assert_eq!(wrapper.outputs[0].name, session.outputs[0].name);
+
+	let input = Array4::<f32>::zeros((1, 4, 4, 3));
+	{
+		let outputs = wrapper.run(inputs![input.view()]?)?;
+		assert_eq!(outputs[0].extract_tensor::<f32>()?.view().shape(), [1, 8, 8, 3]);
+	}
+
+	// dropping the non-owning wrapper must not release the session
+	drop(wrapper);
+	let outputs = session.run(inputs![input.view()]?)?;
+	assert_eq!(outputs[0].extract_tensor::<f32>()?.view().shape(), [1, 8, 8, 3]);
+
+	Ok(())
+}
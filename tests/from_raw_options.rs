@@ -0,0 +1,31 @@
+use std::{path::Path, ptr};
+
+use ort::{inputs, SessionBuilder};
+use test_log::test;
+
+/// Configures an `OrtSessionOptions` through the C API as a host application would, adopts it into a `SessionBuilder`,
+/// and builds a working session from it. See `tests/execution_mode.rs` for how `branches.onnx` was produced.
+#[test]
+fn session_builder_from_raw_options() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let api = ort::api();
+	let mut options_ptr: *mut ort_sys::OrtSessionOptions = ptr::null_mut();
+	unsafe {
+		assert!(api.CreateSessionOptions.unwrap()(&mut options_ptr).is_null());
+		assert!(api.SetIntraOpNumThreads.unwrap()(options_ptr, 1).is_null());
+		assert!(api.SetSessionGraphOptimizationLevel.unwrap()(options_ptr, ort_sys::GraphOptimizationLevel::ORT_DISABLE_ALL).is_null());
+	}
+	assert!(!options_ptr.is_null());
+
+	// the builder takes ownership of the options, so they must not be released here
+	let builder = unsafe { SessionBuilder::from_raw_options(options_ptr) };
+	let session = builder
+		.with_inter_threads(1)?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+
+	let outputs = session.run(inputs![(vec![4], &[-2.0_f32, -0.5, 0.5, 2.0][..])]?)?;
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.0, vec![4]);
+
+	Ok(())
+}
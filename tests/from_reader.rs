@@ -0,0 +1,21 @@
+use std::{io::Cursor, path::Path};
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Loads a model from an in-memory reader and runs it. See `tests/profiling.rs` for how `branches_dynamic.onnx` was
+/// produced.
+#[test]
+fn session_from_reader() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model = std::fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx")).unwrap();
+	let session = Session::builder()?.commit_from_reader(Cursor::new(model))?;
+
+	let input = [-1.0_f32, 0.0, 2.0];
+	let outputs = session.run(inputs![(vec![3], &input[..])]?)?;
+	// y = Relu(x) + Neg(x)
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, [1.0, 0.0, 0.0]);
+
+	Ok(())
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use ort::{GraphStats, Session};
+use test_log::test;
+
+/// Checks `Session::graph_stats` for sessions loaded from both a file and memory. `qdq_relu.onnx` has 3 nodes and two
+/// scalar initializers (a `float` scale and a `uint8` zero point); see `tests/qdq.rs` for how it was produced.
+#[test]
+fn graph_stats() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let expected = GraphStats {
+		node_count: 3,
+		initializer_count: 2,
+		parameter_count: 2,
+		parameter_bytes: 5
+	};
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("qdq_relu.onnx");
+	let session = Session::builder()?.with_model_from_file(&model_path)?;
+	assert_eq!(session.graph_stats()?, expected);
+
+	let session = Session::builder()?.with_model_from_memory(&std::fs::read(&model_path).unwrap())?;
+	assert_eq!(session.graph_stats()?, expected);
+
+	// statistics aren't available for ORT format models
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.ort"))?;
+	assert!(matches!(session.graph_stats(), Err(ort::Error::ComputeGraphStats(_))));
+
+	Ok(())
+}
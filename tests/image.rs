@@ -0,0 +1,54 @@
+#![cfg(feature = "image")]
+
+use std::path::Path;
+
+use image::{imageops::FilterType, ImageBuffer, Rgb};
+use ort::{inputs, ImageLayout, Session};
+use test_log::test;
+
+/// This test verifies that an image survives a round trip through a model via `Value::to_image`.
+/// `identity_image.onnx` is an `Identity` of a `float[N, C, H, W]` input and was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Identity', ['x'], ['y'], name='identity')],
+/// 	'identity_image',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, ['N', 'C', 'H', 'W'])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, ['N', 'C', 'H', 'W'])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'identity_image.onnx')
+/// ```
+#[test]
+fn image_round_trip() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data");
+	let session = Session::builder()?.with_model_from_file(data_dir.join("identity_image.onnx"))?;
+
+	let original = image::open(data_dir.join("mushroom.png")).unwrap().resize_exact(64, 48, FilterType::Nearest).to_rgb8();
+	let (width, height) = original.dimensions();
+
+	// normalize to [0, 1] in NCHW layout
+	let mut input = vec![0.0_f32; 3 * width as usize * height as usize];
+	let plane_len = width as usize * height as usize;
+	for (x, y, Rgb(pixel)) in original.enumerate_pixels() {
+		for (channel, value) in pixel.iter().enumerate() {
+			input[channel * plane_len + y as usize * width as usize + x as usize] = *value as f32 / 255.0;
+		}
+	}
+
+	let outputs = session.run(inputs![(vec![1, 3, height as i64, width as i64], &input[..])]?)?;
+	let output: ImageBuffer<Rgb<u8>, Vec<u8>> = outputs["y"].to_image(ImageLayout::Nchw, Some((0.0, 255.0)))?;
+
+	assert_eq!(output.dimensions(), (width, height));
+	for (expected, actual) in original.pixels().zip(output.pixels()) {
+		for (e, a) in expected.0.iter().zip(actual.0) {
+			assert!(e.abs_diff(a) <= 1, "expected {expected:?}, got {actual:?}");
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use ort::{inputs, Session, TensorElementType};
+use test_log::test;
+
+/// Round-trips 16-bit integer tensors through `identity_int16.onnx`, which has two `Identity` nodes: one mapping an
+/// `int16[1600]` input `x` to `y`, and one mapping a `uint16[1600]` input `z` to `w`. It was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[
+/// 		helper.make_node('Identity', ['x'], ['y'], name='identity_int16'),
+/// 		helper.make_node('Identity', ['z'], ['w'], name='identity_uint16')
+/// 	],
+/// 	'identity_int16',
+/// 	[
+/// 		helper.make_tensor_value_info('x', TensorProto.INT16, [1600]),
+/// 		helper.make_tensor_value_info('z', TensorProto.UINT16, [1600])
+/// 	],
+/// 	[
+/// 		helper.make_tensor_value_info('y', TensorProto.INT16, [1600]),
+/// 		helper.make_tensor_value_info('w', TensorProto.UINT16, [1600])
+/// 	]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'identity_int16.onnx')
+/// ```
+#[test]
+fn int16_identity() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_int16.onnx"))?;
+
+	// 100ms of 16kHz PCM audio, covering the full range of values (including ones that would be mangled by truncation)
+	let pcm: Vec<i16> = (0..1600).map(|i| (i as i16).wrapping_mul(41).wrapping_sub(i16::MAX)).collect();
+	let unsigned: Vec<u16> = pcm.iter().map(|x| *x as u16).collect();
+
+	let outputs = session.run(inputs![(vec![1600], &pcm[..]), (vec![1600], &unsigned[..])]?)?;
+
+	assert_eq!(outputs["y"].tensor_element_type()?, TensorElementType::Int16);
+	let (shape, data) = outputs["y"].extract_raw_tensor::<i16>()?;
+	assert_eq!(shape, vec![1600]);
+	assert_eq!(data, &pcm[..]);
+	let (_, _, bytes) = outputs["y"].to_bytes()?;
+	assert_eq!(bytes, pcm.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>());
+
+	assert_eq!(outputs["w"].tensor_element_type()?, TensorElementType::Uint16);
+	let output = outputs["w"].extract_tensor::<u16>()?;
+	assert_eq!(output.view().iter().copied().collect::<Vec<_>>(), unsigned);
+	let (_, _, bytes) = outputs["w"].to_bytes()?;
+	assert_eq!(bytes, unsigned.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>());
+
+	Ok(())
+}
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use ort::{inputs, IntoInputs, Session, SessionInputs, Value};
+use test_log::test;
+
+/// Typed inputs for `add_two.onnx`, which computes `sum = a + b` for two `float[N]` inputs `a` & `b`.
+struct AddInputs {
+	a: Vec<f32>,
+	b: Vec<f32>
+}
+
+impl IntoInputs<'static> for AddInputs {
+	fn into_inputs(self) -> ort::Result<SessionInputs<'static>> {
+		Ok(vec![
+			("a", Value::from_array((vec![self.a.len() as i64], &self.a[..]))?),
+			("b", Value::from_array((vec![self.b.len() as i64], &self.b[..]))?)
+		]
+		.into())
+	}
+}
+
+/// Runs a session with a struct implementing `IntoInputs`, alongside the other built-in input types.
+/// `add_two.onnx` was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Add', ['a', 'b'], ['sum'], name='add')],
+/// 	'add_two',
+/// 	[
+/// 		helper.make_tensor_value_info('a', TensorProto.FLOAT, ['N']),
+/// 		helper.make_tensor_value_info('b', TensorProto.FLOAT, ['N'])
+/// 	],
+/// 	[helper.make_tensor_value_info('sum', TensorProto.FLOAT, ['N'])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'add_two.onnx')
+/// ```
+#[test]
+fn into_inputs() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("add_two.onnx"))?;
+
+	let outputs = session.run(AddInputs {
+		a: vec![1.0, 2.0, 3.0],
+		b: vec![0.5, -2.0, 10.0]
+	})?;
+	assert_eq!(outputs["sum"].extract_raw_tensor::<f32>()?.1, &[1.5, 0.0, 13.0]);
+
+	// named inputs in a `Vec` may be given in any order
+	let outputs = session.run(vec![
+		("b", Value::from_array((vec![2], &[1.0_f32, 1.0][..]))?),
+		("a", Value::from_array((vec![2], &[2.0_f32, 3.0][..]))?)
+	])?;
+	assert_eq!(outputs["sum"].extract_raw_tensor::<f32>()?.1, &[3.0, 4.0]);
+
+	// the `inputs!` macro's output is still accepted
+	let outputs = session.run(inputs![(vec![1], &[4.0_f32][..]), (vec![1], &[5.0_f32][..])]?)?;
+	assert_eq!(outputs["sum"].extract_raw_tensor::<f32>()?.1, &[9.0]);
+	let outputs = session.run(inputs!["a" => (vec![1], &[4.0_f32][..]), "b" => (vec![1], &[-5.0_f32][..])]?)?;
+	assert_eq!(outputs["sum"].extract_raw_tensor::<f32>()?.1, &[-1.0]);
+
+	Ok(())
+}
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Runs a session with intra-op thread spinning disabled. See `tests/execution_mode.rs` for how `branches.onnx` was
+/// produced.
+#[test]
+fn intra_op_spinning() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let builder = Session::builder()?.with_intra_threads(2)?.with_intra_op_spinning(false)?;
+	assert!(builder.describe().config_entries.contains(&("session.intra_op.allow_spinning".to_string(), "0".to_string())));
+	let session = builder.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+
+	let input = [-2.0_f32, -0.5, 0.5, 2.0];
+	let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+	let expected: Vec<f32> = input.iter().map(|&x| x.max(0.0) - x).collect();
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+
+	Ok(())
+}
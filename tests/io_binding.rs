@@ -0,0 +1,58 @@
+use std::path::Path;
+
+use ndarray::{Array, Array4};
+use ort::{GraphOptimizationLevel, Session, TensorElementType, Value};
+use test_log::test;
+
+/// This test verifies that ONNX Runtime writes outputs directly into a caller-provided buffer bound with
+/// `IoBinding::bind_output_to_buffer`. See `tests/upsample.rs` for how `upsample.onnx` was produced.
+#[test]
+fn bind_output_to_buffer() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?
+		.with_optimization_level(GraphOptimizationLevel::Level1)?
+		.with_intra_threads(1)?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.onnx"))?;
+
+	let input: Array4<f32> = Array::from_shape_fn((1, 2, 2, 3), |(_, y, x, c)| (y * 6 + x * 3 + c) as f32);
+
+	// Run once without binding to get the expected output.
+	let expected: Vec<u8> = {
+		let outputs = session.run(ort::inputs![input.view()]?)?;
+		let output = outputs[0].extract_tensor::<f32>()?;
+		let output = output.view();
+		assert_eq!(output.shape(), [1, 4, 4, 3]);
+		output.iter().flat_map(|x| x.to_ne_bytes()).collect()
+	};
+
+	let mut buffer = vec![0u8; expected.len()];
+	{
+		let mut binding = session.create_binding()?;
+		binding.bind_input(&session.inputs[0].name, Value::from_array(input.view())?)?;
+		binding.bind_output_to_buffer(&session.outputs[0].name, &mut buffer, TensorElementType::Float32, [1, 4, 4, 3])?;
+		binding.run()?;
+	}
+	assert_eq!(buffer, expected);
+
+	// A buffer too small to hold the output should be rejected, and so should one which isn't aligned for `f32`s.
+	let mut small_buffer = vec![0u8; expected.len() - 1];
+	let mut unaligned_buffer = vec![0u8; expected.len() + 4];
+	let offset = (4 - unaligned_buffer.as_ptr() as usize % 4) % 4 + 1;
+	let mut binding = session.create_binding()?;
+	assert!(matches!(
+		binding.bind_output_to_buffer(&session.outputs[0].name, &mut small_buffer, TensorElementType::Float32, [1, 4, 4, 3]),
+		Err(ort::Error::OutputBufferTooSmall { .. })
+	));
+	assert!(matches!(
+		binding.bind_output_to_buffer(
+			&session.outputs[0].name,
+			&mut unaligned_buffer[offset..offset + expected.len()],
+			TensorElementType::Float32,
+			[1, 4, 4, 3]
+		),
+		Err(ort::Error::OutputBufferMisaligned { alignment: 4 })
+	));
+
+	Ok(())
+}
@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Checks that `Session::input_names`/`Session::output_names` match the model's declared IO and are stable across
+/// calls. See `tests/execution_mode.rs` for how `branches.onnx` was produced.
+#[test]
+fn io_names() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+	assert_eq!(session.input_names(), ["x"]);
+	assert_eq!(session.output_names(), ["y"]);
+
+	// repeated calls should borrow the same underlying strings
+	let (first, second) = (session.input_names(), session.input_names());
+	assert!(first.iter().zip(&second).all(|(a, b)| std::ptr::eq(*a, *b)));
+	let (first, second) = (session.output_names(), session.output_names());
+	assert!(first.iter().zip(&second).all(|(a, b)| std::ptr::eq(*a, *b)));
+
+	// the names can be used directly to build inputs & index outputs
+	let [x] = session.input_names()[..] else { unreachable!() };
+	let [y] = session.output_names()[..] else { unreachable!() };
+	let outputs = session.run(inputs![x => (vec![2], &[-1.0_f32, 1.0][..])]?)?;
+	assert_eq!(outputs[y].extract_raw_tensor::<f32>()?.0, vec![2]);
+
+	Ok(())
+}
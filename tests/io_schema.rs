@@ -0,0 +1,35 @@
+#![cfg(feature = "io-schema")]
+
+use std::path::Path;
+
+use ort::Session;
+use test_log::test;
+
+#[test]
+fn io_schema() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	// `branches_dynamic.onnx` computes `y = Relu(x) + Neg(x)` for a `float[N]` input `x`
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx"))?;
+
+	let schema: serde_json::Value = serde_json::from_str(&session.io_schema()?).expect("schema should be valid JSON");
+	assert_eq!(
+		schema,
+		serde_json::json!({
+			"inputs": [{ "name": "x", "type": { "type": "tensor", "element_type": "float", "dimensions": ["N"] } }],
+			"outputs": [{ "name": "y", "type": { "type": "tensor", "element_type": "float", "dimensions": ["N"] } }]
+		})
+	);
+
+	// `upsample.onnx` has dynamic batch & spatial dimensions, but a fixed channel dimension
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.onnx"))?;
+	let schema: serde_json::Value = serde_json::from_str(&session.io_schema()?).expect("schema should be valid JSON");
+	let input = &schema["inputs"][0];
+	assert_eq!(input["name"], session.inputs[0].name.as_str());
+	assert_eq!(input["type"]["element_type"], "float");
+	let dimensions = input["type"]["dimensions"].as_array().unwrap();
+	assert_eq!(dimensions.len(), 4);
+	assert_eq!(dimensions[3], 3);
+
+	Ok(())
+}
@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Checks that session outputs are handed out as the buffers ONNX Runtime allocated, rather than being copied when the
+/// run completes or when they're accessed, so outputs that are never accessed cost nothing. See `tests/run_extract.rs`
+/// for how `multi_output.onnx` was produced.
+#[test]
+fn lazy_outputs() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("multi_output.onnx"))?;
+	let outputs = session.run(inputs![(vec![4], &[-2.5_f32, -0.5, 0.5, 2.5][..])]?)?;
+	assert_eq!(outputs.len(), 3);
+
+	// accessing an output borrows ONNX Runtime's buffer in-place, so repeated accesses (by name or by index) yield the
+	// same memory; the other outputs are left untouched
+	let (_, relu) = outputs["relu"].extract_raw_tensor::<f32>()?;
+	assert_eq!(relu, [0.0, 0.0, 0.5, 2.5]);
+	let (_, relu_again) = outputs[0].extract_raw_tensor::<f32>()?;
+	assert_eq!(relu.as_ptr(), relu_again.as_ptr());
+
+	// an explicit copy yields the same data in different memory
+	let (_, _, copied) = outputs["relu"].to_bytes()?;
+	assert_ne!(copied.as_ptr() as *const f32, relu.as_ptr());
+
+	Ok(())
+}
@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use ort::{inputs, Error, Session};
+use test_log::test;
+
+/// Tests `SessionBuilder::with_metadata_driven_overrides` with `identity_dynamic_batch.onnx`, which maps a
+/// `float[batch, 2]` input `x` to `y` with an `Identity` node, and carries the metadata entries `preferred_batch: batch=3`
+/// and `invalid_batch: batch=three`. It was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Identity', ['x'], ['y'], name='identity')],
+/// 	'identity_dynamic_batch',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, ['batch', 2])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, ['batch', 2])]
+/// )
+/// model = helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7)
+/// helper.set_model_props(model, {'preferred_batch': 'batch=3', 'invalid_batch': 'batch=three'})
+/// onnx.save(model, 'identity_dynamic_batch.onnx')
+/// ```
+#[test]
+fn metadata_driven_overrides() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_dynamic_batch.onnx");
+
+	let session = Session::builder()?.with_model_from_file(&model_path)?;
+	assert_eq!(session.inputs[0].input_type.tensor_dimensions(), Some(&vec![-1, 2]));
+
+	let session = Session::builder()?.with_metadata_driven_overrides("preferred_batch")?.with_model_from_file(&model_path)?;
+	assert_eq!(session.inputs[0].input_type.tensor_dimensions(), Some(&vec![3, 2]));
+	let input = [1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+	let outputs = session.run(inputs![(vec![3, 2], &input[..])]?)?;
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &input[..]);
+	// the batch dimension is now fixed, so other batch sizes are rejected
+	assert!(session.run(inputs![(vec![1, 2], &input[..2])]?).is_err());
+
+	// the same applies to models loaded from memory
+	let model_bytes = std::fs::read(&model_path).unwrap();
+	let session = Session::builder()?.with_metadata_driven_overrides("preferred_batch")?.with_model_from_memory(&model_bytes)?;
+	assert_eq!(session.inputs[0].input_type.tensor_dimensions(), Some(&vec![3, 2]));
+
+	// a missing key leaves the model as-is
+	let session = Session::builder()?.with_metadata_driven_overrides("preferred_sequence")?.with_model_from_file(&model_path)?;
+	assert_eq!(session.inputs[0].input_type.tensor_dimensions(), Some(&vec![-1, 2]));
+
+	match Session::builder()?.with_metadata_driven_overrides("invalid_batch")?.with_model_from_file(&model_path) {
+		Err(Error::InvalidMetadataOverrides { key, value }) => assert_eq!((key.as_str(), value.as_str()), ("invalid_batch", "batch=three")),
+		r => panic!("expected invalid overrides error, got {:?}", r.map(|_| ()))
+	}
+
+	Ok(())
+}
+
+#[test]
+fn dimension_override() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?
+		.with_dimension_override("batch", 4)?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_dynamic_batch.onnx"))?;
+	assert_eq!(session.inputs[0].input_type.tensor_dimensions(), Some(&vec![4, 2]));
+
+	Ok(())
+}
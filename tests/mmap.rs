@@ -0,0 +1,42 @@
+#![cfg(feature = "memmap")]
+
+use std::{fs::File, path::Path};
+
+use memmap2::Mmap;
+use ort::{Session, TensorElementType, Value};
+use test_log::test;
+
+/// Runs inference over a tensor backed by a memory-mapped file of `f32`s. See `tests/execution_mode.rs` for how
+/// `branches.onnx`, which computes `y = Relu(x) + Neg(x)` for a `float[4]` input `x`, was produced.
+#[test]
+fn mmap_tensor() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+
+	// two tensors' worth of data, so the second can be mapped at a nonzero offset
+	let data = [-2.0_f32, -0.5, 0.5, 2.0, 3.0, -3.0, 1.0, -1.0];
+	let path = std::env::temp_dir().join(format!("ort-mmap-test-{}.bin", std::process::id()));
+	std::fs::write(&path, data.iter().flat_map(|x| x.to_ne_bytes()).collect::<Vec<u8>>()).unwrap();
+	let map = unsafe { Mmap::map(&File::open(&path).unwrap()).unwrap() };
+
+	for (i, chunk) in data.chunks(4).enumerate() {
+		let x = Value::from_mmap(&map, i * 16, vec![4], TensorElementType::Float32)?;
+		assert_eq!(x.extract_raw_tensor::<f32>()?.1, chunk);
+
+		let outputs = session.run(std::slice::from_ref(&*x))?;
+		let expected: Vec<f32> = chunk.iter().map(|x| x.max(0.0) - x).collect();
+		assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+	}
+
+	assert!(matches!(
+		Value::from_mmap(&map, 20, vec![4], TensorElementType::Float32),
+		Err(ort::Error::MmapOutOfBounds { offset: 20, len: 16, map_len: 32 })
+	));
+	assert!(matches!(Value::from_mmap(&map, 2, vec![2], TensorElementType::Float32), Err(ort::Error::MmapMisaligned { .. })));
+
+	drop(map);
+	std::fs::remove_file(&path).unwrap();
+
+	Ok(())
+}
@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use ort::{Session, TensorElementType, ValueType};
+use test_log::test;
+
+/// This test verifies that the types of sequence & map inputs are reported in full, including nested element types.
+/// `nested_types.onnx` passes a `float[2]` input `x` through an `Identity` node, and has two additional (unused)
+/// inputs: `records`, a `seq(map(string, float))`, and `nested`, a `seq(seq(int64[N]))`. It was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// record = helper.make_map_type_proto(TensorProto.STRING, helper.make_tensor_type_proto(TensorProto.FLOAT, None))
+/// nested = helper.make_sequence_type_proto(helper.make_tensor_type_proto(TensorProto.INT64, ['N']))
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Identity', ['x'], ['y'], name='identity')],
+/// 	'nested_types',
+/// 	[
+/// 		helper.make_tensor_value_info('x', TensorProto.FLOAT, [2]),
+/// 		helper.make_value_info('records', helper.make_sequence_type_proto(record)),
+/// 		helper.make_value_info('nested', helper.make_sequence_type_proto(nested))
+/// 	],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, [2])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'nested_types.onnx')
+/// ```
+#[test]
+fn nested_input_types() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("nested_types.onnx"))?;
+
+	let input_types: Vec<(&str, &ValueType)> = session.inputs.iter().map(|i| (i.name.as_str(), &i.input_type)).collect();
+	assert_eq!(
+		input_types,
+		[
+			(
+				"x",
+				&ValueType::Tensor {
+					ty: TensorElementType::Float32,
+					dimensions: vec![2]
+				}
+			),
+			(
+				"records",
+				&ValueType::Sequence(Box::new(ValueType::Map {
+					key: TensorElementType::String,
+					value: TensorElementType::Float32
+				}))
+			),
+			(
+				"nested",
+				&ValueType::Sequence(Box::new(ValueType::Sequence(Box::new(ValueType::Tensor {
+					ty: TensorElementType::Int64,
+					dimensions: vec![-1]
+				}))))
+			)
+		]
+	);
+	assert_eq!(session.inputs[2].input_type.to_string(), "Sequence<Sequence<Tensor<Int64>[-1]>>");
+
+	Ok(())
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "ndarray")]
+
+use std::path::Path;
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Post-processes a session output in place through a mutable view. See `tests/metadata_overrides.rs` for how
+/// `identity_dynamic_batch.onnx` was produced.
+#[test]
+fn output_view_mut() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session =
+		Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_dynamic_batch.onnx"))?;
+	let mut outputs = session.run(inputs![(vec![2, 2], &[1.0_f32, 1.0, 0.0, 2.0f32.ln()][..])]?)?;
+
+	let y = outputs.get_mut("y").unwrap();
+	let data_ptr = y.extract_raw_tensor::<f32>()?.1.as_ptr();
+
+	// softmax over each row, without allocating a new buffer
+	let mut view = y.try_view_tensor_mut::<f32, ndarray::Ix2>()?;
+	assert_eq!(view.as_ptr(), data_ptr);
+	for mut row in view.rows_mut() {
+		row.mapv_inplace(f32::exp);
+		let sum = row.sum();
+		row /= sum;
+	}
+
+	let (shape, probabilities) = outputs["y"].extract_raw_tensor::<f32>()?;
+	assert_eq!(shape, [2, 2]);
+	assert_eq!(probabilities.as_ptr(), data_ptr);
+	for (actual, expected) in probabilities.iter().zip([0.5, 0.5, 1.0 / 3.0, 2.0 / 3.0]) {
+		assert!((actual - expected).abs() < 1e-6, "expected {expected}, got {actual}");
+	}
+
+	Ok(())
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "profiling")]
+
+use std::path::Path;
+
+use ort::{inputs, GraphOptimizationLevel, Session};
+use test_log::test;
+
+/// This test verifies that `Session::profiling_summary` reports per-node timings. It runs `branches_dynamic.onnx`,
+/// which computes `y = Relu(x) + Neg(x)` for a `float[N]` input `x` and was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[
+/// 		helper.make_node('Relu', ['x'], ['a'], name='relu'),
+/// 		helper.make_node('Neg', ['x'], ['b'], name='neg'),
+/// 		helper.make_node('Add', ['a', 'b'], ['y'], name='add')
+/// 	],
+/// 	'branches_dynamic',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, ['N'])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, ['N'])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'branches_dynamic.onnx')
+/// ```
+#[test]
+fn profiling_summary() -> ort::Result<()> {
+	const RUNS: u64 = 3;
+
+	ort::init().with_name("integration_test").commit()?;
+
+	let profile_dir = std::env::temp_dir().join(format!("ort-profiling-test-{}", std::process::id()));
+	std::fs::create_dir_all(&profile_dir).unwrap();
+
+	let session = Session::builder()?
+		.with_optimization_level(GraphOptimizationLevel::Disable)?
+		.with_profiling(profile_dir.join("branches").to_str().unwrap())?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx"))?;
+
+	// use a large enough input that each kernel takes a measurable amount of time
+	let input: Vec<f32> = (0..1 << 20).map(|i| (i % 7) as f32 - 3.0).collect();
+	for _ in 0..RUNS {
+		session.run(inputs![(vec![input.len() as i64], &input[..])]?)?;
+	}
+
+	let summary = session.profiling_summary()?;
+	let mut op_names: Vec<&str> = summary.iter().map(|t| t.op_name.as_str()).collect();
+	op_names.sort_unstable();
+	assert_eq!(op_names, ["Add", "Neg", "Relu"]);
+	for timing in &summary {
+		assert_eq!(timing.ep, "CPUExecutionProvider");
+		assert_eq!(timing.count, RUNS);
+		assert!(timing.duration_us > 0, "expected a nonzero duration for node `{}`", timing.node_name);
+	}
+
+	std::fs::remove_dir_all(&profile_dir).unwrap();
+
+	Ok(())
+}
+
+/// This test verifies that dropping the guard returned by `Session::profiling_scope` writes the profiling file.
+#[test]
+fn profiling_scope() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let profile_dir = std::env::temp_dir().join(format!("ort-profiling-scope-test-{}", std::process::id()));
+	std::fs::create_dir_all(&profile_dir).unwrap();
+
+	let session = Session::builder()?
+		.with_profiling(profile_dir.join("branches").to_str().unwrap())?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx"))?;
+
+	{
+		let _profiling = session.profiling_scope();
+		let input = [-1.0_f32, 0.0, 1.0];
+		session.run(inputs![(vec![3], &input[..])]?)?;
+		// ONNX Runtime may create the file up front, but only writes to it once profiling ends
+		assert!(
+			std::fs::read_dir(&profile_dir).unwrap().all(|e| e.unwrap().metadata().unwrap().len() == 0),
+			"profiling file should not be written until the guard is dropped"
+		);
+	}
+
+	let files: Vec<_> = std::fs::read_dir(&profile_dir).unwrap().map(|e| e.unwrap().path()).collect();
+	assert_eq!(files.len(), 1);
+	let trace: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&files[0]).unwrap()).unwrap();
+	assert!(!trace.as_array().unwrap().is_empty());
+
+	std::fs::remove_dir_all(&profile_dir).unwrap();
+
+	Ok(())
+}
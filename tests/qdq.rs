@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use ort::{inputs, Session, SessionBuilder};
+use test_log::test;
+
+/// Runs `qdq_relu.onnx` with the QDQ-related session options. The model quantizes a `float[4]` input `x` to `uint8`
+/// with a scale of 0.1, dequantizes it, and applies `Relu`; it was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[
+/// 		helper.make_node('QuantizeLinear', ['x', 'scale', 'zero_point'], ['x_q'], name='quantize'),
+/// 		helper.make_node('DequantizeLinear', ['x_q', 'scale', 'zero_point'], ['x_dq'], name='dequantize'),
+/// 		helper.make_node('Relu', ['x_dq'], ['y'], name='relu')
+/// 	],
+/// 	'qdq_relu',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, [4])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, [4])],
+/// 	initializer=[
+/// 		helper.make_tensor('scale', TensorProto.FLOAT, [], [0.1]),
+/// 		helper.make_tensor('zero_point', TensorProto.UINT8, [], [0])
+/// 	]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'qdq_relu.onnx')
+/// ```
+#[test]
+fn qdq_options() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("qdq_relu.onnx");
+	let input = [-1.0_f32, 0.0, 0.3, 1.04];
+	let expected = [0.0_f32, 0.0, 0.3, 1.0];
+
+	let builders: [fn() -> ort::Result<SessionBuilder>; 4] = [
+		|| Session::builder(),
+		|| Session::builder()?.with_disable_quant_qdq(true),
+		|| Session::builder()?.with_disable_quant_qdq(false)?.with_qdq_is_int8_allowed(true),
+		|| Session::builder()?.with_disabled_optimizers(&["QDQSelectorActionTransformer"])
+	];
+	for builder in builders {
+		let session = builder()?.with_model_from_file(&model_path)?;
+		let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+		let (_, output) = outputs["y"].extract_raw_tensor::<f32>()?;
+		for (actual, expected) in output.iter().zip(expected) {
+			assert!((actual - expected).abs() < 1e-6, "expected {expected}, got {actual}");
+		}
+	}
+
+	Ok(())
+}
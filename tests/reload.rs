@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use ort::{inputs, GraphOptimizationLevel, Session};
+use test_log::test;
+
+/// Reloads a session from a file in place, running it between reloads. See `tests/execution_mode.rs` for how
+/// `branches.onnx` was produced, and `tests/into_inputs.rs` for `add_two.onnx`.
+#[test]
+fn reload_from_file() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data");
+	let mut session = Session::builder()?
+		.with_optimization_level(GraphOptimizationLevel::Level1)?
+		.with_intra_threads(1)?
+		.with_model_from_file(data_dir.join("branches.onnx"))?;
+
+	let input = [-2.0_f32, -0.5, 0.5, 2.0];
+	let expected: Vec<f32> = input.iter().map(|&x| x.max(0.0) - x).collect();
+	for _ in 0..2 {
+		let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+		assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+		drop(outputs);
+
+		session.reload_from_file(data_dir.join("branches.onnx"))?;
+	}
+	let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+	drop(outputs);
+
+	// a failed reload leaves the session usable
+	assert!(session.reload_from_file(data_dir.join("does-not-exist.onnx")).is_err());
+	assert_eq!(session.input_names(), ["x"]);
+
+	// reloading a different model updates the session's inputs & outputs
+	session.reload_from_file(data_dir.join("add_two.onnx"))?;
+	assert_eq!(session.input_names(), ["a", "b"]);
+	assert_eq!(session.output_names(), ["sum"]);
+	let outputs = session.run(inputs![(vec![1], &[1.0_f32][..]), (vec![1], &[2.0_f32][..])]?)?;
+	assert_eq!(outputs["sum"].extract_raw_tensor::<f32>()?.1, &[3.0]);
+
+	Ok(())
+}
@@ -0,0 +1,91 @@
+use std::{
+	path::Path,
+	sync::{
+		atomic::{AtomicBool, AtomicUsize, Ordering},
+		Arc
+	}
+};
+
+use ort::{inputs, Session};
+use test_log::test;
+
+fn branches_session() -> ort::Result<Session> {
+	// `branches_dynamic.onnx` computes `y = Relu(x) + Neg(x)` for a `float[N]` input `x`; see `tests/profiling.rs`
+	Session::builder()?
+		.with_intra_threads(2)?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx"))
+}
+
+fn expected(input: &[f32]) -> Vec<f32> {
+	input.iter().map(|&x| x.max(0.0) - x).collect()
+}
+
+/// This test verifies that `Session::run_async` yields correct outputs, and that awaiting it lets other tasks on the
+/// same (single-threaded) runtime make progress while inference runs.
+#[test(tokio::test(flavor = "current_thread"))]
+async fn run_async() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = branches_session()?;
+
+	let input: Vec<f32> = (0..1 << 22).map(|i| (i % 7) as f32 - 3.0).collect();
+	let done = Arc::new(AtomicBool::new(false));
+	let ticks = Arc::new(AtomicUsize::new(0));
+	let ticker = tokio::spawn({
+		let (done, ticks) = (Arc::clone(&done), Arc::clone(&ticks));
+		async move {
+			while !done.load(Ordering::Acquire) {
+				ticks.fetch_add(1, Ordering::Relaxed);
+				tokio::task::yield_now().await;
+			}
+		}
+	});
+
+	let outputs = session.run_async(inputs![(vec![input.len() as i64], &input[..])]?)?.await?;
+	done.store(true, Ordering::Release);
+	// if `run_async` blocked the runtime's only thread, the ticker would not have had a chance to run yet
+	assert!(ticks.load(Ordering::Relaxed) > 0, "runtime thread was blocked during inference");
+	ticker.await.unwrap();
+
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected(&input)[..]);
+
+	// dropping a pending run should terminate it without leaving ONNX Runtime with dangling inputs
+	drop(session.run_async(inputs![(vec![input.len() as i64], &input[..])]?)?);
+
+	// passing more values than the model has inputs is an error rather than an out-of-bounds read
+	let too_many = inputs![(vec![1], &[1.0_f32][..]), (vec![1], &[2.0_f32][..])]?;
+	assert!(matches!(session.run_async(too_many), Err(ort::Error::PositionalInputCount { expected: 1, actual: 2 })));
+
+	Ok(())
+}
+
+/// This test verifies that leaking a pending `RunFuture` doesn't leave ONNX Runtime with a dangling session or inputs.
+#[test(tokio::test(flavor = "current_thread"))]
+async fn run_async_forget() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = branches_session()?;
+	let input: Vec<f32> = (0..1 << 22).map(|i| (i % 7) as f32 - 3.0).collect();
+	std::mem::forget(session.run_async(inputs![(vec![input.len() as i64], &input[..])]?)?);
+	drop(input);
+	// the run keeps its own reference to the session, so it can't be released from under it
+	drop(session);
+
+	Ok(())
+}
+
+/// This test verifies that `Session::spawn_run` runs inference on tokio's blocking thread pool.
+#[test(tokio::test)]
+#[cfg(feature = "tokio")]
+async fn spawn_run() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Arc::new(branches_session()?);
+
+	let input = [-2.0_f32, -0.5, 0.5, 2.0];
+	let outputs = session.spawn_run(inputs![(vec![4], &input[..])]?).await.expect("inference task panicked")?;
+	assert_eq!(outputs.len(), 1);
+	assert_eq!(outputs[0].extract_raw_tensor::<f32>()?.1, &expected(&input)[..]);
+
+	Ok(())
+}
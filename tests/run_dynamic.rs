@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use ort::{inputs, Error, Session};
+use test_log::test;
+
+/// This test verifies that a session with memory pattern optimization disabled can be run with inputs of different
+/// shapes. `relu_neg_dynamic.onnx` computes `y = Neg(Relu(x))` for a `float[batch, sequence]` input `x` and was
+/// produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Relu', ['x'], ['a'], name='relu'), helper.make_node('Neg', ['a'], ['y'], name='neg')],
+/// 	'relu_neg_dynamic',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, ['batch', 'sequence'])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, ['batch', 'sequence'])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'relu_neg_dynamic.onnx')
+/// ```
+#[test]
+fn run_dynamic() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("relu_neg_dynamic.onnx");
+	let session = Session::builder()?.with_memory_pattern(false)?.with_model_from_file(&model_path)?;
+
+	for sequence_length in [8, 16, 8] {
+		let input: Vec<f32> = (0..sequence_length).map(|i| i as f32 - 4.0).collect();
+		let outputs = session.run_dynamic(inputs![(vec![1, sequence_length], &input[..])]?)?;
+		let (shape, output) = outputs["y"].extract_raw_tensor::<f32>()?;
+		assert_eq!(shape, [1, sequence_length]);
+		assert_eq!(output, input.iter().map(|x| -x.max(0.0)).collect::<Vec<_>>());
+	}
+
+	let session = Session::builder()?.with_model_from_file(&model_path)?;
+	let input = [0.0_f32; 8];
+	assert!(matches!(session.run_dynamic(inputs![(vec![1, 8], &input[..])]?), Err(Error::MemoryPatternEnabled)));
+
+	Ok(())
+}
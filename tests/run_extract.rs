@@ -0,0 +1,83 @@
+use std::path::Path;
+
+use ndarray::{Array1, Array2, IxDyn};
+use ort::{inputs, Error, Session};
+use test_log::test;
+
+/// Checks that `Session::run_extract` matches running the session & extracting the output manually. See
+/// `tests/execution_mode.rs` for how `branches.onnx`, which computes `y = Relu(x) + Neg(x)` for a `float[4]` input `x`,
+/// was produced.
+#[test]
+fn run_extract() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+	let input = Array1::from_vec(vec![-2.0_f32, -0.5, 0.5, 2.0]);
+
+	let outputs = session.run(inputs![input.view()]?)?;
+	let manual = outputs["y"].extract_tensor::<f32>()?.view().to_owned();
+
+	let y: Array1<f32> = session.run_extract(inputs![input.view()]?, "y")?;
+	assert_eq!(y.into_dyn(), manual);
+	assert_eq!(session.run_extract::<f32, IxDyn, 1>(inputs![input.view()]?, "y")?, manual);
+
+	assert!(matches!(session.run_extract::<f32, IxDyn, 1>(inputs![input.view()]?, "z"), Err(Error::UnknownOutputName(name)) if name == "z"));
+	assert!(matches!(session.run_extract::<i64, IxDyn, 1>(inputs![input.view()]?, "y"), Err(Error::DataTypeMismatch { .. })));
+	let wrong_dimensionality: ort::Result<Array2<f32>> = session.run_extract(inputs![input.view()]?, "y");
+	assert!(matches!(wrong_dimensionality, Err(Error::InvalidViewShape(..))));
+
+	Ok(())
+}
+
+/// Extracts multiple outputs with different element types from `multi_output.onnx`, which maps a `float[4]` input `x`
+/// to `relu = Relu(x)`, `neg = Neg(x)`, and `truncated = Cast(x, to=INT64)`. It was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[
+/// 		helper.make_node('Relu', ['x'], ['relu'], name='relu'),
+/// 		helper.make_node('Neg', ['x'], ['neg'], name='neg'),
+/// 		helper.make_node('Cast', ['x'], ['truncated'], name='cast', to=TensorProto.INT64)
+/// 	],
+/// 	'multi_output',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, [4])],
+/// 	[
+/// 		helper.make_tensor_value_info('relu', TensorProto.FLOAT, [4]),
+/// 		helper.make_tensor_value_info('neg', TensorProto.FLOAT, [4]),
+/// 		helper.make_tensor_value_info('truncated', TensorProto.INT64, [4])
+/// 	]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'multi_output.onnx')
+/// ```
+#[test]
+fn run_extract_many() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("multi_output.onnx"))?;
+	let input = Array1::from_vec(vec![-2.5_f32, -0.5, 0.5, 2.5]);
+
+	// outputs are returned in the requested order, not the order they're declared in
+	let outputs = session.run_extract_many(inputs![input.view()]?, &["truncated", "relu"])?;
+	assert_eq!(outputs.len(), 2);
+	assert_eq!(outputs[0].extract_raw_tensor::<i64>()?.1, [-2, 0, 0, 2]);
+	assert_eq!(outputs[1].extract_raw_tensor::<f32>()?.1, [0.0, 0.0, 0.5, 2.5]);
+
+	let (relu, truncated): (Array1<f32>, Array1<i64>) = session.run_extract2(inputs![input.view()]?, ["relu", "truncated"])?;
+	assert_eq!(relu, Array1::from_vec(vec![0.0, 0.0, 0.5, 2.5]));
+	assert_eq!(truncated, Array1::from_vec(vec![-2, 0, 0, 2]));
+
+	let (neg, truncated, relu) = session.run_extract3::<f32, IxDyn, i64, IxDyn, f32, IxDyn, 1>(inputs![input.view()]?, ["neg", "truncated", "relu"])?;
+	assert_eq!(neg.as_slice(), Some(&[2.5_f32, 0.5, -0.5, -2.5][..]));
+	assert_eq!(truncated.as_slice(), Some(&[-2_i64, 0, 0, 2][..]));
+	assert_eq!(relu.as_slice(), Some(&[0.0_f32, 0.0, 0.5, 2.5][..]));
+
+	assert!(matches!(session.run_extract_many(inputs![input.view()]?, &["relu", "z"]), Err(Error::UnknownOutputName(name)) if name == "z"));
+	assert!(matches!(session.run_extract_many(inputs![input.view()]?, &["neg", "neg"]), Err(Error::DuplicateOutputName(name)) if name == "neg"));
+	let wrong_type: ort::Result<(Array1<f32>, Array1<f32>)> = session.run_extract2(inputs![input.view()]?, ["relu", "truncated"]);
+	assert!(matches!(wrong_type, Err(Error::DataTypeMismatch { .. })));
+
+	Ok(())
+}
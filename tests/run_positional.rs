@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use ort::{Error, Session, Value};
+use test_log::test;
+
+/// Runs a two-input model with inputs given by position, and compares the result to passing them by name. See
+/// `tests/into_inputs.rs` for how `add_two.onnx` was produced.
+#[test]
+fn run_positional() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("add_two.onnx"))?;
+	assert_eq!(session.input_names(), ["a", "b"]);
+
+	let a = [1.0_f32, 2.0, 3.0];
+	let b = [0.5_f32, -2.0, 10.0];
+	let positional = session.run_positional(vec![Value::from_array((vec![3], &a[..]))?, Value::from_array((vec![3], &b[..]))?])?;
+	let named = session.run(vec![("a", Value::from_array((vec![3], &a[..]))?), ("b", Value::from_array((vec![3], &b[..]))?)])?;
+	assert_eq!(positional["sum"].extract_raw_tensor::<f32>()?.1, named["sum"].extract_raw_tensor::<f32>()?.1);
+	assert_eq!(positional["sum"].extract_raw_tensor::<f32>()?.1, &[1.5, 0.0, 13.0]);
+
+	match session.run_positional(vec![Value::from_array((vec![3], &a[..]))?]) {
+		Err(Error::PositionalInputCount { expected, actual }) => assert_eq!((expected, actual), (2, 1)),
+		r => panic!("expected an input count error, got {:?}", r.map(|_| ()))
+	}
+	assert!(matches!(session.run_positional(Vec::new()), Err(Error::PositionalInputCount { expected: 2, actual: 0 })));
+
+	Ok(())
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Round-trips a 0-dimensional (scalar) tensor through `identity_scalar.onnx`, a single `Identity` node with a scalar
+/// `float` input `x` and output `y`, which was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Identity', ['x'], ['y'], name='identity')],
+/// 	'identity_scalar',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, [])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, [])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'identity_scalar.onnx')
+/// ```
+#[test]
+fn scalar_identity() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_scalar.onnx"))?;
+	assert_eq!(session.inputs[0].input_type.tensor_dimensions(), Some(&vec![]));
+
+	let outputs = session.run(inputs![(vec![], &[0.7_f32][..])]?)?;
+	let (shape, data) = outputs["y"].extract_raw_tensor::<f32>()?;
+	assert!(shape.is_empty());
+	assert_eq!(data, &[0.7]);
+	assert_eq!(outputs["y"].try_extract_scalar::<f32>()?, 0.7);
+
+	// ndarray's 0-d arrays work too
+	let outputs = session.run(inputs![ndarray::arr0(0.7_f32)]?)?;
+	let output = outputs["y"].extract_tensor::<f32>()?;
+	assert_eq!(output.view().ndim(), 0);
+	assert_eq!(output.view()[[]], 0.7);
+
+	// a `[1]`-shaped tensor is not a scalar, so the model should reject it
+	assert!(session.run(inputs![(vec![1], &[0.7_f32][..])]?).is_err());
+
+	Ok(())
+}
@@ -0,0 +1,103 @@
+use std::{
+	collections::HashMap,
+	fmt::Debug,
+	path::Path,
+	sync::{Arc, Mutex}
+};
+
+use ort::{GraphOptimizationLevel, Session};
+use test_log::test;
+use tracing::{
+	field::{Field, Visit},
+	span::{Attributes, Id},
+	Event, Subscriber
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+#[derive(Debug)]
+struct RecordedSpan {
+	target: String,
+	fields: HashMap<String, String>
+}
+
+#[derive(Debug)]
+struct RecordedEvent {
+	span: Option<&'static str>,
+	fields: HashMap<String, String>
+}
+
+/// A layer which records the fields of every span named `build` & every event.
+#[derive(Clone, Default)]
+struct Recorder {
+	spans: Arc<Mutex<Vec<RecordedSpan>>>,
+	events: Arc<Mutex<Vec<RecordedEvent>>>
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+	fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+		self.0.insert(field.name().to_string(), format!("{value:?}"));
+	}
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Recorder {
+	fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+		if attrs.metadata().name() != "build" {
+			return;
+		}
+		let mut fields = HashMap::new();
+		attrs.record(&mut FieldVisitor(&mut fields));
+		self.spans.lock().unwrap().push(RecordedSpan {
+			target: attrs.metadata().target().to_string(),
+			fields
+		});
+	}
+
+	fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+		let mut fields = HashMap::new();
+		event.record(&mut FieldVisitor(&mut fields));
+		self.events.lock().unwrap().push(RecordedEvent {
+			span: ctx.event_span(event).map(|span| span.name()),
+			fields
+		});
+	}
+}
+
+/// Checks that building a session emits a `build` span around ONNX Runtime's parsing & optimization of the model,
+/// followed by an event with the elapsed time. See `tests/metadata_overrides.rs` for how `identity_dynamic_batch.onnx`
+/// was produced.
+#[test]
+fn session_build_span() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_dynamic_batch.onnx");
+	let model_bytes = std::fs::read(&model_path).unwrap();
+
+	let recorder = Recorder::default();
+	let subscriber = tracing_subscriber::registry().with(recorder.clone());
+	tracing::subscriber::with_default(subscriber, || -> ort::Result<()> {
+		Session::builder()?
+			.with_optimization_level(GraphOptimizationLevel::Level1)?
+			.with_model_from_file(&model_path)?;
+		Session::builder()?.with_model_from_memory(&model_bytes)?;
+		Ok(())
+	})?;
+
+	let spans = recorder.spans.lock().unwrap();
+	assert_eq!(spans.len(), 2, "expected a span for each session: {spans:?}");
+	for (span, optimization_level) in spans.iter().zip(["Level1", "Level3"]) {
+		assert_eq!(span.target, "ort::session");
+		assert_eq!(span.fields["model_bytes"], model_bytes.len().to_string());
+		assert_eq!(span.fields["optimization_level"], optimization_level);
+	}
+
+	let events = recorder.events.lock().unwrap();
+	let completions = events
+		.iter()
+		.filter(|e| e.span == Some("build") && e.fields.contains_key("elapsed"))
+		.count();
+	assert_eq!(completions, 2, "expected a completion event for each session: {events:?}");
+
+	Ok(())
+}
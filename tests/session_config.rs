@@ -0,0 +1,63 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use ort::{ExecutionMode, ExecutionProviderConfig, GraphOptimizationLevel, Session, SessionConfig};
+use test_log::test;
+
+/// Round-trips a [`SessionConfig`] through JSON and checks that applying it configures the builder accordingly.
+#[test]
+fn session_config() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let config = SessionConfig {
+		optimization_level: Some(GraphOptimizationLevel::Level1),
+		execution_mode: Some(ExecutionMode::Parallel),
+		intra_threads: Some(2),
+		inter_threads: Some(3),
+		memory_pattern: Some(false),
+		execution_providers: vec![ExecutionProviderConfig::CPU { arena_allocator: false }],
+		config_entries: BTreeMap::from([("session.set_denormal_as_zero".to_string(), "1".to_string())])
+	};
+	let json = serde_json::to_string(&config).unwrap();
+	let restored: SessionConfig = serde_json::from_str(&json).unwrap();
+	assert_eq!(restored, config);
+
+	let summary = Session::builder()?.apply_config(&restored)?.describe();
+	assert_eq!(summary.optimization_level, GraphOptimizationLevel::Level1);
+	assert_eq!(summary.execution_mode, ExecutionMode::Parallel);
+	assert_eq!((summary.intra_threads, summary.inter_threads), (Some(2), Some(3)));
+	assert!(!summary.memory_pattern);
+	assert_eq!(summary.execution_providers, ["CPUExecutionProvider"]);
+	assert!(!summary.cpu_mem_arena);
+	assert_eq!(summary.config_entries, [("session.set_denormal_as_zero".to_string(), "1".to_string())]);
+
+	// omitted options are left as-is, and execution providers are tagged by their `type`
+	let config: SessionConfig = serde_json::from_str(
+		r#"{
+			"intra_threads": 4,
+			"execution_providers": [
+				{ "type": "CUDA", "device_id": 1, "options": { "cudnn_conv_use_max_workspace": "1" } },
+				{ "type": "CPU" }
+			]
+		}"#
+	)
+	.unwrap();
+	assert_eq!(
+		config.execution_providers[0],
+		ExecutionProviderConfig::CUDA {
+			device_id: Some(1),
+			options: BTreeMap::from([("cudnn_conv_use_max_workspace".to_string(), "1".to_string())])
+		}
+	);
+	let summary = Session::builder()?.with_inter_threads(3)?.apply_config(&config)?.describe();
+	assert_eq!(summary.optimization_level, GraphOptimizationLevel::Level3);
+	assert_eq!((summary.intra_threads, summary.inter_threads), (Some(4), Some(3)));
+	assert_eq!(summary.execution_providers, ["CUDAExecutionProvider", "CPUExecutionProvider"]);
+
+	// unknown fields are rejected to catch typos
+	assert!(serde_json::from_str::<SessionConfig>(r#"{ "intra_thread": 4 }"#).is_err());
+	assert!(serde_json::from_str::<SessionConfig>(r#"{ "execution_providers": [{ "type": "CUDA", "device": 1 }] }"#).is_err());
+
+	Ok(())
+}
@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use ort::{inputs, LogSeverity, Session};
+use test_log::test;
+
+/// Checks that a session's log severity can be configured independently of the environment, which forwards all
+/// messages (including verbose ones) to `tracing`. See `tests/profiling.rs` for how `branches_dynamic.onnx` was
+/// produced.
+#[test]
+fn session_log_severity() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx");
+	let input = [-1.0_f32, 0.0, 2.0];
+	for severity in [LogSeverity::Error, LogSeverity::Verbose] {
+		let session = Session::builder()?
+			.with_log_severity_level(severity)?
+			.with_log_verbosity_level(1)?
+			.with_model_from_file(&model_path)?;
+		let outputs = session.run(inputs![(vec![3], &input[..])]?)?;
+		assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, [1.0, 0.0, 0.0]);
+	}
+
+	Ok(())
+}
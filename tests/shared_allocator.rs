@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use ort::{inputs, AllocatorType, ArenaConfig, ArenaExtendStrategy, MemoryInfo, MemoryType, Session};
+use test_log::test;
+
+/// Runs two sessions that share an arena allocator registered on the environment. See `tests/execution_mode.rs` for
+/// how `branches.onnx` was produced.
+#[test]
+fn shared_allocator() -> ort::Result<()> {
+	ort::init()
+		.with_name("integration_test")
+		.with_shared_allocator(
+			MemoryInfo::new_cpu(AllocatorType::Arena, MemoryType::Default)?,
+			ArenaConfig {
+				max_mem: Some(64 * 1024 * 1024),
+				arena_extend_strategy: Some(ArenaExtendStrategy::SameAsRequested),
+				..ArenaConfig::default()
+			}
+		)
+		.commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx");
+	let sessions = [
+		Session::builder()?.with_use_env_allocators()?.with_model_from_file(&model_path)?,
+		Session::builder()?.with_use_env_allocators()?.with_model_from_file(&model_path)?
+	];
+
+	let input = [-2.0_f32, -0.5, 0.5, 2.0];
+	let expected: Vec<f32> = input.iter().map(|&x| x.max(0.0) - x).collect();
+	for session in &sessions {
+		let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+		assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+	}
+
+	Ok(())
+}
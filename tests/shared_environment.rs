@@ -0,0 +1,33 @@
+use std::{path::Path, sync::Arc};
+
+use ndarray::Array4;
+use ort::{inputs, Environment, Session};
+use test_log::test;
+
+/// Creates several sessions in one explicitly built environment and checks that they all share it.
+#[test]
+fn shared_environment() -> ort::Result<()> {
+	let environment = ort::init().with_name("shared_environment").build()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.onnx");
+	let sessions = (0..10)
+		.map(|_| Session::builder()?.with_environment(&environment)?.with_intra_threads(1)?.with_model_from_file(&model_path))
+		.collect::<ort::Result<Vec<_>>>()?;
+
+	// every session holds a reference to the one environment; none were created on the side
+	assert_eq!(Arc::strong_count(&environment), 1 + sessions.len());
+
+	let input = Array4::<f32>::zeros((1, 4, 4, 3));
+	for session in &sessions {
+		let outputs = session.run(inputs![input.view()]?)?;
+		assert_eq!(outputs[0].extract_tensor::<f32>()?.view().shape(), [1, 8, 8, 3]);
+	}
+
+	drop(sessions);
+	assert_eq!(Arc::strong_count(&environment), 1);
+
+	// the global environment is a separate, lazily created environment
+	assert!(!Arc::ptr_eq(&environment, &Environment::current()?));
+
+	Ok(())
+}
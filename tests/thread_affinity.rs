@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Runs a session whose intra-op pool thread is pinned to the first logical processor. See `tests/execution_mode.rs`
+/// for how `branches.onnx` was produced.
+#[test]
+fn intra_op_thread_affinity() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	// with 2 intra-op threads, the pool has one thread besides the main thread, so one affinity is specified
+	let session = Session::builder()?
+		.with_intra_threads(2)?
+		.with_intra_op_thread_affinity("1")?
+		.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+
+	let input = [-2.0_f32, -0.5, 0.5, 2.0];
+	let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+	let expected: Vec<f32> = input.iter().map(|&x| x.max(0.0) - x).collect();
+	assert_eq!(outputs["y"].extract_raw_tensor::<f32>()?.1, &expected[..]);
+
+	Ok(())
+}
@@ -0,0 +1,70 @@
+#![cfg(all(feature = "training", feature = "ndarray"))]
+
+use std::path::{Path, PathBuf};
+
+use ndarray::Array2;
+use ort::{inputs, Checkpoint, Session};
+use test_log::test;
+
+fn training_artifact<P: AsRef<Path>>(name: P) -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("training").join(name)
+}
+
+/// This test verifies that a single training step can be run. It loads the training artifacts in
+/// `tests/data/training`, which are produced via:
+///
+/// ```python
+/// import onnx
+/// import torch
+/// from onnxruntime.training import artifacts
+///
+/// model = torch.nn.Linear(4, 2)
+/// torch.onnx.export(
+/// 	model, torch.randn(1, 4), 'linear.onnx',
+/// 	input_names=['input'], output_names=['output'],
+/// 	dynamic_axes={'input': {0: 'batch'}, 'output': {0: 'batch'}},
+/// 	do_constant_folding=False, training=torch.onnx.TrainingMode.TRAINING
+/// )
+///
+/// artifacts.generate_artifacts(
+/// 	onnx.load('linear.onnx'),
+/// 	requires_grad=['weight', 'bias'],
+/// 	loss=artifacts.LossType.MSELoss,
+/// 	optimizer=artifacts.OptimType.AdamW,
+/// 	artifact_directory='tests/data/training'
+/// )
+/// ```
+///
+/// This requires an ONNX Runtime build with training support, and the generated artifacts are not checked in, so the
+/// test must be run explicitly with `--ignored`.
+#[test]
+#[ignore = "requires training artifacts in tests/data/training and an ONNX Runtime build with training support"]
+fn train_step() -> ort::Result<()> {
+	let checkpoint = Checkpoint::load(training_artifact("checkpoint"))?;
+	let trainer = Session::builder()?.with_training_artifacts(
+		checkpoint,
+		training_artifact("training_model.onnx"),
+		Some(training_artifact("eval_model.onnx")),
+		Some(training_artifact("optimizer_model.onnx"))
+	)?;
+
+	let input = Array2::<f32>::from_shape_fn((8, 4), |(i, j)| (i * 4 + j) as f32 / 32.0);
+	let target = Array2::<f32>::from_shape_fn((8, 2), |(i, j)| (i + j) as f32 / 8.0);
+
+	let outputs = trainer.train_step(inputs![input.view(), target.view()]?)?;
+	let loss = outputs[0].extract_tensor::<f32>()?;
+	let loss = loss.view();
+	assert_eq!(loss.len(), 1);
+	assert!(loss.iter().all(|x| x.is_finite()));
+	drop(outputs);
+
+	trainer.optimizer_step()?;
+	trainer.lazy_reset_grad()?;
+
+	// outputs hold onto the training session, so they can outlive it
+	let (_, loss) = trainer.train_step(inputs![input.view(), target.view()]?)?.drain().next().unwrap();
+	drop(trainer);
+	assert!(loss.extract_raw_tensor::<f32>()?.1.iter().all(|x| x.is_finite()));
+
+	Ok(())
+}
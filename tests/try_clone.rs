@@ -0,0 +1,33 @@
+use std::{path::Path, thread};
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// Clones a session and runs the original and the clone (on another thread), checking that both share the same
+/// underlying session and produce identical results. See `tests/execution_mode.rs` for how `branches.onnx` was
+/// produced.
+#[test]
+fn session_try_clone() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches.onnx"))?;
+	let clone = session.try_clone()?;
+	assert_eq!(clone.ptr(), session.ptr());
+	assert_eq!(clone.inputs[0].name, session.inputs[0].name);
+
+	let input = [-2.0_f32, -0.5, 0.5, 2.0];
+	let run = |session: &Session| -> ort::Result<Vec<f32>> {
+		let outputs = session.run(inputs![(vec![4], &input[..])]?)?;
+		Ok(outputs["y"].extract_raw_tensor::<f32>()?.1.to_vec())
+	};
+
+	let original_output = run(&session)?;
+	let clone_output = thread::scope(|s| s.spawn(|| run(&clone)).join().unwrap())?;
+	assert_eq!(original_output, clone_output);
+
+	// the underlying session must stay alive for the clone after the original is dropped
+	drop(session);
+	assert_eq!(run(&clone)?, original_output);
+
+	Ok(())
+}
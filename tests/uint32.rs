@@ -0,0 +1,45 @@
+use std::path::Path;
+
+use ort::{inputs, Session, TensorElementType, Value};
+use test_log::test;
+
+/// Round-trips a `uint32` tensor, such as the token IDs output by some tokenizers, through `identity_uint32.onnx`,
+/// which maps a `uint32[128]` input `x` to `y` with an `Identity` node. It was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Identity', ['x'], ['y'], name='identity')],
+/// 	'identity_uint32',
+/// 	[helper.make_tensor_value_info('x', TensorProto.UINT32, [128])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.UINT32, [128])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13)], ir_version=7), 'identity_uint32.onnx')
+/// ```
+#[test]
+fn uint32_identity() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_uint32.onnx"))?;
+
+	// include values that don't fit in an `i32`
+	let token_ids: Vec<u32> = (0..128).map(|i| i * 33_554_467).collect();
+	assert!(token_ids.iter().any(|&id| id > i32::MAX as u32));
+
+	let input = Value::from_array((vec![128], &token_ids[..]))?;
+	assert_eq!(input.tensor_element_type()?, TensorElementType::Uint32);
+	let outputs = session.run(inputs![input]?)?;
+
+	assert_eq!(outputs["y"].tensor_element_type()?, TensorElementType::Uint32);
+	let (shape, data) = outputs["y"].extract_raw_tensor::<u32>()?;
+	assert_eq!(shape, vec![128]);
+	assert_eq!(data, &token_ids[..]);
+	let output = outputs["y"].extract_tensor::<u32>()?;
+	assert_eq!(output.view().iter().copied().collect::<Vec<_>>(), token_ids);
+	let (_, _, bytes) = outputs["y"].to_bytes()?;
+	assert_eq!(bytes, token_ids.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>());
+
+	Ok(())
+}
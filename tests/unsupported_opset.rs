@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use ort::Session;
+use test_log::test;
+
+/// Loads `identity_opset99.onnx`, a single `Identity` node stamped with an unreleased opset, which was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// graph = helper.make_graph(
+/// 	[helper.make_node('Identity', ['x'], ['y'], name='identity')],
+/// 	'identity_opset99',
+/// 	[helper.make_tensor_value_info('x', TensorProto.FLOAT, [4])],
+/// 	[helper.make_tensor_value_info('y', TensorProto.FLOAT, [4])]
+/// )
+/// onnx.save(helper.make_model(graph, opset_imports=[helper.make_opsetid('', 99)], ir_version=7), 'identity_opset99.onnx')
+/// ```
+#[test]
+fn unsupported_opset() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let result = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("identity_opset99.onnx"));
+	match result {
+		Err(ort::Error::UnsupportedOpset { model_opset, max_supported_opset, .. }) => {
+			assert_eq!(model_opset, 99);
+			assert!(max_supported_opset < 99);
+		}
+		other => panic!("expected `Error::UnsupportedOpset`, got {other:?}")
+	}
+
+	Ok(())
+}
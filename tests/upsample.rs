@@ -2,7 +2,7 @@ use std::path::Path;
 
 use image::RgbImage;
 use ndarray::{Array, CowArray, Ix4};
-use ort::{inputs, GraphOptimizationLevel, Session, Tensor};
+use ort::{inputs, GraphOptimizationLevel, ModelFileFormat, Session, Tensor};
 use test_log::test;
 
 fn load_input_image<P: AsRef<Path>>(name: P) -> RgbImage {
@@ -113,3 +113,53 @@ fn upsample_with_ort_model() -> ort::Result<()> {
 
 	Ok(())
 }
+
+/// Loads `upsample.ort` from a file, relying on the extension to detect the format, and from memory with an explicitly
+/// configured format. See [`upsample_with_ort_model`] for how it was produced.
+#[test]
+fn upsample_ort_model_format() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.ort");
+	let session_data = std::fs::read(&model_path).expect("Could not open model from file");
+	let sessions = [
+		Session::builder()?.with_model_from_file(&model_path)?,
+		Session::builder()?.with_model_format(ModelFileFormat::Ort)?.with_model_from_memory(&session_data)?
+	];
+
+	let image_buffer = load_input_image("mushroom.png");
+	let array = convert_image_to_cow_array(&image_buffer);
+	for session in &sessions {
+		let outputs = session.run(inputs![&array]?)?;
+		let output: Tensor<f32> = outputs[0].extract_tensor()?;
+		assert_eq!(output.view().shape(), [1, 448, 448, 3]);
+	}
+
+	// forcing the wrong format should fail to load the model
+	assert!(Session::builder()?.with_model_format(ModelFileFormat::Onnx)?.with_model_from_memory(&session_data).is_err());
+
+	Ok(())
+}
+
+/// Loads `upsample.ort` from memory with `SessionBuilder::with_model_bytes_directly`, and checks that the session
+/// doesn't depend on the caller's buffer. See [`upsample_with_ort_model`] for how it was produced.
+#[test]
+fn upsample_ort_model_bytes_directly() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let mut session_data =
+		std::fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.ort")).expect("Could not open model from file");
+	let session = Session::builder()?.with_model_bytes_directly(true)?.with_model_from_memory(&session_data)?;
+
+	// clobber & free the caller's buffer; the session must have retained its own copy
+	session_data.fill(0);
+	drop(session_data);
+
+	let image_buffer = load_input_image("mushroom.png");
+	let array = convert_image_to_cow_array(&image_buffer);
+	let outputs = session.run(inputs![&array]?)?;
+	let output: Tensor<f32> = outputs[0].extract_tensor()?;
+	assert_eq!(output.view().shape(), [1, 448, 448, 3]);
+
+	Ok(())
+}
@@ -0,0 +1,87 @@
+use std::path::Path;
+
+use ort::{Error, Session};
+use test_log::test;
+
+/// Checks that `SessionBuilder::commit_and_warmup` runs the model before returning the session, using
+/// the profiler to count runs. See `tests/profiling.rs` for how `branches_dynamic.onnx` was produced.
+#[test]
+#[cfg(feature = "profiling")]
+fn warmup_runs_before_returning() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let profile_dir = std::env::temp_dir().join(format!("ort-warmup-test-{}", std::process::id()));
+	std::fs::create_dir_all(&profile_dir).unwrap();
+
+	let session = Session::builder()?
+		.with_profiling(profile_dir.join("branches").to_str().unwrap())?
+		.commit_and_warmup(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx"), &[&[16]])?;
+
+	let summary = session.profiling_summary()?;
+	assert_eq!(summary.len(), 3);
+	assert!(summary.iter().all(|timing| timing.count == 1), "expected exactly one (warmup) run: {summary:?}");
+
+	std::fs::remove_dir_all(&profile_dir).unwrap();
+
+	Ok(())
+}
+
+#[test]
+fn warmup_shape_count() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx");
+	assert!(matches!(
+		Session::builder()?.commit_and_warmup(&model_path, &[&[16], &[16]]),
+		Err(Error::WarmupShapeCount { expected: 1, actual: 2 })
+	));
+	assert!(matches!(
+		Session::builder()?.commit_and_warmup(&model_path, &[&[i64::MAX, 4]]),
+		Err(Error::ShapeTooLarge(shape)) if shape == [i64::MAX, 4]
+	));
+
+	Ok(())
+}
+
+/// Checks that `commit_and_warmup` leaves the builder (and anything it owns, like custom op libraries) intact, so it can
+/// be committed again afterwards.
+#[test]
+fn warmup_keeps_builder_usable() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let model_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("branches_dynamic.onnx");
+	let builder = Session::builder()?;
+	let first = builder.commit_and_warmup(&model_path, &[&[16]])?;
+	let second = builder.commit_and_warmup(&model_path, &[&[16]])?;
+	drop(first);
+	let session = builder.with_model_from_file(&model_path)?;
+	assert_eq!(session.inputs.len(), second.inputs.len());
+
+	Ok(())
+}
+
+/// Checks that the warmup run builds TensorRT engines and writes them to the engine cache before the session is
+/// returned.
+#[test]
+#[cfg(feature = "tensorrt")]
+#[ignore = "requires a TensorRT-capable GPU"]
+fn warmup_populates_tensorrt_engine_cache() -> ort::Result<()> {
+	use ort::{ExecutionProvider, TensorRTExecutionProvider};
+
+	ort::init().with_name("integration_test").commit()?;
+
+	let cache_dir = std::env::temp_dir().join(format!("ort-warmup-trt-cache-{}", std::process::id()));
+	std::fs::create_dir_all(&cache_dir).unwrap();
+
+	let ep = TensorRTExecutionProvider::default().with_engine_cache(true).with_engine_cache_path(cache_dir.display());
+	assert!(ep.is_available()?);
+
+	let _session = Session::builder()?
+		.with_execution_providers([ep.build().error_on_failure()])?
+		.commit_and_warmup(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("upsample.onnx"), &[&[1, 32, 32, 3]])?;
+	assert!(std::fs::read_dir(&cache_dir).unwrap().next().is_some(), "engine cache should be populated after warmup");
+
+	std::fs::remove_dir_all(&cache_dir).unwrap();
+
+	Ok(())
+}
@@ -0,0 +1,50 @@
+use std::{collections::HashMap, path::Path};
+
+use ort::{inputs, Session};
+use test_log::test;
+
+/// This test verifies that string-keyed maps can be extracted. `zipmap.onnx` applies `ZipMap` with the class labels
+/// `["cat", "dog", "bird"]` to a `float[N, 3]` input, producing a `seq(map(string, float))` with one map per row, and
+/// was produced via:
+///
+/// ```python
+/// import onnx
+/// from onnx import helper, TensorProto
+///
+/// probabilities = helper.make_map_type_proto(TensorProto.STRING, helper.make_tensor_type_proto(TensorProto.FLOAT, None))
+/// graph = helper.make_graph(
+/// 	[helper.make_node('ZipMap', ['X'], ['Z'], name='zipmap', domain='ai.onnx.ml', classlabels_strings=['cat', 'dog', 'bird'])],
+/// 	'zipmap',
+/// 	[helper.make_tensor_value_info('X', TensorProto.FLOAT, ['N', 3])],
+/// 	[helper.make_value_info('Z', helper.make_sequence_type_proto(probabilities))]
+/// )
+/// onnx.save(
+/// 	helper.make_model(graph, opset_imports=[helper.make_opsetid('', 13), helper.make_opsetid('ai.onnx.ml', 1)], ir_version=7),
+/// 	'zipmap.onnx'
+/// )
+/// ```
+#[test]
+fn zipmap_string_keys() -> ort::Result<()> {
+	ort::init().with_name("integration_test").commit()?;
+
+	let session = Session::builder()?.with_model_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("zipmap.onnx"))?;
+
+	let probabilities = [0.7_f32, 0.2, 0.1, 0.05, 0.9, 0.05];
+	let outputs = session.run(inputs![(vec![2, 3], &probabilities[..])]?)?;
+	let maps = outputs["Z"].extract_sequence(session.allocator())?;
+	assert_eq!(maps.len(), 2);
+
+	let first: HashMap<String, f32> = maps[0].extract_map(session.allocator())?;
+	assert_eq!(first, HashMap::from([("cat".to_string(), 0.7), ("dog".to_string(), 0.2), ("bird".to_string(), 0.1)]));
+	let second: HashMap<String, f32> = maps[1].extract_map(session.allocator())?;
+	assert_eq!(second["dog"], 0.9);
+
+	// keys must be extracted with the right type
+	assert!(maps[0].extract_map::<i64, f32>(session.allocator()).is_err());
+
+	// an empty batch produces no maps
+	let outputs = session.run(inputs![(vec![0, 3], &[][..] as &[f32])]?)?;
+	assert!(outputs["Z"].extract_sequence(session.allocator())?.is_empty());
+
+	Ok(())
+}